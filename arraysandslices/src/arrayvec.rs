@@ -0,0 +1,293 @@
+// A vector that never allocates: `ArrayVec<T, N>` keeps up to `N` elements
+// in an array stored inline, on the stack when the `ArrayVec` itself is.
+// Like an array, its capacity is part of its type; like a `Vec`, its length
+// changes as elements are pushed and popped.
+//
+// The array starts out uninitialized, so it is an array of
+// `MaybeUninit<T>`: the first `len` slots hold elements, the rest hold
+// nothing. Every `unsafe` block below relies on that single invariant.
+//
+// Through `Deref`, an `ArrayVec` can be used wherever a slice can:
+// `v.len()`, `v[0]`, `v.iter()`, `&v[1..3]`, `v.sort()`, ...
+
+use std::fmt;
+use std::mem::{self, MaybeUninit};
+use std::ops::{Deref, DerefMut};
+use std::ptr;
+use std::slice;
+
+pub struct ArrayVec<T, const N: usize> {
+    data: [MaybeUninit<T>; N],
+    len: usize,
+}
+
+// Returned by the operations that would go over the capacity. The element
+// that didn't fit is handed back rather than dropped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CapacityError<T>(pub T);
+
+impl<T> CapacityError<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> fmt::Display for CapacityError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "the collection is full")
+    }
+}
+
+impl<T: fmt::Debug> std::error::Error for CapacityError<T> {}
+
+impl<T, const N: usize> ArrayVec<T, N> {
+    pub const fn new() -> ArrayVec<T, N> {
+        ArrayVec {
+            data: [const { MaybeUninit::uninit() }; N],
+            len: 0,
+        }
+    }
+
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    pub const fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    pub const fn remaining_capacity(&self) -> usize {
+        N - self.len
+    }
+
+    pub fn push(&mut self, element: T) -> Result<(), CapacityError<T>> {
+        if self.is_full() {
+            return Err(CapacityError(element));
+        }
+        self.data[self.len].write(element);
+        self.len += 1;
+        Ok(())
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        // SAFETY: the slot was below the old `len`, so it is initialized, and
+        // it is now past `len`, so it won't be read or dropped again.
+        Some(unsafe { self.data[self.len].assume_init_read() })
+    }
+
+    // Insert `element` at `index`, shifting everything after it to the right.
+    // Panics if `index > len`, like `Vec::insert`.
+    pub fn insert(&mut self, index: usize, element: T) -> Result<(), CapacityError<T>> {
+        assert!(index <= self.len, "insertion index {} is out of bounds (len {})", index, self.len);
+        if self.is_full() {
+            return Err(CapacityError(element));
+        }
+
+        // SAFETY: `index <= len < N`, so both the moved range
+        // `index..len` and its destination `index + 1..len + 1` are in the array.
+        unsafe {
+            let p = self.as_mut_ptr().add(index);
+            ptr::copy(p, p.add(1), self.len - index);
+            ptr::write(p, element);
+        }
+        self.len += 1;
+        Ok(())
+    }
+
+    // Remove and return the element at `index`, shifting everything after it
+    // to the left. Panics if `index >= len`, like `Vec::remove`.
+    pub fn remove(&mut self, index: usize) -> T {
+        assert!(index < self.len, "removal index {} is out of bounds (len {})", index, self.len);
+
+        // SAFETY: `index < len`, so the element is initialized. The elements
+        // after it are moved down one slot and the last slot leaves `len`.
+        unsafe {
+            let p = self.as_mut_ptr().add(index);
+            let element = ptr::read(p);
+            ptr::copy(p.add(1), p, self.len - index - 1);
+            self.len -= 1;
+            element
+        }
+    }
+
+    // Keep the first `len` elements and drop the rest. Does nothing if the
+    // `ArrayVec` is already that short.
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len {
+            return;
+        }
+        // Shrink first: if dropping an element panics, the others are leaked
+        // rather than dropped twice.
+        let tail_len = self.len - len;
+        self.len = len;
+        // SAFETY: the tail was initialized and is no longer part of the `ArrayVec`.
+        unsafe {
+            let tail = ptr::slice_from_raw_parts_mut(self.as_mut_ptr().add(len), tail_len);
+            ptr::drop_in_place(tail);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: the first `len` slots are initialized.
+        unsafe { slice::from_raw_parts(self.data.as_ptr() as *const T, self.len) }
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: the first `len` slots are initialized.
+        unsafe { slice::from_raw_parts_mut(self.data.as_mut_ptr() as *mut T, self.len) }
+    }
+
+    fn as_mut_ptr(&mut self) -> *mut T {
+        self.data.as_mut_ptr() as *mut T
+    }
+}
+
+impl<T, const N: usize> Drop for ArrayVec<T, N> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T, const N: usize> Default for ArrayVec<T, N> {
+    fn default() -> ArrayVec<T, N> {
+        ArrayVec::new()
+    }
+}
+
+impl<T, const N: usize> Deref for ArrayVec<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, const N: usize> DerefMut for ArrayVec<T, N> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for ArrayVec<T, N> {
+    fn clone(&self) -> ArrayVec<T, N> {
+        let mut clone = ArrayVec::new();
+        for element in self.iter() {
+            // Can't fail: `clone` has the same capacity as `self`.
+            let _ = clone.push(element.clone());
+        }
+        clone
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for ArrayVec<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.as_slice(), f)
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq for ArrayVec<T, N> {
+    fn eq(&self, other: &ArrayVec<T, N>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for ArrayVec<T, N> {}
+
+// Copying a slice with more than `N` elements hands back the first one
+// that didn't fit.
+impl<T, const N: usize> TryFrom<&[T]> for ArrayVec<T, N>
+where
+    T: Clone,
+{
+    type Error = CapacityError<T>;
+
+    fn try_from(slice: &[T]) -> Result<ArrayVec<T, N>, CapacityError<T>> {
+        let mut vec = ArrayVec::new();
+        for element in slice {
+            vec.push(element.clone())?;
+        }
+        Ok(vec)
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a ArrayVec<T, N> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> slice::Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut ArrayVec<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> slice::IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+impl<T, const N: usize> IntoIterator for ArrayVec<T, N> {
+    type Item = T;
+    type IntoIter = IntoIter<T, N>;
+
+    fn into_iter(self) -> IntoIter<T, N> {
+        IntoIter { vec: self, start: 0 }
+    }
+}
+
+// Moves the elements out of an `ArrayVec`. The elements still to be
+// yielded are `vec.data[start..vec.len]`.
+pub struct IntoIter<T, const N: usize> {
+    vec: ArrayVec<T, N>,
+    start: usize,
+}
+
+impl<T, const N: usize> Iterator for IntoIter<T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.start == self.vec.len {
+            return None;
+        }
+        self.start += 1;
+        // SAFETY: the slot is below `len` and was not yielded yet.
+        Some(unsafe { self.vec.data[self.start - 1].assume_init_read() })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.vec.len - self.start;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for IntoIter<T, N> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.start == self.vec.len {
+            return None;
+        }
+        self.vec.pop()
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for IntoIter<T, N> {}
+
+impl<T, const N: usize> Drop for IntoIter<T, N> {
+    fn drop(&mut self) {
+        // Drop what was not yielded, then empty the `ArrayVec` so that its own
+        // `Drop` doesn't touch the elements that were moved out.
+        let len = mem::replace(&mut self.vec.len, 0);
+        let remaining = &mut self.vec.data[self.start..len];
+        // SAFETY: `start..len` are initialized and were not yielded.
+        unsafe { ptr::drop_in_place(remaining as *mut [MaybeUninit<T>] as *mut [T]) };
+    }
+}
@@ -0,0 +1,398 @@
+// A two-dimensional grid stored the way a one-dimensional slice is: all the
+// cells in a single contiguous buffer, row after row ("row-major" order).
+// The cell in row `r` and column `c` is at index `r * width + c`.
+//
+// Because rows are contiguous, a row is simply a slice of the buffer. A
+// column isn't: its cells are `width` elements apart, so it is visited with a
+// strided iterator instead, `step_by(width)`.
+//
+// A `GridView` is to a `Grid` what a slice is to an array: it borrows a
+// rectangle of the grid, `grid.view(1..3, 2..5)`, without copying anything.
+// Each of its rows is still a contiguous slice, but consecutive rows are
+// `stride` elements apart, the width of the grid they come from.
+
+use std::fmt;
+use std::iter::StepBy;
+use std::ops::{Index, IndexMut, Range};
+use std::slice;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+// Returned by `Grid::from_vec` when the cells don't fill the grid exactly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShapeError {
+    pub width: usize,
+    pub height: usize,
+    pub len: usize,
+}
+
+impl fmt::Display for ShapeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} cells cannot fill a {}x{} grid", self.len, self.width, self.height)
+    }
+}
+
+impl std::error::Error for ShapeError {}
+
+// Which cells count as neighbors: the four sharing a side with a cell, or
+// the eight sharing a side or a corner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Connectivity {
+    Four,
+    Eight,
+}
+
+impl Connectivity {
+    // `(row, column)` offsets, in row-major order.
+    fn offsets(self) -> &'static [(isize, isize)] {
+        match self {
+            Connectivity::Four => &[(-1, 0), (0, -1), (0, 1), (1, 0)],
+            Connectivity::Eight => &[(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    // A `width` x `height` grid with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; area(width, height)],
+        }
+    }
+
+    // A grid whose cells are given row after row.
+    pub fn from_vec(width: usize, height: usize, cells: Vec<T>) -> Result<Grid<T>, ShapeError> {
+        if width.checked_mul(height) != Some(cells.len()) {
+            return Err(ShapeError { width, height, len: cells.len() });
+        }
+        Ok(Grid { width, height, cells })
+    }
+
+    // A grid whose cells are computed from their `(row, column)` position.
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(usize, usize) -> T) -> Grid<T> {
+        let mut cells = Vec::with_capacity(area(width, height));
+        for row in 0..height {
+            for col in 0..width {
+                cells.push(f(row, col));
+            }
+        }
+        Grid { width, height, cells }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    // The whole buffer, row after row.
+    pub fn as_slice(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.cells
+    }
+
+    // The whole grid as a view, which every read-only method goes through.
+    pub fn as_view(&self) -> GridView<'_, T> {
+        GridView {
+            cells: &self.cells,
+            stride: self.width,
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        self.as_view().get(row, col)
+    }
+
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        if row >= self.height || col >= self.width {
+            return None;
+        }
+        Some(&mut self.cells[row * self.width + col])
+    }
+
+    // Panics if `row` is out of bounds, like indexing a slice.
+    pub fn row(&self, row: usize) -> &[T] {
+        self.as_view().row(row)
+    }
+
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        assert!(row < self.height, "row {} is out of bounds (height {})", row, self.height);
+        &mut self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        self.as_view().rows()
+    }
+
+    // Panics if `col` is out of bounds.
+    pub fn column(&self, col: usize) -> Column<'_, T> {
+        self.as_view().column(col)
+    }
+
+    pub fn column_mut(&mut self, col: usize) -> ColumnMut<'_, T> {
+        assert!(col < self.width, "column {} is out of bounds (width {})", col, self.width);
+        self.cells[column_range(self.width, self.height, col)].iter_mut().step_by(self.width)
+    }
+
+    // Every cell, row after row.
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, T> {
+        self.cells.iter_mut()
+    }
+
+    // Borrow the rectangle made of the rows in `rows` and the columns in
+    // `cols`. Panics if it doesn't fit in the grid, like slicing does.
+    pub fn view(&self, rows: Range<usize>, cols: Range<usize>) -> GridView<'_, T> {
+        self.as_view().view(rows, cols)
+    }
+
+    pub fn view_mut(&mut self, rows: Range<usize>, cols: Range<usize>) -> GridViewMut<'_, T> {
+        check_view(self.width, self.height, &rows, &cols);
+        let start = (rows.start * self.width + cols.start).min(self.cells.len());
+        GridViewMut {
+            cells: &mut self.cells[start..],
+            stride: self.width,
+            width: cols.len(),
+            height: rows.len(),
+        }
+    }
+
+    // The cells around `(row, col)` that are inside the grid, with their positions.
+    pub fn neighbors(
+        &self,
+        row: usize,
+        col: usize,
+        connectivity: Connectivity,
+    ) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.as_view().neighbors(row, col, connectivity)
+    }
+}
+
+// Indexing by `(row, column)`: `grid[(1, 2)]`.
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        &self.row(row)[col]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        &mut self.row_mut(row)[col]
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.as_view(), f)
+    }
+}
+
+pub type Column<'a, T> = StepBy<slice::Iter<'a, T>>;
+pub type ColumnMut<'a, T> = StepBy<slice::IterMut<'a, T>>;
+
+// A rectangle borrowed from a `Grid`. `cells` starts at its top-left cell;
+// row `r` of the view is `cells[r * stride..r * stride + width]`.
+#[derive(Debug)]
+pub struct GridView<'a, T> {
+    cells: &'a [T],
+    stride: usize,
+    width: usize,
+    height: usize,
+}
+
+// Copying a view copies the borrow, not the cells, whatever `T` is.
+impl<T> Clone for GridView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for GridView<'_, T> {}
+
+impl<'a, T> GridView<'a, T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&'a T> {
+        if row >= self.height || col >= self.width {
+            return None;
+        }
+        Some(&self.cells[row * self.stride + col])
+    }
+
+    pub fn row(&self, row: usize) -> &'a [T] {
+        assert!(row < self.height, "row {} is out of bounds (height {})", row, self.height);
+        let start = row * self.stride;
+        &self.cells[start..start + self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &'a [T]> + ExactSizeIterator {
+        let view = *self;
+        (0..self.height).map(move |row| view.row(row))
+    }
+
+    pub fn column(&self, col: usize) -> Column<'a, T> {
+        assert!(col < self.width, "column {} is out of bounds (width {})", col, self.width);
+        self.cells[column_range(self.stride, self.height, col)].iter().step_by(self.stride)
+    }
+
+    // A view of a view, relative to this one's top-left corner.
+    pub fn view(&self, rows: Range<usize>, cols: Range<usize>) -> GridView<'a, T> {
+        check_view(self.width, self.height, &rows, &cols);
+        let start = (rows.start * self.stride + cols.start).min(self.cells.len());
+        GridView {
+            cells: &self.cells[start..],
+            stride: self.stride,
+            width: cols.len(),
+            height: rows.len(),
+        }
+    }
+
+    pub fn neighbors(
+        &self,
+        row: usize,
+        col: usize,
+        connectivity: Connectivity,
+    ) -> impl Iterator<Item = ((usize, usize), &'a T)> {
+        let view = *self;
+        connectivity.offsets().iter().filter_map(move |&(dr, dc)| {
+            let (row, col) = (row.checked_add_signed(dr)?, col.checked_add_signed(dc)?);
+            Some(((row, col), view.get(row, col)?))
+        })
+    }
+}
+
+// Every cell right-aligned in its column, columns one space apart.
+impl<T: fmt::Display> fmt::Display for GridView<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let cells: Vec<Vec<String>> = self
+            .rows()
+            .map(|row| row.iter().map(ToString::to_string).collect())
+            .collect();
+
+        let mut widths = vec![0; self.width];
+        for row in &cells {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        for (i, row) in cells.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for (j, (cell, width)) in row.iter().zip(&widths).enumerate() {
+                if j > 0 {
+                    write!(f, " ")?;
+                }
+                write!(f, "{:>width$}", cell, width = width)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// A rectangle borrowed mutably from a `Grid`, laid out like a `GridView`.
+#[derive(Debug)]
+pub struct GridViewMut<'a, T> {
+    cells: &'a mut [T],
+    stride: usize,
+    width: usize,
+    height: usize,
+}
+
+impl<T> GridViewMut<'_, T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn as_view(&self) -> GridView<'_, T> {
+        GridView {
+            cells: self.cells,
+            stride: self.stride,
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        if row >= self.height || col >= self.width {
+            return None;
+        }
+        Some(&mut self.cells[row * self.stride + col])
+    }
+
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        assert!(row < self.height, "row {} is out of bounds (height {})", row, self.height);
+        let start = row * self.stride;
+        &mut self.cells[start..start + self.width]
+    }
+
+    // Set every cell of the view to `value`.
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        for row in 0..self.height {
+            self.row_mut(row).fill(value.clone());
+        }
+    }
+}
+
+fn area(width: usize, height: usize) -> usize {
+    width
+        .checked_mul(height)
+        .unwrap_or_else(|| panic!("a {}x{} grid has too many cells", width, height))
+}
+
+// The part of the buffer from the top of column `col` to its bottom,
+// `height` rows down. The buffer may go on past the last row, the column
+// must stop there.
+fn column_range(stride: usize, height: usize, col: usize) -> Range<usize> {
+    match height {
+        0 => 0..0,
+        _ => col..(height - 1) * stride + col + 1,
+    }
+}
+
+fn check_view(width: usize, height: usize, rows: &Range<usize>, cols: &Range<usize>) {
+    assert!(
+        rows.start <= rows.end && rows.end <= height && cols.start <= cols.end && cols.end <= width,
+        "rows {:?} and columns {:?} are not inside a {}x{} grid",
+        rows,
+        cols,
+        width,
+        height
+    );
+}
@@ -0,0 +1,203 @@
+// An array is a collection of objects of the same type `T`,
+// stored in contiguous memory. Arrays are created using brackets `[]`,
+// and their length, which is known at compile time, is part of their
+// type signature `[T; lenght]`.
+//
+// Slices are similar to arrays, but their length is not known at compile time.
+// A slice is a two-word object, the first word is a pointer to the data
+// and the second word is the length of the slice.
+// The word size is the same as `usize`, determined by the processor architecture (i.e. 64 bits on x86-64).
+// Slices can be used to borrow a section of an array and have the type signature `&[T]`.
+
+// `ys` only shows the `[value; length]` initialization syntax.
+#![allow(unused_variables)]
+
+use std::mem;
+use std::io::{self, Write};
+
+use cmdline::Args;
+
+pub mod arrayvec;
+pub mod grid;
+mod report;
+pub mod ringbuffer;
+
+pub use arrayvec::{ArrayVec, CapacityError};
+pub use grid::{Connectivity, Grid, GridView};
+pub use report::{analyze_numbers, analyze_slice, SliceReport, Stats};
+pub use ringbuffer::{Overflow, RingBuffer};
+
+pub const USAGE: &str = "\
+usage: arraysandslices [--start <usize>] [--end <usize>]
+
+options:
+    --start <usize>    first index of the borrowed section (default: 1)
+    --end <usize>      one past the last index of the section, at most 5 (default: 4)
+                       `--start` and `--end` may be equal: the section is then empty
+    -h, --help         print this message";
+
+// The section of the array borrowed as a slice: `&xs[start..end]`.
+pub struct Params {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Default for Params {
+    fn default() -> Params {
+        Params { start: 1, end: 4 }
+    }
+}
+
+impl Params {
+    pub fn from_args(mut args: Args) -> Result<Params, cmdline::Error> {
+        let default = Params::default();
+        let params = Params {
+            start: args.get("start", default.start)?,
+            end: args.get("end", default.end)?,
+        };
+        args.finish()?;
+
+        // The section may be empty, but it must fit in the five element array.
+        if params.start > params.end || params.end > 5 {
+            return Err(cmdline::Error::Usage(format!(
+                "`{}..{}` is not a section of a 5 element array",
+                params.start, params.end
+            )));
+        }
+        Ok(params)
+    }
+}
+
+pub fn run(out: &mut dyn Write) -> io::Result<()> {
+    run_with(out, &Params::default())
+}
+
+pub fn run_args(args: Args, out: &mut dyn Write) -> Result<(), cmdline::Error> {
+    let params = Params::from_args(args)?;
+    Ok(run_with(out, &params)?)
+}
+
+pub fn run_with(out: &mut dyn Write, params: &Params) -> io::Result<()> {
+    // Fixed size array (type signature is superfluous)
+    let xs: [i32; 5] = [1, 2, 3, 4, 5];
+
+    // All elements can be initialized to the same value
+    let ys: [i32; 500] = [0; 500];
+
+    // Indexing starts at zero
+    writeln!(out, "first element of the array is: {}", xs[0])?;
+    writeln!(out, "second element of the array is: {}", xs[1])?;
+
+    // `len` returns the count of elements in the array
+    writeln!(out, "number of elements in the array: {}", xs.len())?;
+
+    // Arrays are stack allocated
+    writeln!(out, "array occupies {} bytes", mem::size_of_val(&xs))?;
+
+    // Arrays can be automatically borrowed as slices
+    writeln!(out, "borrow the whole array as a slice")?;
+    writeln!(out, "{}", analyze_numbers(&xs))?;
+
+    //Slices can point to a section of an array
+    // They are of the form [starting_index..ending_index]
+    // starting_index is the first position in the slice
+    // ending_index is one more than the last position in the slice
+    writeln!(out, "borrow a section of the array as a slice")?;
+    let section = &xs[params.start..params.end];
+    writeln!(out, "slice elements: {:?}", section)?;
+    writeln!(out, "{}", analyze_numbers(section))?;
+
+    // `analyze_slice` works for anything that can be compared, numbers or not,
+    // it just can't compute a mean of words
+    let words = ["slice", "array", "borrow", "vector"];
+    writeln!(out, "borrow an array of words as a slice")?;
+    writeln!(out, "{}", analyze_slice(&words))?;
+
+    // Example of empty slice `&[]`
+    let empty_array: [u32; 0] = [];
+    assert_eq!(&empty_array, &[]);
+    assert_eq!(&empty_array, &[][..]); //same as above but more verbose
+
+    // An empty slice has no first element, the report says so instead of panicking
+    writeln!(out, "{}", analyze_numbers(&empty_array))?;
+
+    // Arrays can be safelt accessed using `.get`
+    // which returns an `Option`.
+    // This can be matched as shown below or used with `.expect()`
+    // if we would like the program to exit with a nice message instead of
+    // happily continue.
+    for i in 0..xs.len() + 1 { //ERORR: one element too far
+        match xs.get(i) {
+            Some(xval) => writeln!(out, "{}: {}", i, xval)?,
+            None => writeln!(out, "{}: out of bounds", i)?,
+        }
+    }
+
+    // Out of bound indexing causes compiler error
+    // println!("{}", xs[5]);
+
+    // An `ArrayVec` grows and shrinks like a `Vec`, but inside an array
+    // whose size is fixed at compile time: nothing is allocated on the heap
+    let mut stack: ArrayVec<i32, 4> = ArrayVec::new();
+    for x in xs {
+        if let Err(err) = stack.push(x) {
+            writeln!(out, "cannot push {}: {}", err.0, err)?;
+        }
+    }
+    writeln!(out, "array vec: {:?}, capacity: {}", stack, stack.capacity())?;
+
+    // Make room at the front: `insert` shifts the elements to the right
+    let popped = stack.pop();
+    stack.insert(0, 0).expect("popping made room");
+    writeln!(out, "popped {:?}, inserted 0: {:?}", popped, stack)?;
+    stack.remove(1);
+    stack.truncate(2);
+    writeln!(out, "after remove(1) and truncate(2): {:?}", stack)?;
+
+    // It derefs to a slice, so it can be analyzed like one
+    writeln!(out, "{}", analyze_numbers(&stack))?;
+
+    // A `RingBuffer` keeps the last few elements pushed into it: here a
+    // rolling window over the last three samples of a sensor
+    let samples = [20.5, 21.0, 21.25, 22.0, 21.5];
+    let mut window: RingBuffer<f64, 3> = RingBuffer::new(Overflow::Overwrite);
+    for sample in samples {
+        if let Ok(Some(oldest)) = window.push_back(sample) {
+            writeln!(out, "{} pushed out {}", sample, oldest)?;
+        }
+    }
+    writeln!(out, "window: {:?}, front: {:?}, back: {:?}", window, window.front(), window.back())?;
+
+    // The window wrapped around the end of its array: in memory it is two slices
+    let (first, second) = window.as_slices();
+    writeln!(out, "as slices: {:?} and {:?}", first, second)?;
+    writeln!(out, "{}", analyze_numbers(window.make_contiguous()))?;
+
+    // A `Reject` buffer keeps the first elements instead
+    let mut first_two: RingBuffer<f64, 2> = RingBuffer::new(Overflow::Reject);
+    for sample in samples {
+        if let Err(err) = first_two.push_back(sample) {
+            writeln!(out, "cannot push {}: {}", err.0, err)?;
+        }
+    }
+
+    // A `Grid` stores its rows one after the other in a single buffer, so
+    // each row can be borrowed as a slice, while a column is every
+    // `width`-th element
+    let board = Grid::from_fn(4, 3, |row, col| (row * 4 + col) * 7 % 11);
+    writeln!(out, "grid:\n{}", board)?;
+    writeln!(out, "row 1: {:?}", board.row(1))?;
+    writeln!(out, "column 2: {:?}", board.column(2).collect::<Vec<_>>())?;
+
+    // A view borrows a rectangle of the grid, much like `&xs[1..4]` borrows
+    // a section of an array
+    let corner = board.view(1..3, 2..4);
+    writeln!(out, "rows 1..3, columns 2..4:\n{}", corner)?;
+
+    let around: Vec<_> = board.neighbors(0, 1, Connectivity::Four).map(|(_, x)| x).collect();
+    writeln!(out, "4 neighbors of (0, 1): {:?}", around)?;
+    let around: Vec<_> = board.neighbors(0, 1, Connectivity::Eight).map(|(_, x)| x).collect();
+    writeln!(out, "8 neighbors of (0, 1): {:?}", around)?;
+
+    Ok(())
+}
@@ -0,0 +1,118 @@
+// Analyzing a slice without printing it: `analyze_slice` borrows any slice and
+// returns a `SliceReport` describing it. Printing is left to the caller,
+// through the report's `fmt::Display` implementation.
+//
+// An empty slice has no first element, no minimum and no mean, so every
+// field that needs at least one element is an `Option`.
+
+use std::cmp::Ordering;
+use std::fmt::{self, Debug};
+
+#[derive(Debug, PartialEq)]
+pub struct SliceReport<'a, T> {
+    pub len: usize,
+    pub first: Option<&'a T>,
+    pub last: Option<&'a T>,
+    pub min: Option<&'a T>,
+    pub max: Option<&'a T>,
+    // Only filled in by `analyze_numbers`.
+    pub stats: Option<Stats>,
+}
+
+// Statistics computed in `f64`, whatever the element type.
+#[derive(Debug, PartialEq)]
+pub struct Stats {
+    pub sum: f64,
+    pub mean: f64,
+    pub median: f64,
+    // Population variance: the mean of the squared distances to the mean.
+    pub variance: f64,
+}
+
+// Works for any slice whose elements can be printed and compared.
+// `PartialOrd` is enough: an element that compares with nothing, like a
+// `NaN`, simply never becomes the minimum or the maximum.
+pub fn analyze_slice<T: Debug + PartialOrd>(slice: &[T]) -> SliceReport<'_, T> {
+    let mut min = slice.first();
+    let mut max = slice.first();
+
+    for x in slice {
+        if min.is_some_and(|m| x.partial_cmp(m) == Some(Ordering::Less)) {
+            min = Some(x);
+        }
+        if max.is_some_and(|m| x.partial_cmp(m) == Some(Ordering::Greater)) {
+            max = Some(x);
+        }
+    }
+
+    SliceReport {
+        len: slice.len(),
+        first: slice.first(),
+        last: slice.last(),
+        min,
+        max,
+        stats: None,
+    }
+}
+
+// Like `analyze_slice`, plus the statistics of numeric slices. Numeric here
+// means any type converting to `f64` without loss: `i32`, `u8`, `f32`, ...
+pub fn analyze_numbers<T>(slice: &[T]) -> SliceReport<'_, T>
+where
+    T: Debug + PartialOrd + Copy + Into<f64>,
+{
+    SliceReport {
+        stats: stats(slice),
+        ..analyze_slice(slice)
+    }
+}
+
+fn stats<T: Copy + Into<f64>>(slice: &[T]) -> Option<Stats> {
+    if slice.is_empty() {
+        return None;
+    }
+
+    let mut values: Vec<f64> = slice.iter().map(|&x| x.into()).collect();
+    let n = values.len() as f64;
+
+    let sum: f64 = values.iter().sum();
+    let mean = sum / n;
+    let variance = values.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / n;
+
+    // The median needs the values in order. `total_cmp` also orders NaNs,
+    // so the sort never panics.
+    values.sort_by(f64::total_cmp);
+    let middle = values.len() / 2;
+    let median = if values.len().is_multiple_of(2) {
+        (values[middle - 1] + values[middle]) / 2.0
+    } else {
+        values[middle]
+    };
+
+    Some(Stats { sum, mean, median, variance })
+}
+
+impl<T: Debug> fmt::Display for SliceReport<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // `first`, `last`, `min` and `max` are all `Some` for a non-empty slice.
+        let (Some(first), Some(last), Some(min), Some(max)) =
+            (self.first, self.last, self.min, self.max)
+        else {
+            return write!(f, "the slice is empty");
+        };
+
+        writeln!(f, "the slice has {} elements", self.len)?;
+        writeln!(f, "first: {:?}, last: {:?}", first, last)?;
+        write!(f, "min: {:?}, max: {:?}", min, max)?;
+
+        if let Some(stats) = &self.stats {
+            write!(
+                f,
+                "\nsum: {}, mean: {}, median: {}, variance: {}",
+                stats.sum, stats.mean, stats.median, stats.variance
+            )?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,306 @@
+// A ring buffer keeps the last `N` elements pushed into it, in an array
+// whose size is fixed at compile time. Elements are pushed at the back and
+// popped from the front; when the buffer is full, its `Overflow` mode decides
+// whether a new element pushes the oldest one out or is turned away.
+//
+// The elements live in `data[head..head + len]`, wrapping around the end of
+// the array back to its start. Seen from memory, the logical sequence is
+// therefore at most two contiguous pieces, which is what `as_slices` returns.
+// As in `ArrayVec`, the slots holding no element are left uninitialized.
+
+use std::fmt;
+use std::iter;
+use std::mem::MaybeUninit;
+use std::ptr;
+use std::slice;
+
+use crate::arrayvec::CapacityError;
+
+// What `push_back` does when the buffer is full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Overflow {
+    // Drop the oldest element to make room: a rolling window.
+    Overwrite,
+    // Refuse the new element and hand it back.
+    Reject,
+}
+
+pub struct RingBuffer<T, const N: usize> {
+    data: [MaybeUninit<T>; N],
+    head: usize,
+    len: usize,
+    overflow: Overflow,
+}
+
+pub type Iter<'a, T> = iter::Chain<slice::Iter<'a, T>, slice::Iter<'a, T>>;
+pub type IterMut<'a, T> = iter::Chain<slice::IterMut<'a, T>, slice::IterMut<'a, T>>;
+
+impl<T, const N: usize> RingBuffer<T, N> {
+    pub const fn new(overflow: Overflow) -> RingBuffer<T, N> {
+        RingBuffer {
+            data: [const { MaybeUninit::uninit() }; N],
+            head: 0,
+            len: 0,
+            overflow,
+        }
+    }
+
+    pub const fn overflow(&self) -> Overflow {
+        self.overflow
+    }
+
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub const fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    // Push at the back. When the buffer is full, an `Overwrite` buffer returns
+    // the oldest element it dropped to make room, and a `Reject` buffer fails.
+    pub fn push_back(&mut self, element: T) -> Result<Option<T>, CapacityError<T>> {
+        if !self.is_full() {
+            let slot = self.slot(self.len);
+            self.data[slot].write(element);
+            self.len += 1;
+            return Ok(None);
+        }
+
+        match self.overflow {
+            Overflow::Reject => Err(CapacityError(element)),
+            // A buffer that can't hold anything drops whatever comes in.
+            Overflow::Overwrite if N == 0 => Ok(Some(element)),
+            Overflow::Overwrite => {
+                // The front slot becomes the back slot: swap the new element
+                // in and move `head` past it.
+                // SAFETY: the buffer is full, so the front slot is initialized.
+                let oldest = unsafe { self.data[self.head].assume_init_read() };
+                self.data[self.head].write(element);
+                self.head = (self.head + 1) % N;
+                Ok(Some(oldest))
+            }
+        }
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        let slot = self.head;
+        self.head = (self.head + 1) % N;
+        self.len -= 1;
+        // SAFETY: the slot was the front, and is now outside the buffer.
+        Some(unsafe { self.data[slot].assume_init_read() })
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        self.len -= 1;
+        let slot = self.slot(self.len);
+        // SAFETY: the slot was the back, and is now outside the buffer.
+        Some(unsafe { self.data[slot].assume_init_read() })
+    }
+
+    // The element `index` places from the front, the oldest one being 0.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len {
+            return None;
+        }
+        // SAFETY: `index < len`, so the slot is initialized.
+        Some(unsafe { self.data[self.slot(index)].assume_init_ref() })
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.len {
+            return None;
+        }
+        let slot = self.slot(index);
+        // SAFETY: `index < len`, so the slot is initialized.
+        Some(unsafe { self.data[slot].assume_init_mut() })
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        self.get(self.len.checked_sub(1)?)
+    }
+
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(0)
+    }
+
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(self.len.checked_sub(1)?)
+    }
+
+    // The elements in order, as the part from `head` to the end of the array
+    // followed by the part that wrapped around to its start. The second slice
+    // is empty when nothing wrapped.
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let (first, second) = self.ranges();
+        let data = self.data.as_ptr() as *const T;
+        // SAFETY: `ranges` covers exactly the initialized slots.
+        unsafe {
+            (
+                slice::from_raw_parts(data.add(first.0), first.1),
+                slice::from_raw_parts(data, second),
+            )
+        }
+    }
+
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let (first, second) = self.ranges();
+        let data = self.data.as_mut_ptr() as *mut T;
+        // SAFETY: `ranges` covers exactly the initialized slots, and the two
+        // ranges don't overlap.
+        unsafe {
+            (
+                slice::from_raw_parts_mut(data.add(first.0), first.1),
+                slice::from_raw_parts_mut(data, second),
+            )
+        }
+    }
+
+    // Rotate the array so that the elements start at its first slot, and
+    // return them as a single slice.
+    pub fn make_contiguous(&mut self) -> &mut [T] {
+        // Moving uninitialized slots around is fine, they are never read.
+        self.data.rotate_left(self.head);
+        self.head = 0;
+        self.as_mut_slices().0
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        let (first, second) = self.as_slices();
+        first.iter().chain(second)
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let (first, second) = self.as_mut_slices();
+        first.iter_mut().chain(second)
+    }
+
+    pub fn clear(&mut self) {
+        let (first, second) = self.as_mut_slices();
+        let (first, second) = (first as *mut [T], second as *mut [T]);
+        // Empty the buffer first: if a drop panics, the rest is leaked rather
+        // than dropped twice.
+        self.head = 0;
+        self.len = 0;
+        // SAFETY: both slices were initialized and are no longer in the buffer.
+        unsafe {
+            ptr::drop_in_place(first);
+            ptr::drop_in_place(second);
+        }
+    }
+
+    // The array slot of the element `index` places from the front.
+    fn slot(&self, index: usize) -> usize {
+        (self.head + index) % N
+    }
+
+    // `(start, len)` of the first piece and the length of the second, which
+    // always starts at slot 0.
+    fn ranges(&self) -> ((usize, usize), usize) {
+        let first = self.len.min(N - self.head);
+        ((self.head, first), self.len - first)
+    }
+}
+
+impl<T, const N: usize> Drop for RingBuffer<T, N> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for RingBuffer<T, N> {
+    fn clone(&self) -> RingBuffer<T, N> {
+        let mut clone = RingBuffer::new(self.overflow);
+        for element in self.iter() {
+            // Can't fail: `clone` has the same capacity as `self`.
+            let _ = clone.push_back(element.clone());
+        }
+        clone
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for RingBuffer<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+// Two buffers are equal when they hold the same elements in the same order,
+// wherever those elements sit in their arrays.
+impl<T: PartialEq, const N: usize> PartialEq for RingBuffer<T, N> {
+    fn eq(&self, other: &RingBuffer<T, N>) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for RingBuffer<T, N> {}
+
+impl<'a, T, const N: usize> IntoIterator for &'a RingBuffer<T, N> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut RingBuffer<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+impl<T, const N: usize> IntoIterator for RingBuffer<T, N> {
+    type Item = T;
+    type IntoIter = IntoIter<T, N>;
+
+    fn into_iter(self) -> IntoIter<T, N> {
+        IntoIter { buffer: self }
+    }
+}
+
+// Moves the elements out of a `RingBuffer`, oldest first.
+pub struct IntoIter<T, const N: usize> {
+    buffer: RingBuffer<T, N>,
+}
+
+impl<T, const N: usize> Iterator for IntoIter<T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.buffer.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.buffer.len, Some(self.buffer.len))
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for IntoIter<T, N> {
+    fn next_back(&mut self) -> Option<T> {
+        self.buffer.pop_back()
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for IntoIter<T, N> {}
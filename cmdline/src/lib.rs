@@ -0,0 +1,194 @@
+// A tiny command line parser shared by the example binaries and `rbe`.
+//
+// Every input of an example is a flag with a value, `--name value` or
+// `--name=value`, and every flag has a default so running an example without
+// arguments behaves exactly like the original, hard-coded version. A few
+// flags are switches that take no value, like `--print-config`.
+
+use std::env;
+use std::fmt;
+use std::io::{self, Write};
+use std::process::ExitCode;
+use std::str::FromStr;
+
+#[derive(Debug)]
+pub enum Error {
+    // `-h` or `--help` was given: print the usage and stop.
+    Help,
+    // The command line is wrong, the message says why.
+    Usage(String),
+    // The example itself failed while writing its output.
+    Io(io::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Help => write!(f, "help requested"),
+            Error::Usage(message) => write!(f, "{}", message),
+            Error::Io(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Error {
+        Error::Io(err)
+    }
+}
+
+// The flags given on the command line, in order, with their value if they
+// have one. Flags are taken out as the example asks for them, whatever is
+// left over at the end is unknown.
+#[derive(Debug, Default)]
+pub struct Args {
+    flags: Vec<(String, Option<String>)>,
+}
+
+impl Args {
+    pub fn parse<I>(args: I) -> Result<Args, Error>
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        let mut flags = Vec::new();
+        let mut args = args.into_iter().map(Into::into).peekable();
+
+        while let Some(arg) = args.next() {
+            if arg == "-h" || arg == "--help" {
+                return Err(Error::Help);
+            }
+
+            let flag = match arg.strip_prefix("--") {
+                Some(flag) if !flag.is_empty() => flag,
+                _ => return Err(Error::Usage(format!("unexpected argument `{}`", arg))),
+            };
+
+            // The value is whatever follows, even when it starts with a
+            // dash, so that negative numbers work: `--n -5`. A flag followed
+            // by another `--flag`, or by nothing, has no value.
+            let (name, value) = match flag.split_once('=') {
+                Some((name, value)) => (name.to_string(), Some(value.to_string())),
+                None => match args.next_if(|next| !next.starts_with("--")) {
+                    Some(value) => (flag.to_string(), Some(value)),
+                    None => (flag.to_string(), None),
+                },
+            };
+
+            if flags.iter().any(|(seen, _)| *seen == name) {
+                return Err(Error::Usage(format!("`--{}` given more than once", name)));
+            }
+            flags.push((name, value));
+        }
+
+        Ok(Args { flags })
+    }
+
+    // Take the value of `--name` out of the arguments, or `default` when absent.
+    pub fn get<T>(&mut self, name: &str, default: T) -> Result<T, Error>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        Ok(self.get_opt(name)?.unwrap_or(default))
+    }
+
+    // Like `get`, for a flag without a default: `None` when absent.
+    pub fn get_opt<T>(&mut self, name: &str) -> Result<Option<T>, Error>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        match self.take_value(name)? {
+            Some(value) => parse_value(name, &value).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    // Like `get`, for a comma separated list of values: `--xs 1,2,3`.
+    pub fn get_list<T>(&mut self, name: &str, default: Vec<T>) -> Result<Vec<T>, Error>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        match self.take_value(name)? {
+            Some(value) if value.is_empty() => Ok(Vec::new()),
+            Some(value) => value.split(',').map(|item| parse_value(name, item.trim())).collect(),
+            None => Ok(default),
+        }
+    }
+
+    // Whether the switch `--name` was given. Switches take no value.
+    pub fn switch(&mut self, name: &str) -> Result<bool, Error> {
+        match self.take(name) {
+            Some(None) => Ok(true),
+            Some(Some(_)) => Err(Error::Usage(format!("`--{}` takes no value", name))),
+            None => Ok(false),
+        }
+    }
+
+    // Fail if any flag was not asked for by the example.
+    pub fn finish(self) -> Result<(), Error> {
+        match self.flags.first() {
+            Some((name, _)) => Err(Error::Usage(format!("unknown flag `--{}`", name))),
+            None => Ok(()),
+        }
+    }
+
+    fn take(&mut self, name: &str) -> Option<Option<String>> {
+        let index = self.flags.iter().position(|(flag, _)| flag == name)?;
+        Some(self.flags.remove(index).1)
+    }
+
+    // Like `take`, for a flag that needs a value.
+    fn take_value(&mut self, name: &str) -> Result<Option<String>, Error> {
+        match self.take(name) {
+            Some(Some(value)) => Ok(Some(value)),
+            Some(None) => Err(Error::Usage(format!("missing value for `--{}`", name))),
+            None => Ok(None),
+        }
+    }
+}
+
+fn parse_value<T>(name: &str, value: &str) -> Result<T, Error>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    value
+        .parse()
+        .map_err(|err| Error::Usage(format!("invalid value `{}` for `--{}`: {}", value, name, err)))
+}
+
+// Turn the outcome of running an example into an exit code, printing the
+// usage for `--help` and for command line mistakes.
+pub fn report(usage: &str, result: Result<(), Error>) -> ExitCode {
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(Error::Help) => {
+            println!("{}", usage);
+            ExitCode::SUCCESS
+        }
+        Err(Error::Usage(message)) => {
+            eprintln!("error: {}\n\n{}", message, usage);
+            ExitCode::from(2)
+        }
+        Err(Error::Io(err)) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+// The whole `main` of an example binary: parse the process arguments and
+// hand them to the example's `run_args`, writing to standard output.
+pub fn main(usage: &str, run_args: fn(Args, &mut dyn Write) -> Result<(), Error>) -> ExitCode {
+    let mut out = io::stdout().lock();
+    let result = Args::parse(env::args().skip(1))
+        .and_then(|args| run_args(args, &mut out))
+        .and_then(|()| out.flush().map_err(Error::from));
+
+    report(usage, result)
+}
@@ -0,0 +1,590 @@
+// A small binary codec: `Encode` writes a value as bytes, `Decode` reads it
+// back. The same value has several byte representations, chosen by `Format`:
+//
+//   300u32, big endian      00 00 01 2C    most significant byte first
+//   300u32, little endian   2C 01 00 00    least significant byte first
+//   300u32, varint          AC 02          LEB128, 7 bits per byte
+//
+// Values are written one after the other, with nothing in between: a struct
+// is its fields in order, a `Vec` or a `String` its length then its items, an
+// enum a tag byte then the fields of its variant. Nothing says what type the
+// bytes are, so they must be decoded as the type they were encoded from.
+//
+// A truncated or corrupt input is an error, never a panic, and the error
+// gives the byte offset where decoding stopped.
+
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    // Integers and floats in their full width, most significant byte first.
+    BigEndian,
+    // The same, least significant byte first, as x86 and ARM store them.
+    LittleEndian,
+    // Integers as LEB128: 7 bits per byte, least significant first, with the
+    // high bit set on every byte but the last, so small values take a single
+    // byte. Signed ones are sign-extended (SLEB128), so -1 is a single byte
+    // too. Floats have no such form and are little endian.
+    Varint,
+}
+
+impl Format {
+    pub const ALL: [Format; 3] = [Format::BigEndian, Format::LittleEndian, Format::Varint];
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(match self {
+            Format::BigEndian => "big endian",
+            Format::LittleEndian => "little endian",
+            Format::Varint => "varint",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeErrorKind {
+    // The input ends in the middle of a value.
+    UnexpectedEnd { needed: usize },
+    // A varint goes on for more bytes than its type has bits.
+    VarintTooLong,
+    // The value doesn't fit the type decoded: a varint, or a `usize` wider
+    // than this platform's.
+    OutOfRange(&'static str),
+    InvalidBool(u8),
+    InvalidChar(u32),
+    InvalidUtf8,
+    // The tag byte of an enum names no variant.
+    InvalidTag { ty: &'static str, tag: u8 },
+    // `decode` read a whole value, but bytes are left.
+    TrailingBytes(usize),
+}
+
+impl fmt::Display for DecodeErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DecodeErrorKind::UnexpectedEnd { needed: 1 } => write!(f, "unexpected end of input, 1 more byte needed"),
+            DecodeErrorKind::UnexpectedEnd { needed } => write!(f, "unexpected end of input, {} more bytes needed", needed),
+            DecodeErrorKind::VarintTooLong => write!(f, "varint too long for its type"),
+            DecodeErrorKind::OutOfRange(ty) => write!(f, "value out of range for `{}`", ty),
+            DecodeErrorKind::InvalidBool(byte) => write!(f, "invalid bool 0x{:02X}, expected 0 or 1", byte),
+            DecodeErrorKind::InvalidChar(value) => write!(f, "invalid char 0x{:X}", value),
+            DecodeErrorKind::InvalidUtf8 => write!(f, "invalid UTF-8"),
+            DecodeErrorKind::InvalidTag { ty, tag } => write!(f, "invalid tag {} for `{}`", tag, ty),
+            DecodeErrorKind::TrailingBytes(1) => write!(f, "1 byte left after the value"),
+            DecodeErrorKind::TrailingBytes(count) => write!(f, "{} bytes left after the value", count),
+        }
+    }
+}
+
+// What went wrong, and at which byte of the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodeError {
+    pub offset: usize,
+    pub kind: DecodeErrorKind,
+}
+
+impl DecodeError {
+    pub fn new(offset: usize, kind: DecodeErrorKind) -> DecodeError {
+        DecodeError { offset, kind }
+    }
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "at byte {}: {}", self.offset, self.kind)
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+pub trait Encode {
+    fn encode(&self, encoder: &mut Encoder);
+}
+
+pub trait Decode: Sized {
+    fn decode(decoder: &mut Decoder) -> Result<Self, DecodeError>;
+}
+
+pub fn encode<T: Encode + ?Sized>(value: &T, format: Format) -> Vec<u8> {
+    let mut encoder = Encoder::new(format);
+    value.encode(&mut encoder);
+    encoder.into_bytes()
+}
+
+// Decode a value that takes all of `bytes`.
+pub fn decode<T: Decode>(bytes: &[u8], format: Format) -> Result<T, DecodeError> {
+    let mut decoder = Decoder::new(bytes, format);
+    let value = T::decode(&mut decoder)?;
+    match decoder.remaining() {
+        0 => Ok(value),
+        count => Err(DecodeError::new(decoder.offset(), DecodeErrorKind::TrailingBytes(count))),
+    }
+}
+
+pub struct Encoder {
+    format: Format,
+    bytes: Vec<u8>,
+}
+
+impl Encoder {
+    pub fn new(format: Format) -> Encoder {
+        Encoder { format, bytes: Vec::new() }
+    }
+
+    pub fn format(&self) -> Format {
+        self.format
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+
+    pub fn write_u8(&mut self, byte: u8) {
+        self.bytes.push(byte);
+    }
+
+    pub fn write_bytes(&mut self, bytes: &[u8]) {
+        self.bytes.extend_from_slice(bytes);
+    }
+
+    // The length of a `Vec` or a `String`, as a `u64` so it reads the same
+    // on 32 and 64 bit platforms.
+    pub fn write_len(&mut self, len: usize) {
+        (len as u64).encode(self);
+    }
+
+    fn write_uleb(&mut self, mut value: u128) {
+        loop {
+            let byte = (value & 0x7f) as u8;
+            value >>= 7;
+            if value == 0 {
+                self.write_u8(byte);
+                return;
+            }
+            self.write_u8(byte | 0x80);
+        }
+    }
+
+    // The last byte is the one whose bit 6, the sign, extends to the rest.
+    fn write_sleb(&mut self, mut value: i128) {
+        loop {
+            let byte = (value & 0x7f) as u8;
+            value >>= 7;
+            let negative = byte & 0x40 != 0;
+            if (value == 0 && !negative) || (value == -1 && negative) {
+                self.write_u8(byte);
+                return;
+            }
+            self.write_u8(byte | 0x80);
+        }
+    }
+}
+
+pub struct Decoder<'a> {
+    format: Format,
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Decoder<'a> {
+    pub fn new(bytes: &'a [u8], format: Format) -> Decoder<'a> {
+        Decoder { format, bytes, offset: 0 }
+    }
+
+    pub fn format(&self) -> Format {
+        self.format
+    }
+
+    // The offset of the next byte to read.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.bytes.len() - self.offset
+    }
+
+    pub fn read_bytes(&mut self, len: usize) -> Result<&'a [u8], DecodeError> {
+        if len > self.remaining() {
+            let needed = len - self.remaining();
+            return Err(DecodeError::new(self.bytes.len(), DecodeErrorKind::UnexpectedEnd { needed }));
+        }
+        let bytes = &self.bytes[self.offset..self.offset + len];
+        self.offset += len;
+        Ok(bytes)
+    }
+
+    pub fn read_u8(&mut self) -> Result<u8, DecodeError> {
+        Ok(self.read_bytes(1)?[0])
+    }
+
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N], DecodeError> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.read_bytes(N)?);
+        Ok(array)
+    }
+
+    pub fn read_len(&mut self) -> Result<usize, DecodeError> {
+        usize::decode(self)
+    }
+
+    // A LEB128 varint of a type of `bits` bits, which takes at most one byte
+    // per 7 bits. Whether the value fits the type is left to the caller.
+    fn read_uleb(&mut self, bits: u32) -> Result<u128, DecodeError> {
+        let start = self.offset;
+        let mut value = 0;
+        let mut shift = 0;
+        loop {
+            let byte = self.read_u8()?;
+            let low = (byte & 0x7f) as u128;
+            // Only the 19th byte of a `u128` can have bits beyond 128.
+            if low > u128::MAX >> shift {
+                return Err(DecodeError::new(start, DecodeErrorKind::OutOfRange("u128")));
+            }
+            value |= low << shift;
+            shift += 7;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+            if shift >= bits {
+                return Err(DecodeError::new(start, DecodeErrorKind::VarintTooLong));
+            }
+        }
+    }
+
+    fn read_sleb(&mut self, bits: u32) -> Result<i128, DecodeError> {
+        let start = self.offset;
+        let mut value = 0;
+        let mut shift = 0;
+        loop {
+            let byte = self.read_u8()?;
+            value |= ((byte & 0x7f) as i128) << shift;
+            shift += 7;
+            if byte & 0x80 == 0 {
+                if shift < 128 && byte & 0x40 != 0 {
+                    value |= -1 << shift;
+                }
+                // Only the 19th byte of an `i128` can have bits beyond 128,
+                // and they must all repeat bit 127, the sign.
+                if shift > 128 && !matches!(byte >> 1, 0 | 0x3f) {
+                    return Err(DecodeError::new(start, DecodeErrorKind::OutOfRange("i128")));
+                }
+                return Ok(value);
+            }
+            if shift >= bits {
+                return Err(DecodeError::new(start, DecodeErrorKind::VarintTooLong));
+            }
+        }
+    }
+}
+
+// A byte is a byte in every format, and so are `i8` and `bool`.
+impl Encode for u8 {
+    fn encode(&self, encoder: &mut Encoder) {
+        encoder.write_u8(*self);
+    }
+}
+
+impl Decode for u8 {
+    fn decode(decoder: &mut Decoder) -> Result<u8, DecodeError> {
+        decoder.read_u8()
+    }
+}
+
+impl Encode for i8 {
+    fn encode(&self, encoder: &mut Encoder) {
+        encoder.write_u8(*self as u8);
+    }
+}
+
+impl Decode for i8 {
+    fn decode(decoder: &mut Decoder) -> Result<i8, DecodeError> {
+        Ok(decoder.read_u8()? as i8)
+    }
+}
+
+impl Encode for bool {
+    fn encode(&self, encoder: &mut Encoder) {
+        encoder.write_u8(*self as u8);
+    }
+}
+
+impl Decode for bool {
+    fn decode(decoder: &mut Decoder) -> Result<bool, DecodeError> {
+        let offset = decoder.offset();
+        match decoder.read_u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            byte => Err(DecodeError::new(offset, DecodeErrorKind::InvalidBool(byte))),
+        }
+    }
+}
+
+// `$write` and `$read` are the LEB128 flavor of the type, `$wide` the type
+// the varint is read into before it's checked against `$ty`.
+macro_rules! int {
+    ($($ty:ident: $write:ident $read:ident $wide:ident),*) => {$(
+        impl Encode for $ty {
+            fn encode(&self, encoder: &mut Encoder) {
+                match encoder.format() {
+                    Format::BigEndian => encoder.write_bytes(&self.to_be_bytes()),
+                    Format::LittleEndian => encoder.write_bytes(&self.to_le_bytes()),
+                    Format::Varint => encoder.$write(*self as $wide),
+                }
+            }
+        }
+
+        impl Decode for $ty {
+            fn decode(decoder: &mut Decoder) -> Result<$ty, DecodeError> {
+                let start = decoder.offset();
+                match decoder.format() {
+                    Format::BigEndian => Ok($ty::from_be_bytes(decoder.read_array()?)),
+                    Format::LittleEndian => Ok($ty::from_le_bytes(decoder.read_array()?)),
+                    Format::Varint => {
+                        let value = decoder.$read($ty::BITS)?;
+                        $ty::try_from(value)
+                            .map_err(|_| DecodeError::new(start, DecodeErrorKind::OutOfRange(stringify!($ty))))
+                    }
+                }
+            }
+        }
+    )*};
+}
+
+int!(
+    u16: write_uleb read_uleb u128,
+    u32: write_uleb read_uleb u128,
+    u64: write_uleb read_uleb u128,
+    u128: write_uleb read_uleb u128,
+    i16: write_sleb read_sleb i128,
+    i32: write_sleb read_sleb i128,
+    i64: write_sleb read_sleb i128,
+    i128: write_sleb read_sleb i128
+);
+
+// `usize` and `isize` are written as 64 bits whatever the platform, so the
+// bytes can be read on another one, as long as the value fits.
+macro_rules! size {
+    ($($ty:ident: $as:ident),*) => {$(
+        impl Encode for $ty {
+            fn encode(&self, encoder: &mut Encoder) {
+                (*self as $as).encode(encoder);
+            }
+        }
+
+        impl Decode for $ty {
+            fn decode(decoder: &mut Decoder) -> Result<$ty, DecodeError> {
+                let start = decoder.offset();
+                $ty::try_from($as::decode(decoder)?)
+                    .map_err(|_| DecodeError::new(start, DecodeErrorKind::OutOfRange(stringify!($ty))))
+            }
+        }
+    )*};
+}
+
+size!(usize: u64, isize: i64);
+
+macro_rules! float {
+    ($($ty:ident),*) => {$(
+        impl Encode for $ty {
+            fn encode(&self, encoder: &mut Encoder) {
+                match encoder.format() {
+                    Format::BigEndian => encoder.write_bytes(&self.to_be_bytes()),
+                    Format::LittleEndian | Format::Varint => encoder.write_bytes(&self.to_le_bytes()),
+                }
+            }
+        }
+
+        impl Decode for $ty {
+            fn decode(decoder: &mut Decoder) -> Result<$ty, DecodeError> {
+                match decoder.format() {
+                    Format::BigEndian => Ok($ty::from_be_bytes(decoder.read_array()?)),
+                    Format::LittleEndian | Format::Varint => Ok($ty::from_le_bytes(decoder.read_array()?)),
+                }
+            }
+        }
+    )*};
+}
+
+float!(f32, f64);
+
+// A char is its code point.
+impl Encode for char {
+    fn encode(&self, encoder: &mut Encoder) {
+        u32::from(*self).encode(encoder);
+    }
+}
+
+impl Decode for char {
+    fn decode(decoder: &mut Decoder) -> Result<char, DecodeError> {
+        let start = decoder.offset();
+        let value = u32::decode(decoder)?;
+        char::from_u32(value).ok_or(DecodeError::new(start, DecodeErrorKind::InvalidChar(value)))
+    }
+}
+
+impl Encode for () {
+    fn encode(&self, _: &mut Encoder) {}
+}
+
+impl Decode for () {
+    fn decode(_: &mut Decoder) -> Result<(), DecodeError> {
+        Ok(())
+    }
+}
+
+// A string is its length in bytes, then its UTF-8.
+impl Encode for str {
+    fn encode(&self, encoder: &mut Encoder) {
+        encoder.write_len(self.len());
+        encoder.write_bytes(self.as_bytes());
+    }
+}
+
+impl Encode for String {
+    fn encode(&self, encoder: &mut Encoder) {
+        self.as_str().encode(encoder);
+    }
+}
+
+impl Decode for String {
+    fn decode(decoder: &mut Decoder) -> Result<String, DecodeError> {
+        let len = decoder.read_len()?;
+        let start = decoder.offset();
+        let bytes = decoder.read_bytes(len)?;
+        match std::str::from_utf8(bytes) {
+            Ok(text) => Ok(text.to_string()),
+            Err(err) => Err(DecodeError::new(start + err.valid_up_to(), DecodeErrorKind::InvalidUtf8)),
+        }
+    }
+}
+
+// The most items a `Vec` of a zero-sized type such as `()` is decoded with.
+pub const MAX_EMPTY_ITEMS: usize = 1 << 20;
+
+// A slice is its length, then its items.
+impl<T: Encode> Encode for [T] {
+    fn encode(&self, encoder: &mut Encoder) {
+        encoder.write_len(self.len());
+        for item in self {
+            item.encode(encoder);
+        }
+    }
+}
+
+impl<T: Encode> Encode for Vec<T> {
+    fn encode(&self, encoder: &mut Encoder) {
+        self.as_slice().encode(encoder);
+    }
+}
+
+impl<T: Decode> Decode for Vec<T> {
+    fn decode(decoder: &mut Decoder) -> Result<Vec<T>, DecodeError> {
+        let start = decoder.offset();
+        let len = decoder.read_len()?;
+        // Items taking no bytes, as `()` does, aren't bounded by the input:
+        // a corrupt length would spin decoding them.
+        if std::mem::size_of::<T>() == 0 && len > MAX_EMPTY_ITEMS {
+            return Err(DecodeError::new(start, DecodeErrorKind::OutOfRange("Vec")));
+        }
+        // A corrupt length mustn't allocate more than the input can fill.
+        let mut items = Vec::with_capacity(len.min(decoder.remaining()));
+        for _ in 0..len {
+            items.push(T::decode(decoder)?);
+        }
+        Ok(items)
+    }
+}
+
+// An array has a fixed length, so only its items are written.
+impl<T: Encode, const N: usize> Encode for [T; N] {
+    fn encode(&self, encoder: &mut Encoder) {
+        for item in self {
+            item.encode(encoder);
+        }
+    }
+}
+
+impl<T: Decode, const N: usize> Decode for [T; N] {
+    fn decode(decoder: &mut Decoder) -> Result<[T; N], DecodeError> {
+        let mut items = Vec::with_capacity(N);
+        for _ in 0..N {
+            items.push(T::decode(decoder)?);
+        }
+        match items.try_into() {
+            Ok(array) => Ok(array),
+            Err(_) => unreachable!("{} items were decoded", N),
+        }
+    }
+}
+
+// A tag byte, 0 for `None` and 1 for `Some`, then the value if any.
+impl<T: Encode> Encode for Option<T> {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            None => encoder.write_u8(0),
+            Some(value) => {
+                encoder.write_u8(1);
+                value.encode(encoder);
+            }
+        }
+    }
+}
+
+impl<T: Decode> Decode for Option<T> {
+    fn decode(decoder: &mut Decoder) -> Result<Option<T>, DecodeError> {
+        let offset = decoder.offset();
+        match decoder.read_u8()? {
+            0 => Ok(None),
+            1 => Ok(Some(T::decode(decoder)?)),
+            tag => Err(DecodeError::new(offset, DecodeErrorKind::InvalidTag { ty: "Option", tag })),
+        }
+    }
+}
+
+impl<T: Encode + ?Sized> Encode for &T {
+    fn encode(&self, encoder: &mut Encoder) {
+        (**self).encode(encoder);
+    }
+}
+
+impl<T: Encode + ?Sized> Encode for Box<T> {
+    fn encode(&self, encoder: &mut Encoder) {
+        (**self).encode(encoder);
+    }
+}
+
+impl<T: Decode> Decode for Box<T> {
+    fn decode(decoder: &mut Decoder) -> Result<Box<T>, DecodeError> {
+        T::decode(decoder).map(Box::new)
+    }
+}
+
+// A tuple is its fields in order.
+macro_rules! tuple {
+    ($(($($name:ident),+))*) => {$(
+        impl<$($name: Encode),+> Encode for ($($name,)+) {
+            #[allow(non_snake_case)]
+            fn encode(&self, encoder: &mut Encoder) {
+                let ($($name,)+) = self;
+                $($name.encode(encoder);)+
+            }
+        }
+
+        impl<$($name: Decode),+> Decode for ($($name,)+) {
+            fn decode(decoder: &mut Decoder) -> Result<($($name,)+), DecodeError> {
+                Ok(($($name::decode(decoder)?,)+))
+            }
+        }
+    )*};
+}
+
+tuple! {
+    (A)
+    (A, B)
+    (A, B, C)
+    (A, B, C, D)
+    (A, B, C, D, E)
+    (A, B, C, D, E, F)
+}
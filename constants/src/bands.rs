@@ -0,0 +1,323 @@
+// `is_big` splits the numbers in two with a single threshold. A `Classifier`
+// splits them into any number of named bands, each with its own bounds:
+//
+//   low       below 10
+//   normal    from 10 to 20, both included
+//   high      above 20, up to 30 excluded
+//   critical  30 and more
+//
+// Bands can't overlap, but they may leave gaps: a value in a gap belongs to
+// no band.
+//
+// A value wandering around a boundary, 19.9, 20.1, 19.8, 20.2, ..., would
+// flip between two bands on every reading. With some hysteresis, a value
+// has to go past the boundary by more than a margin before it leaves its
+// current band, which only streaming classification (`Classifier::stream`)
+// can do, since it needs to know the current band.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Sub};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Bound<T> {
+    Inclusive(T),
+    Exclusive(T),
+}
+
+impl<T: Copy + PartialOrd> Bound<T> {
+    pub fn value(&self) -> T {
+        match *self {
+            Bound::Inclusive(value) | Bound::Exclusive(value) => value,
+        }
+    }
+
+    // `x` is on the right side of a lower bound.
+    fn admits_above(&self, x: T) -> bool {
+        match *self {
+            Bound::Inclusive(lower) => x >= lower,
+            Bound::Exclusive(lower) => x > lower,
+        }
+    }
+
+    // `x` is on the right side of an upper bound.
+    fn admits_below(&self, x: T) -> bool {
+        match *self {
+            Bound::Inclusive(upper) => x <= upper,
+            Bound::Exclusive(upper) => x < upper,
+        }
+    }
+}
+
+// A named range of values. A missing bound means the band goes on forever
+// in that direction.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Band<T> {
+    pub name: String,
+    pub lower: Option<Bound<T>>,
+    pub upper: Option<Bound<T>>,
+}
+
+impl<T: Copy + PartialOrd> Band<T> {
+    // A band holding every value, to be narrowed with the methods below:
+    // `Band::new("normal").at_least(10).at_most(20)`.
+    pub fn new(name: impl Into<String>) -> Band<T> {
+        Band { name: name.into(), lower: None, upper: None }
+    }
+
+    pub fn at_least(self, lower: T) -> Band<T> {
+        Band { lower: Some(Bound::Inclusive(lower)), ..self }
+    }
+
+    pub fn above(self, lower: T) -> Band<T> {
+        Band { lower: Some(Bound::Exclusive(lower)), ..self }
+    }
+
+    pub fn at_most(self, upper: T) -> Band<T> {
+        Band { upper: Some(Bound::Inclusive(upper)), ..self }
+    }
+
+    pub fn below(self, upper: T) -> Band<T> {
+        Band { upper: Some(Bound::Exclusive(upper)), ..self }
+    }
+
+    pub fn contains(&self, x: T) -> bool {
+        self.lower.is_none_or(|lower| lower.admits_above(x)) && self.upper.is_none_or(|upper| upper.admits_below(x))
+    }
+
+    // Like `contains`, with both bounds pushed `margin` further out. Moving
+    // the bounds themselves could overflow an integer near `MIN` or `MAX`:
+    // the distance past a bound is measured instead, on the side of zero
+    // where the subtraction can't overflow.
+    fn contains_within(&self, x: T, margin: T) -> bool
+    where
+        T: Default + Add<Output = T> + Sub<Output = T>,
+    {
+        let zero = T::default();
+        let above = self.lower.is_none_or(|lower| match lower {
+            Bound::Inclusive(lower) => x >= lower || if x >= zero { lower - x <= margin } else { lower <= x + margin },
+            Bound::Exclusive(lower) => x > lower || if x >= zero { lower - x < margin } else { lower < x + margin },
+        });
+        let below = self.upper.is_none_or(|upper| match upper {
+            Bound::Inclusive(upper) => x <= upper || if upper >= zero { x - upper <= margin } else { x <= upper + margin },
+            Bound::Exclusive(upper) => x < upper || if upper >= zero { x - upper < margin } else { x < upper + margin },
+        });
+        above && below
+    }
+
+    // No value fits. A bound that doesn't even compare with itself, a NaN,
+    // admits nothing either.
+    fn is_empty(&self) -> bool {
+        let bounds = self.lower.iter().chain(&self.upper);
+        if bounds.map(Bound::value).any(|bound| bound.partial_cmp(&bound).is_none()) {
+            return true;
+        }
+        match (self.lower, self.upper) {
+            (Some(Bound::Inclusive(lower)), Some(Bound::Inclusive(upper))) => lower > upper,
+            (Some(lower), Some(upper)) => lower.value() >= upper.value(),
+            _ => false,
+        }
+    }
+}
+
+// In interval notation: `normal [10, 20]`, `low (-inf, 10)`.
+impl<T: fmt::Display> fmt::Display for Band<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} ", self.name)?;
+        match &self.lower {
+            Some(Bound::Inclusive(lower)) => write!(f, "[{}", lower)?,
+            Some(Bound::Exclusive(lower)) => write!(f, "({}", lower)?,
+            None => write!(f, "(-inf")?,
+        }
+        match &self.upper {
+            Some(Bound::Inclusive(upper)) => write!(f, ", {}]", upper),
+            Some(Bound::Exclusive(upper)) => write!(f, ", {})", upper),
+            None => write!(f, ", inf)"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BandError {
+    NoBands,
+    // No value fits between the bounds, or a bound can't be compared (NaN).
+    EmptyBand { name: String },
+    // Some values would belong to both bands.
+    Overlap { first: String, second: String },
+    // Two bands share a name, so a value's band would be ambiguous.
+    DuplicateName { name: String },
+    NegativeHysteresis,
+}
+
+impl fmt::Display for BandError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BandError::NoBands => write!(f, "a classifier needs at least one band"),
+            BandError::EmptyBand { name } => write!(f, "band `{}` holds no value", name),
+            BandError::Overlap { first, second } => write!(f, "bands `{}` and `{}` overlap", first, second),
+            BandError::DuplicateName { name } => write!(f, "more than one band is named `{}`", name),
+            BandError::NegativeHysteresis => write!(f, "the hysteresis cannot be negative"),
+        }
+    }
+}
+
+impl std::error::Error for BandError {}
+
+// Bands sorted from the lowest to the highest, and the hysteresis margin.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Classifier<T> {
+    bands: Vec<Band<T>>,
+    hysteresis: T,
+}
+
+impl<T> Classifier<T>
+where
+    T: Copy + PartialOrd + Default + Add<Output = T> + Sub<Output = T>,
+{
+    // The bands may be given in any order.
+    pub fn new(mut bands: Vec<Band<T>>) -> Result<Classifier<T>, BandError> {
+        if bands.is_empty() {
+            return Err(BandError::NoBands);
+        }
+        if let Some(band) = bands.iter().find(|band| band.is_empty()) {
+            return Err(BandError::EmptyBand { name: band.name.clone() });
+        }
+        for (i, band) in bands.iter().enumerate() {
+            if bands[..i].iter().any(|other| other.name == band.name) {
+                return Err(BandError::DuplicateName { name: band.name.clone() });
+            }
+        }
+
+        // No band is empty, so every bound compares: sorting by lower bound
+        // puts each band right after the only one it could overlap with. At
+        // equal values `[10` starts before `(10`, so `[10, 10]` comes first
+        // whatever the order the bands were given in.
+        bands.sort_by(|a, b| match (a.lower, b.lower) {
+            (None, None) => Ordering::Equal,
+            (None, Some(_)) => Ordering::Less,
+            (Some(_), None) => Ordering::Greater,
+            (Some(a), Some(b)) => a.value().partial_cmp(&b.value()).unwrap_or(Ordering::Equal).then_with(|| match (a, b) {
+                (Bound::Inclusive(_), Bound::Exclusive(_)) => Ordering::Less,
+                (Bound::Exclusive(_), Bound::Inclusive(_)) => Ordering::Greater,
+                _ => Ordering::Equal,
+            }),
+        });
+        for pair in bands.windows(2) {
+            let overlap = match (pair[0].upper, pair[1].lower) {
+                (None, _) | (_, None) => true,
+                (Some(Bound::Inclusive(upper)), Some(Bound::Inclusive(lower))) => upper >= lower,
+                (Some(upper), Some(lower)) => upper.value() > lower.value(),
+            };
+            if overlap {
+                return Err(BandError::Overlap { first: pair[0].name.clone(), second: pair[1].name.clone() });
+            }
+        }
+
+        Ok(Classifier { bands, hysteresis: T::default() })
+    }
+
+    // How far past a boundary a value must go, when streaming, to leave its band.
+    pub fn with_hysteresis(self, hysteresis: T) -> Result<Classifier<T>, BandError> {
+        // Written so that a NaN margin is refused too.
+        if !matches!(hysteresis.partial_cmp(&T::default()), Some(Ordering::Greater | Ordering::Equal)) {
+            return Err(BandError::NegativeHysteresis);
+        }
+        Ok(Classifier { hysteresis, ..self })
+    }
+
+    pub fn bands(&self) -> &[Band<T>] {
+        &self.bands
+    }
+
+    // The band of `x` on its own, without hysteresis.
+    pub fn classify(&self, x: T) -> Option<&Band<T>> {
+        self.bands.iter().find(|band| band.contains(x))
+    }
+
+    // Classify every value of `values` in turn, keeping track of the
+    // current band so hysteresis can apply.
+    pub fn stream<I>(&self, values: I) -> Stream<'_, T, I::IntoIter>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        Stream { classifier: self, values: values.into_iter(), current: None, started: false }
+    }
+
+    // The band `x` belongs to, given that the previous value was in `current`.
+    fn next_band<'a>(&'a self, current: Option<&'a Band<T>>, x: T) -> Option<&'a Band<T>> {
+        match current {
+            Some(band) if band.contains_within(x, self.hysteresis) => Some(band),
+            _ => self.classify(x),
+        }
+    }
+}
+
+// One value of a stream and the band it was put in.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reading<'a, T> {
+    pub value: T,
+    pub band: Option<&'a Band<T>>,
+    // Set when the band differs from the previous value's.
+    pub transition: Option<Transition<'a, T>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Transition<'a, T> {
+    pub from: Option<&'a Band<T>>,
+    pub to: Option<&'a Band<T>>,
+}
+
+// A band name for messages, `none` for a gap between bands.
+fn name<T>(band: Option<&Band<T>>) -> &str {
+    band.map_or("none", |band| band.name.as_str())
+}
+
+impl<T> fmt::Display for Transition<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} -> {}", name(self.from), name(self.to))
+    }
+}
+
+// The iterator returned by `Classifier::stream`.
+pub struct Stream<'a, T, I> {
+    classifier: &'a Classifier<T>,
+    values: I,
+    current: Option<&'a Band<T>>,
+    started: bool,
+}
+
+impl<'a, T, I> Stream<'a, T, I>
+where
+    T: Copy + PartialOrd + Default + Add<Output = T> + Sub<Output = T>,
+    I: Iterator<Item = T>,
+{
+    // Only the readings where the band changed.
+    pub fn transitions(self) -> impl Iterator<Item = (T, Transition<'a, T>)> {
+        self.filter_map(|reading| Some((reading.value, reading.transition?)))
+    }
+}
+
+impl<'a, T, I> Iterator for Stream<'a, T, I>
+where
+    T: Copy + PartialOrd + Default + Add<Output = T> + Sub<Output = T>,
+    I: Iterator<Item = T>,
+{
+    type Item = Reading<'a, T>;
+
+    fn next(&mut self) -> Option<Reading<'a, T>> {
+        let value = self.values.next()?;
+        let band = self.classifier.next_band(self.current, value);
+
+        // The first value has no previous band to move from.
+        let changed = self.started && name(band) != name(self.current);
+        let transition = changed.then_some(Transition { from: self.current, to: band });
+
+        self.current = band;
+        self.started = true;
+        Some(Reading { value, band, transition })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.values.size_hint()
+    }
+}
@@ -0,0 +1,273 @@
+// The globals of this example are compile-time values, but a program often
+// wants them as defaults it can override at run time. A `Config` starts from
+// the `THRESHOLD` and `LANGUAGE` globals and lets each layer below override
+// them, the later layers winning:
+//
+//   1. the defaults, `THRESHOLD` and `LANGUAGE`
+//   2. a config file, `--config <path>` or `$CONSTANTS_CONFIG`
+//   3. the environment, `$CONSTANTS_THRESHOLD` and `$CONSTANTS_LANGUAGE`
+//   4. the command line, `--threshold` and `--language`
+//
+// The config file is a small subset of TOML: one `key = value` per line,
+// strings in double quotes, and `#` starting a comment.
+//
+//   # constants.toml
+//   threshold = 12
+//   language = "Rust"
+//
+// Every setting remembers the layer it came from, so `--print-config` can
+// say why a value is what it is.
+
+use std::env::VarError;
+use std::ffi::OsString;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::{LANGUAGE, THRESHOLD};
+
+// Where a setting got its value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Default,
+    File { path: PathBuf, line: usize },
+    Env(String),
+    Flag(String),
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Source::Default => write!(f, "default"),
+            Source::File { path, line } => write!(f, "file {}:{}", path.display(), line),
+            Source::Env(var) => write!(f, "environment ${}", var),
+            Source::Flag(flag) => write!(f, "flag --{}", flag),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Setting<T> {
+    pub value: T,
+    pub source: Source,
+}
+
+impl<T> Setting<T> {
+    fn default(value: T) -> Setting<T> {
+        Setting { value, source: Source::Default }
+    }
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    // The config file could not be read.
+    Read { path: PathBuf, error: io::Error },
+    // A line of the config file is not `key = value`.
+    Syntax { path: PathBuf, line: usize, message: String },
+    // A key no setting answers to, in the file or on the command line.
+    UnknownKey { key: String, source: Source },
+    // A value that doesn't suit its setting, whatever layer it came from.
+    Invalid { key: &'static str, value: String, source: Source, reason: String },
+    // An environment variable that is set, but not to valid Unicode.
+    NotUnicode { var: &'static str, value: OsString },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConfigError::Read { path, error } => write!(f, "cannot read {}: {}", path.display(), error),
+            ConfigError::Syntax { path, line, message } => {
+                write!(f, "{}:{}: {}", path.display(), line, message)
+            }
+            ConfigError::UnknownKey { key, source } => write!(f, "unknown setting `{}` ({})", key, source),
+            ConfigError::Invalid { key, value, source, reason } => {
+                write!(f, "invalid {} `{}` ({}): {}", key, value, source, reason)
+            }
+            ConfigError::NotUnicode { var, value } => write!(f, "${} is not valid Unicode: {:?}", var, value),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ConfigError::Read { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+// The environment variables read by `merge_env`.
+pub const CONFIG_VAR: &str = "CONSTANTS_CONFIG";
+pub const THRESHOLD_VAR: &str = "CONSTANTS_THRESHOLD";
+pub const LANGUAGE_VAR: &str = "CONSTANTS_LANGUAGE";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    pub threshold: Setting<i32>,
+    pub language: Setting<String>,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            threshold: Setting::default(THRESHOLD),
+            language: Setting::default(LANGUAGE.to_string()),
+        }
+    }
+}
+
+impl Config {
+    pub fn is_big(&self, n: i32) -> bool {
+        n > self.threshold.value
+    }
+
+    // Set `key` from its textual `value`, checking the value first. Every
+    // layer goes through here, so they all accept the same values.
+    pub fn set(&mut self, key: &str, value: &str, source: Source) -> Result<(), ConfigError> {
+        let invalid = |key, reason: String| ConfigError::Invalid {
+            key,
+            value: value.to_string(),
+            source: source.clone(),
+            reason,
+        };
+
+        match key {
+            "threshold" => {
+                let threshold = value.parse().map_err(|err| invalid("threshold", format!("{}", err)))?;
+                self.threshold = Setting { value: threshold, source };
+            }
+            "language" => {
+                if value.trim().is_empty() {
+                    return Err(invalid("language", "the language cannot be empty".to_string()));
+                }
+                self.language = Setting { value: value.to_string(), source };
+            }
+            _ => return Err(ConfigError::UnknownKey { key: key.to_string(), source }),
+        }
+        Ok(())
+    }
+
+    // Layer 2: read the config file at `path`.
+    pub fn merge_file(&mut self, path: &Path) -> Result<(), ConfigError> {
+        let text = fs::read_to_string(path).map_err(|error| ConfigError::Read { path: path.to_path_buf(), error })?;
+        self.merge_str(&text, path)
+    }
+
+    // Like `merge_file`, with the contents of the file already in `text`.
+    pub fn merge_str(&mut self, text: &str, path: &Path) -> Result<(), ConfigError> {
+        for (index, line) in text.lines().enumerate() {
+            let line_number = index + 1;
+            let syntax = |message: &str| ConfigError::Syntax {
+                path: path.to_path_buf(),
+                line: line_number,
+                message: message.to_string(),
+            };
+
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = line.split_once('=').ok_or_else(|| syntax("expected `key = value`"))?;
+            let key = key.trim();
+            if key.is_empty() {
+                return Err(syntax("missing key before `=`"));
+            }
+            let value = parse_value(value.trim()).map_err(syntax)?;
+
+            let source = Source::File { path: path.to_path_buf(), line: line_number };
+            self.set(key, &value, source)?;
+        }
+        Ok(())
+    }
+
+    // Layer 3: read the environment through `var`, normally `std::env::var`.
+    // A variable set to something that isn't Unicode is an error, not unset.
+    pub fn merge_env(&mut self, var: impl Fn(&str) -> Result<String, VarError>) -> Result<(), ConfigError> {
+        for (key, name) in [("threshold", THRESHOLD_VAR), ("language", LANGUAGE_VAR)] {
+            match var(name) {
+                Ok(value) => self.set(key, &value, Source::Env(name.to_string()))?,
+                Err(VarError::NotPresent) => {}
+                Err(VarError::NotUnicode(value)) => return Err(ConfigError::NotUnicode { var: name, value }),
+            }
+        }
+        Ok(())
+    }
+
+    // The configuration in the file format, each line saying where its value
+    // came from. Feeding it back as a config file gives the same values.
+    pub fn write_to(&self, f: &mut dyn io::Write) -> io::Result<()> {
+        let threshold = self.threshold.value.to_string();
+        let language = quote(&self.language.value);
+        let width = threshold.len().max(language.len());
+
+        writeln!(f, "threshold = {:width$}  # {}", threshold, self.threshold.source, width = width)?;
+        writeln!(f, "language  = {:width$}  # {}", language, self.language.source, width = width)
+    }
+}
+
+// `value` as a double-quoted string `parse_value` reads back: the TOML
+// escapes for quotes, backslashes and control characters, anything else as is.
+fn quote(value: &str) -> String {
+    let mut quoted = String::from('"');
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() => quoted.push_str(&format!("\\u{:04X}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+// The value on the right of `=`: a double-quoted string, with the TOML
+// escapes `\"`, `\\`, `\n`, `\r`, `\t`, `\uXXXX` and `\UXXXXXXXX`, or a bare
+// word such as a number. Either may be followed by a comment.
+fn parse_value(text: &str) -> Result<String, &'static str> {
+    let Some(quoted) = text.strip_prefix('"') else {
+        let value = text.split('#').next().unwrap_or("").trim();
+        if value.is_empty() {
+            return Err("missing value after `=`");
+        }
+        return Ok(value.to_string());
+    };
+
+    let mut value = String::new();
+    let mut chars = quoted.chars();
+    loop {
+        match chars.next() {
+            Some('"') => break,
+            Some('\\') => match chars.next() {
+                Some(c @ ('"' | '\\')) => value.push(c),
+                Some('n') => value.push('\n'),
+                Some('r') => value.push('\r'),
+                Some('t') => value.push('\t'),
+                Some(u @ ('u' | 'U')) => {
+                    let digits = if u == 'u' { 4 } else { 8 };
+                    let hex: String = chars.by_ref().take(digits).collect();
+                    let valid = hex.len() == digits && hex.chars().all(|c| c.is_ascii_hexdigit());
+                    match u32::from_str_radix(&hex, 16).ok().filter(|_| valid).and_then(char::from_u32) {
+                        Some(c) => value.push(c),
+                        None => return Err("`\\u` needs 4 hex digits, `\\U` 8, naming a char"),
+                    }
+                }
+                _ => return Err("unknown escape, expected one of `\\\"`, `\\\\`, `\\n`, `\\r`, `\\t`, `\\u` and `\\U`"),
+            },
+            Some(c) => value.push(c),
+            None => return Err("unterminated string"),
+        }
+    }
+
+    let rest = chars.as_str().trim();
+    if !rest.is_empty() && !rest.starts_with('#') {
+        return Err("unexpected text after the string");
+    }
+    Ok(value)
+}
@@ -0,0 +1,150 @@
+// Rust has two different types of constants which can be declared in any scope including global.
+// Both require explicit type annotation:
+// `const`: an unchangeable value (the common case)
+// `static`: a possible `mut`able variable with `static` lifetime. The static lifetime is inferred
+// and does not have to be specified. Accessing or modifying a mutable static variable is `unsafe`.
+
+use std::env;
+use std::io::{self, Write};
+use std::path::PathBuf;
+
+use cmdline::Args;
+
+pub mod bands;
+pub mod config;
+
+pub use bands::{Band, BandError, Bound, Classifier, Reading, Transition};
+pub use config::{Config, ConfigError, Setting, Source};
+
+// Globals are declared outside all other scopes.
+pub static LANGUAGE: &str = "Rust";
+pub const THRESHOLD: i32 = 10;
+
+pub fn is_big(n: i32) -> bool {
+    // Access `constant` in some function
+    n > THRESHOLD
+}
+
+pub const USAGE: &str = "\
+usage: constants [options]
+
+options:
+    --n <i32>              the number compared with the threshold (default: 16)
+    --threshold <i32>      override THRESHOLD
+    --language <string>    override LANGUAGE
+    --config <path>        read `threshold = ...` and `language = \"...\"` from a file
+    --print-config         print the configuration and where each value came from
+    -h, --help             print this message
+
+environment:
+    CONSTANTS_CONFIG       the config file, when `--config` is not given
+    CONSTANTS_THRESHOLD    override THRESHOLD, unless `--threshold` is given
+    CONSTANTS_LANGUAGE     override LANGUAGE, unless `--language` is given";
+
+// The inputs of the example, try different values for `n` with `--n`.
+pub struct Params {
+    pub n: i32,
+    pub config: Config,
+    pub print_config: bool,
+}
+
+impl Default for Params {
+    fn default() -> Params {
+        Params {
+            n: 16,
+            config: Config::default(),
+            print_config: false,
+        }
+    }
+}
+
+impl Params {
+    pub fn from_args(mut args: Args) -> Result<Params, cmdline::Error> {
+        let n = args.get("n", Params::default().n)?;
+        let print_config = args.switch("print-config")?;
+        let config_path: Option<PathBuf> = args.get_opt("config")?;
+        let threshold: Option<String> = args.get_opt("threshold")?;
+        let language: Option<String> = args.get_opt("language")?;
+        args.finish()?;
+
+        let config = load_config(config_path, threshold, language)
+            .map_err(|err| cmdline::Error::Usage(err.to_string()))?;
+
+        Ok(Params { n, config, print_config })
+    }
+}
+
+// Apply the configuration layers in order, each one overriding the previous ones.
+fn load_config(
+    path: Option<PathBuf>,
+    threshold: Option<String>,
+    language: Option<String>,
+) -> Result<Config, ConfigError> {
+    let mut config = Config::default();
+
+    if let Some(path) = path.or_else(|| env::var_os(config::CONFIG_VAR).map(PathBuf::from)) {
+        config.merge_file(&path)?;
+    }
+    config.merge_env(|name| env::var(name))?;
+    if let Some(threshold) = threshold {
+        config.set("threshold", &threshold, Source::Flag("threshold".to_string()))?;
+    }
+    if let Some(language) = language {
+        config.set("language", &language, Source::Flag("language".to_string()))?;
+    }
+    Ok(config)
+}
+
+pub fn run(out: &mut dyn Write) -> io::Result<()> {
+    run_with(out, &Params::default())
+}
+
+pub fn run_args(args: Args, out: &mut dyn Write) -> Result<(), cmdline::Error> {
+    let params = Params::from_args(args)?;
+    Ok(run_with(out, &params)?)
+}
+
+pub fn run_with(out: &mut dyn Write, params: &Params) -> io::Result<()> {
+    let n = params.n;
+    let config = &params.config;
+
+    if params.print_config {
+        return config.write_to(out);
+    }
+
+    // Access `constant` in the main thread, through the configuration: it
+    // holds `LANGUAGE` and `THRESHOLD` unless they were overridden
+    writeln!(out, "This is {}", config.language.value)?;
+    writeln!(out, "The threshold is: {}", config.threshold.value)?;
+    writeln!(out, "{} is {} than threshold", n, if config.is_big(n) { "bigger" } else { "smaller" })?;
+
+    // A single threshold splits numbers in two, a `Classifier` splits them
+    // in as many named bands as needed
+    let bands = vec![
+        Band::new("low").below(10),
+        Band::new("normal").at_least(10).at_most(20),
+        Band::new("high").above(20).below(30),
+        Band::new("critical").at_least(30),
+    ];
+    let levels = Classifier::new(bands).expect("the bands neither overlap nor are empty");
+    for band in levels.bands() {
+        writeln!(out, "band {}", band)?;
+    }
+
+    // Readings going back and forth around a boundary change band every
+    // time, unless they must go 2 past the boundary to leave their band
+    let readings = [8, 11, 9, 12, 19, 21, 19, 22, 25, 31, 29, 27];
+    let steady = levels.clone().with_hysteresis(2).expect("the hysteresis is positive");
+    for (name, classifier) in [("without", &levels), ("with", &steady)] {
+        writeln!(out, "transitions {} hysteresis:", name)?;
+        for (value, transition) in classifier.stream(readings).transitions() {
+            writeln!(out, "    {}: {}", value, transition)?;
+        }
+    }
+
+    // Error! Cannot modify a constant
+    // THRESHOLD = 5;
+    // comment out the previous line to fix the error.
+
+    Ok(())
+}
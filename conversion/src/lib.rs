@@ -0,0 +1,292 @@
+// Primitive types can be converted to each other through `casting`.
+// Rust addresses conversion between custom types like `struct` and `enum` by the use of `traits`.
+// The generic conversion will use the `From` and `Into` traits.
+// However there are more specific ones for the more common cases,
+// in particular when converting to and from `String`s.
+
+// `From` and `Into` are inherently linked. If you are able to convert type A from type B,
+// then it should be easy to believe that we should be able to convert type B to type A.
+
+// The `From` trait allows for a type to define how to create itself from another type,
+// hence providing a very simple mechanism for converting between several types.
+// There are numerous implementations of this trait within the standard library
+// for convesion of primitive and common types.
+
+// We can do similar for defining a conversion for our own type.
+
+// The `into` trait is the reciprocal of the `From` trait.
+// If you have implemented the `From` trait for your type, `Into` will call it when necessary.
+// Using the `Into` trait will typically require specification of the type to convert into
+// as the compiler is unable to determine this most of the time.
+// However this is a small trade-off considering we get the functionality for free.
+
+// Similar to `From` and `Into`, `TryFrom` and `TryInto` are generic traits for converting between types. Unlike `From/Into`, the `TryFrom/TryInto` traits are used for fallible conversions, and as such, return `Result`s.
+
+// Some values are only built to show the conversion, never read back.
+#![allow(unused_variables)]
+// `to_string` is called explicitly to show where it comes from.
+#![allow(clippy::to_string_in_format_args)]
+
+use std::convert::From;
+use std::convert::TryFrom;
+use std::convert::TryInto;
+use std::io::{self, Write};
+
+use cmdline::Args;
+use validate::Validated;
+
+mod number;
+pub mod validated;
+
+pub use number::ParseNumberError;
+pub use validated::{Bounded, Even, NonZero, Odd, Positive, Prime, ValidationError};
+
+// The `number` module gives it the arithmetic and conversions of an `i32`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Number {
+    pub value: i32,
+}
+
+impl From<i32> for Number {
+    fn from(item: i32) -> Self {
+        Number { value: item }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct EvenNumber(i32);
+
+impl EvenNumber {
+    pub fn value(&self) -> i32 {
+        self.0
+    }
+}
+
+// The error says why the conversion failed: a bare `()` would leave the
+// caller guessing. The `validated` module has more types built this way.
+impl TryFrom<i32> for EvenNumber {
+    type Error = ValidationError;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        if value % 2 == 0 {
+            Ok(EvenNumber(value))
+        } else {
+            Err(ValidationError::NotEven(value.into()))
+        }
+    }
+}
+
+// `#[derive(Validated)]` writes such a `TryFrom` from the check alone, along
+// with `FromStr`, `Deref`, `Display` and the error type, here `NotAPercentage`.
+fn is_percentage(value: &u8) -> bool {
+    *value <= 100
+}
+
+#[derive(Debug, PartialEq, Validated)]
+#[validate(fn = "is_percentage", error = "NotAPercentage")]
+pub struct Percentage(u8);
+
+// Converting to String
+// To convert any type to a `String` is as simple as implementing the `ToString` trait for the type.
+// Rather than doing so directly, you should implement the `fmt::Display` trait which
+// automagically provides `ToString` and also allows printing the types as discussed previously.
+
+use std::fmt;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq)]
+pub struct Circle {
+    pub radius: i32
+}
+
+impl fmt::Display for Circle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Circle of radius {}", self.radius)
+    }
+}
+
+// And back: `FromStr` reads what `Display` writes, and nothing else.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseCircleError {
+    // The text doesn't start with `Circle of radius `, or the radius isn't
+    // written the way `Display` writes it: `+6`, `007`, `-0`.
+    Format(String),
+    Radius(ParseIntError),
+}
+
+impl fmt::Display for ParseCircleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseCircleError::Format(input) => write!(f, "expected `Circle of radius <i32>`, found {:?}", input),
+            ParseCircleError::Radius(err) => write!(f, "invalid radius: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for ParseCircleError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParseCircleError::Format(_) => None,
+            ParseCircleError::Radius(err) => Some(err),
+        }
+    }
+}
+
+impl FromStr for Circle {
+    type Err = ParseCircleError;
+
+    fn from_str(s: &str) -> Result<Circle, ParseCircleError> {
+        let radius = s.strip_prefix("Circle of radius ").ok_or_else(|| ParseCircleError::Format(s.to_string()))?;
+        let circle = Circle { radius: radius.parse().map_err(ParseCircleError::Radius)? };
+        if circle.to_string() != s {
+            return Err(ParseCircleError::Format(s.to_string()));
+        }
+        Ok(circle)
+    }
+}
+
+pub const USAGE: &str = "\
+usage: conversion [options]
+
+options:
+    --from <i32>      the value converted with `Number::from` (default: 30)
+    --into <i32>      the value converted with `.into()` (default: 5)
+    --radius <i32>    the radius of the circle converted to a `String` (default: 6)
+    -h, --help        print this message";
+
+// The values converted by the example.
+pub struct Params {
+    pub from: i32,
+    pub into: i32,
+    pub radius: i32,
+}
+
+impl Default for Params {
+    fn default() -> Params {
+        Params { from: 30, into: 5, radius: 6 }
+    }
+}
+
+impl Params {
+    pub fn from_args(mut args: Args) -> Result<Params, cmdline::Error> {
+        let default = Params::default();
+        let params = Params {
+            from: args.get("from", default.from)?,
+            into: args.get("into", default.into)?,
+            radius: args.get("radius", default.radius)?,
+        };
+        args.finish()?;
+        Ok(params)
+    }
+}
+
+pub fn run(out: &mut dyn Write) -> io::Result<()> {
+    run_with(out, &Params::default())
+}
+
+pub fn run_args(args: Args, out: &mut dyn Write) -> Result<(), cmdline::Error> {
+    let params = Params::from_args(args)?;
+    Ok(run_with(out, &params)?)
+}
+
+pub fn run_with(out: &mut dyn Write, params: &Params) -> io::Result<()> {
+    // Using the `from` trait to easily convert a `str` into a `String`
+    let my_str = "hello";
+    let my_string = String::from(my_str);
+
+    let num = Number::from(params.from);
+    writeln!(out, "My number 'from' is {:?}", num)?;
+
+    // Using `Into`
+    let int = params.into;
+    let num: Number = int.into(); //try removing the type declaration
+    writeln!(out, "My number 'into' is {:?}", num)?;
+
+    // A `Number` computes like the `i32` inside, overflows included: the
+    // values come from the command line, so the checked operations are used
+    let from = Number::from(params.from);
+    let show = |result: Option<Number>| result.map_or("overflow".to_string(), |number| number.to_string());
+    let sum = from.checked_add(num);
+    let product = num.checked_neg().and_then(|neg| from.checked_mul(neg));
+    writeln!(out, "{} + {} = {}, {} * -({}) = {}", from, num, show(sum), from, num, show(product))?;
+    let numbers: Vec<Number> = ["3", "-7", "12"].iter().map(|s| s.parse().unwrap()).collect();
+    let sum: Number = numbers.iter().sum();
+    let product: Number = numbers.iter().product();
+    let max = numbers.iter().max().copied().unwrap_or_default();
+    let list: Vec<String> = numbers.iter().map(Number::to_string).collect();
+    writeln!(out, "{}: sum {}, product {}, max {}", list.join(", "), sum, product, max)?;
+    writeln!(
+        out,
+        "MAX + 1: checked {:?}, saturating {}, wrapping {}",
+        Number::MAX.checked_add(Number::from(1)),
+        Number::MAX.saturating_add(Number::from(1)),
+        Number::MAX.wrapping_add(Number::from(1))
+    )?;
+    if let Err(err) = "12.5".parse::<Number>() {
+        writeln!(out, "{}", err)?;
+    }
+
+    // using `TryFrom`
+    assert_eq!(EvenNumber::try_from(8), Ok(EvenNumber(8)));
+    assert_eq!(EvenNumber::try_from(5), Err(ValidationError::NotEven(5)));
+
+    // `TryInto`
+    let result: Result<EvenNumber, ValidationError> = 8i32.try_into();
+    assert_eq!(result, Ok(EvenNumber(8)));
+    let result: Result<EvenNumber, ValidationError> = 5i32.try_into();
+    if let Err(err) = result {
+        writeln!(out, "5 is no EvenNumber: {}", err)?;
+    }
+
+    // The `validated` types convert the same way, and keep their invariant
+    // through arithmetic: the sum of two odd numbers is an `Even`
+    let odd = Odd::try_from(params.into);
+    match odd {
+        Ok(odd) => writeln!(out, "{} + {} = {} is even", odd, odd, odd + odd)?,
+        Err(err) => writeln!(out, "{}", err)?,
+    }
+    let describe = |result: Result<i64, ValidationError>| match result {
+        Ok(value) => format!("ok, {}", value),
+        Err(err) => err.to_string(),
+    };
+    for input in ["12", "13", "-4", "abc"] {
+        let month = input.parse::<Bounded<1, 12>>().map(i64::from);
+        let prime = input.parse::<Prime>().map(i64::from);
+        writeln!(out, "{:?} as a month: {}, as a prime: {}", input, describe(month), describe(prime))?;
+    }
+
+    // The derived conversions of `Percentage`
+    for input in ["42", "142", "-1"] {
+        match input.parse::<Percentage>() {
+            Ok(percentage) => writeln!(out, "{:?} is {}%", input, percentage)?,
+            Err(err) => writeln!(out, "{:?} is no Percentage: {}", input, err)?,
+        }
+    }
+
+    // String conversion via `fmt::Display` trait implementation
+    let circle = Circle { radius: params.radius };
+    writeln!(out, "{}", circle.to_string())?;
+
+    // Parsing a String
+    // One of the more common types to convert a string into is a number.
+    // The idiomatic approach to this is to use the `parse` function and either
+    // to arrange for type inference or to specify the type to parse
+    // using the 'turbofish' syntax. Both alternatives are shown in the following example.
+    // This will convert the string into the type specified as long as the `FromStr` trait
+    // is implemented for that type.
+    // This is implemented for numerous types within the standard library.
+    // To obtain this functionality on a user defined type simply implement the `FromStr` for that type.
+
+    let parsed: i32 = "5".parse().unwrap();
+    let turbo_parsed = "10".parse::<i32>().unwrap();
+
+    let sum = parsed + turbo_parsed;
+    writeln!(out, "sum: {:?}", sum)?;
+
+    // `Circle` implements `FromStr`, so its `Display` output parses back
+    let circle: Circle = circle.to_string().parse().unwrap();
+    writeln!(out, "parsed back: {:?}", circle)?;
+
+    Ok(())
+}
@@ -0,0 +1,197 @@
+// Everything that makes `Number` usable in place of the `i32` it wraps: the
+// arithmetic operators, their checked, saturating and wrapping variants,
+// parsing, printing, ordering and hashing, and sums and products.
+//
+// The operators behave exactly as they do on `i32`: an overflow panics in
+// debug builds and wraps in release builds. Dividing by zero always panics.
+// When that's not good enough, `checked_*` returns `None` instead,
+// `saturating_*` stops at `Number::MIN` or `Number::MAX` and `wrapping_*`
+// always wraps around.
+//
+// Each operator accepts a `Number` or a plain `i32` on the right:
+// `Number::from(3) * 2`.
+
+use std::fmt;
+use std::iter::{Product, Sum};
+use std::num::ParseIntError;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign};
+use std::str::FromStr;
+
+use crate::Number;
+
+impl Number {
+    pub const MIN: Number = Number { value: i32::MIN };
+    pub const MAX: Number = Number { value: i32::MAX };
+}
+
+impl From<Number> for i32 {
+    fn from(number: Number) -> i32 {
+        number.value
+    }
+}
+
+// `$trait::$method` for `Number` and `i32` right-hand sides, and the
+// assigning `$assign_trait::$assign_method` built on it.
+macro_rules! binary_op {
+    ($trait:ident::$method:ident, $assign_trait:ident::$assign_method:ident) => {
+        impl $trait for Number {
+            type Output = Number;
+
+            fn $method(self, rhs: Number) -> Number {
+                Number { value: $trait::$method(self.value, rhs.value) }
+            }
+        }
+
+        impl $trait<i32> for Number {
+            type Output = Number;
+
+            fn $method(self, rhs: i32) -> Number {
+                Number { value: $trait::$method(self.value, rhs) }
+            }
+        }
+
+        impl $assign_trait for Number {
+            fn $assign_method(&mut self, rhs: Number) {
+                *self = $trait::$method(*self, rhs);
+            }
+        }
+
+        impl $assign_trait<i32> for Number {
+            fn $assign_method(&mut self, rhs: i32) {
+                *self = $trait::$method(*self, rhs);
+            }
+        }
+    };
+}
+
+binary_op!(Add::add, AddAssign::add_assign);
+binary_op!(Sub::sub, SubAssign::sub_assign);
+binary_op!(Mul::mul, MulAssign::mul_assign);
+binary_op!(Div::div, DivAssign::div_assign);
+binary_op!(Rem::rem, RemAssign::rem_assign);
+
+impl Neg for Number {
+    type Output = Number;
+
+    fn neg(self) -> Number {
+        Number { value: -self.value }
+    }
+}
+
+// Inherent methods forwarding to the `i32` method of the same name, either
+// taking another `Number` (`binary`) or nothing (`unary`).
+macro_rules! forward {
+    (binary $wrap:ident: $($method:ident),*) => {
+        impl Number {
+            $(
+                pub fn $method(self, rhs: Number) -> $wrap!(Number) {
+                    $wrap!(self.value.$method(rhs.value))
+                }
+            )*
+        }
+    };
+    (unary $wrap:ident: $($method:ident),*) => {
+        impl Number {
+            $(
+                pub fn $method(self) -> $wrap!(Number) {
+                    $wrap!(self.value.$method())
+                }
+            )*
+        }
+    };
+}
+
+// `Option<Number>` as a type, `Option<i32>` mapped to it as an expression.
+macro_rules! checked {
+    (Number) => { Option<Number> };
+    ($value:expr) => { $value.map(|value| Number { value }) };
+}
+
+// `Number` as a type, an `i32` wrapped into it as an expression.
+macro_rules! number {
+    (Number) => { Number };
+    ($value:expr) => { Number { value: $value } };
+}
+
+forward!(binary checked: checked_add, checked_sub, checked_mul, checked_div, checked_rem);
+forward!(unary checked: checked_neg, checked_abs);
+forward!(binary number: saturating_add, saturating_sub, saturating_mul, saturating_div);
+forward!(unary number: saturating_neg, saturating_abs);
+forward!(binary number: wrapping_add, wrapping_sub, wrapping_mul, wrapping_div, wrapping_rem);
+forward!(unary number: wrapping_neg, wrapping_abs);
+
+impl Number {
+    pub fn abs(self) -> Number {
+        Number { value: self.value.abs() }
+    }
+
+    pub fn pow(self, exp: u32) -> Number {
+        Number { value: self.value.pow(exp) }
+    }
+
+    pub fn checked_pow(self, exp: u32) -> Option<Number> {
+        self.value.checked_pow(exp).map(|value| Number { value })
+    }
+}
+
+// Just the value, as for an `i32`: the `{:?}` output still shows the struct.
+impl fmt::Display for Number {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseNumberError {
+    // The text that failed to parse.
+    pub input: String,
+    pub error: ParseIntError,
+}
+
+impl fmt::Display for ParseNumberError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "cannot parse {:?} as a Number: {}", self.input, self.error)
+    }
+}
+
+impl std::error::Error for ParseNumberError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+// Accepts what `i32` accepts, so everything `Display` prints parses back.
+impl FromStr for Number {
+    type Err = ParseNumberError;
+
+    fn from_str(s: &str) -> Result<Number, ParseNumberError> {
+        match s.parse() {
+            Ok(value) => Ok(Number { value }),
+            Err(error) => Err(ParseNumberError { input: s.to_string(), error }),
+        }
+    }
+}
+
+impl Sum for Number {
+    fn sum<I: Iterator<Item = Number>>(iter: I) -> Number {
+        iter.fold(Number { value: 0 }, Add::add)
+    }
+}
+
+impl<'a> Sum<&'a Number> for Number {
+    fn sum<I: Iterator<Item = &'a Number>>(iter: I) -> Number {
+        iter.copied().sum()
+    }
+}
+
+impl Product for Number {
+    fn product<I: Iterator<Item = Number>>(iter: I) -> Number {
+        iter.fold(Number { value: 1 }, Mul::mul)
+    }
+}
+
+impl<'a> Product<&'a Number> for Number {
+    fn product<I: Iterator<Item = &'a Number>>(iter: I) -> Number {
+        iter.copied().product()
+    }
+}
@@ -0,0 +1,383 @@
+// Validated newtypes: an integer wrapped in a type that can only hold the
+// values passing a check. Once an `Even` exists, it is even, so a function
+// taking an `Even` never needs to check again.
+//
+// Every type is built the `TryFrom` way, from an `i32` or an `i64`, or
+// parsed from a string with `FromStr`. A failed conversion says why with a
+// `ValidationError`, rather than the bare `()` of `EvenNumber`.
+//
+// The arithmetic operators are implemented where the result keeps the
+// invariant: an even plus an even is even, an odd plus an odd is even too,
+// a positive times a positive is positive. An overflow would break the
+// invariant, so the operators panic on overflow in release builds too, and
+// the `CheckedAdd`, `CheckedSub` and `CheckedMul` traits return a
+// `ValidationError::Overflow` instead.
+
+use std::fmt;
+use std::num::ParseIntError;
+use std::ops::{Add, Mul, Neg, Sub};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationError {
+    NotEven(i64),
+    NotOdd(i64),
+    NotPositive(i64),
+    Zero,
+    NotPrime(i64),
+    OutOfRange { value: i64, min: i64, max: i64 },
+    // An arithmetic operation overflowed `i64`.
+    Overflow,
+    // `FromStr` was given something that is not an integer.
+    Parse(ParseIntError),
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ValidationError::NotEven(value) => write!(f, "{} is not even", value),
+            ValidationError::NotOdd(value) => write!(f, "{} is not odd", value),
+            ValidationError::NotPositive(value) => write!(f, "{} is not positive", value),
+            ValidationError::Zero => write!(f, "the value is zero"),
+            ValidationError::NotPrime(value) => write!(f, "{} is not prime", value),
+            ValidationError::OutOfRange { value, min, max } => {
+                write!(f, "{} is not between {} and {}", value, min, max)
+            }
+            ValidationError::Overflow => write!(f, "the result overflows an i64"),
+            ValidationError::Parse(err) => write!(f, "not an integer: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ValidationError::Parse(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseIntError> for ValidationError {
+    fn from(err: ParseIntError) -> ValidationError {
+        ValidationError::Parse(err)
+    }
+}
+
+// The parts every validated type shares: the struct, the constructor, the
+// conversions and `Display`. `$check` tells whether an `i64` is valid and
+// `$error` builds the error from the rejected value.
+macro_rules! validated {
+    ($name:ident, $check:expr, $error:expr) => {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name(i64);
+
+        impl $name {
+            pub fn new(value: i64) -> Result<$name, ValidationError> {
+                let check: fn(i64) -> bool = $check;
+                let error: fn(i64) -> ValidationError = $error;
+                if check(value) {
+                    Ok($name(value))
+                } else {
+                    Err(error(value))
+                }
+            }
+
+            pub fn get(self) -> i64 {
+                self.0
+            }
+        }
+
+        impl TryFrom<i64> for $name {
+            type Error = ValidationError;
+
+            fn try_from(value: i64) -> Result<$name, ValidationError> {
+                $name::new(value)
+            }
+        }
+
+        impl TryFrom<i32> for $name {
+            type Error = ValidationError;
+
+            fn try_from(value: i32) -> Result<$name, ValidationError> {
+                $name::new(i64::from(value))
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = ValidationError;
+
+            fn from_str(s: &str) -> Result<$name, ValidationError> {
+                $name::new(s.trim().parse()?)
+            }
+        }
+
+        impl From<$name> for i64 {
+            fn from(value: $name) -> i64 {
+                value.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+    };
+}
+
+// The `checked_*` forms of the operators: `None` from the primitive
+// operation becomes a `ValidationError::Overflow`.
+pub trait CheckedAdd<Rhs = Self> {
+    type Output;
+    fn checked_add(self, rhs: Rhs) -> Result<Self::Output, ValidationError>;
+}
+
+pub trait CheckedSub<Rhs = Self> {
+    type Output;
+    fn checked_sub(self, rhs: Rhs) -> Result<Self::Output, ValidationError>;
+}
+
+pub trait CheckedMul<Rhs = Self> {
+    type Output;
+    fn checked_mul(self, rhs: Rhs) -> Result<Self::Output, ValidationError>;
+}
+
+// `$lhs $op $rhs` always gives an `$output`, provided it doesn't overflow:
+// implement the operator, and its checked form for when it might.
+macro_rules! closed_op {
+    ($lhs:ident $trait:ident::$method:ident $rhs:ident = $output:ident, $checked_trait:ident::$checked:ident) => {
+        impl $checked_trait<$rhs> for $lhs {
+            type Output = $output;
+
+            fn $checked(self, rhs: $rhs) -> Result<$output, ValidationError> {
+                let value = self.0.$checked(rhs.0).ok_or(ValidationError::Overflow)?;
+                // The invariant holds by construction.
+                Ok($output(value))
+            }
+        }
+
+        impl $trait<$rhs> for $lhs {
+            type Output = $output;
+
+            fn $method(self, rhs: $rhs) -> $output {
+                match $checked_trait::$checked(self, rhs) {
+                    Ok(result) => result,
+                    Err(_) => panic!("attempt to {} with overflow", stringify!($method)),
+                }
+            }
+        }
+    };
+}
+
+// `-x` keeps the invariant. `i64::MIN` has no positive counterpart, so
+// negating it panics, as it does for a plain `i64`.
+macro_rules! closed_neg {
+    ($name:ident) => {
+        impl Neg for $name {
+            type Output = $name;
+
+            fn neg(self) -> $name {
+                match self.0.checked_neg() {
+                    Some(value) => $name(value),
+                    None => panic!("attempt to negate with overflow"),
+                }
+            }
+        }
+    };
+}
+
+validated!(
+    // An even number: 0, 2, -4, ...
+    Even,
+    |n| n % 2 == 0,
+    ValidationError::NotEven
+);
+validated!(
+    // An odd number: 1, -3, 5, ...
+    Odd,
+    |n| n % 2 != 0,
+    ValidationError::NotOdd
+);
+validated!(
+    // A number greater than zero.
+    Positive,
+    |n| n > 0,
+    ValidationError::NotPositive
+);
+validated!(
+    // Any number but zero, so it can always be divided by.
+    NonZero,
+    |n| n != 0,
+    |_| ValidationError::Zero
+);
+validated!(
+    // A prime number: 2, 3, 5, 7, 11, ...
+    Prime,
+    is_prime,
+    ValidationError::NotPrime
+);
+
+// Parity arithmetic: sums and differences of two numbers of the same parity
+// are even, of different parities odd. A product is even as soon as one
+// factor is, and odd when both are.
+closed_op!(Even Add::add Even = Even, CheckedAdd::checked_add);
+closed_op!(Even Sub::sub Even = Even, CheckedSub::checked_sub);
+closed_op!(Even Mul::mul Even = Even, CheckedMul::checked_mul);
+closed_op!(Even Add::add Odd = Odd, CheckedAdd::checked_add);
+closed_op!(Even Sub::sub Odd = Odd, CheckedSub::checked_sub);
+closed_op!(Even Mul::mul Odd = Even, CheckedMul::checked_mul);
+closed_op!(Odd Add::add Odd = Even, CheckedAdd::checked_add);
+closed_op!(Odd Sub::sub Odd = Even, CheckedSub::checked_sub);
+closed_op!(Odd Mul::mul Odd = Odd, CheckedMul::checked_mul);
+closed_op!(Odd Add::add Even = Odd, CheckedAdd::checked_add);
+closed_op!(Odd Sub::sub Even = Odd, CheckedSub::checked_sub);
+closed_op!(Odd Mul::mul Even = Even, CheckedMul::checked_mul);
+closed_neg!(Even);
+closed_neg!(Odd);
+
+// Sums and products of positive numbers stay positive, products of non-zero
+// numbers stay non-zero. Differences can go anywhere.
+closed_op!(Positive Add::add Positive = Positive, CheckedAdd::checked_add);
+closed_op!(Positive Mul::mul Positive = Positive, CheckedMul::checked_mul);
+closed_op!(NonZero Mul::mul NonZero = NonZero, CheckedMul::checked_mul);
+closed_neg!(NonZero);
+
+// A number between `MIN` and `MAX`, both included: `Bounded<1, 12>` is a month.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Bounded<const MIN: i64, const MAX: i64>(i64);
+
+impl<const MIN: i64, const MAX: i64> Bounded<MIN, MAX> {
+    // Evaluated at compile time wherever a `Bounded` is built, so that
+    // `Bounded<5, 1>` is rejected by the compiler rather than never holding a value.
+    const VALID_BOUNDS: () = assert!(MIN <= MAX, "`Bounded<MIN, MAX>` needs MIN <= MAX");
+
+    pub const MIN: Bounded<MIN, MAX> = {
+        let () = Self::VALID_BOUNDS;
+        Bounded(MIN)
+    };
+    pub const MAX: Bounded<MIN, MAX> = {
+        let () = Self::VALID_BOUNDS;
+        Bounded(MAX)
+    };
+
+    pub fn new(value: i64) -> Result<Bounded<MIN, MAX>, ValidationError> {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_BOUNDS;
+        if (MIN..=MAX).contains(&value) {
+            Ok(Bounded(value))
+        } else {
+            Err(ValidationError::OutOfRange { value, min: MIN, max: MAX })
+        }
+    }
+
+    pub fn get(self) -> i64 {
+        self.0
+    }
+
+    // Sums and differences may leave the range: check them, or clamp them
+    // back into it.
+    pub fn checked_add(self, rhs: i64) -> Result<Bounded<MIN, MAX>, ValidationError> {
+        Bounded::new(self.0.checked_add(rhs).ok_or(ValidationError::Overflow)?)
+    }
+
+    pub fn checked_sub(self, rhs: i64) -> Result<Bounded<MIN, MAX>, ValidationError> {
+        Bounded::new(self.0.checked_sub(rhs).ok_or(ValidationError::Overflow)?)
+    }
+
+    pub fn saturating_add(self, rhs: i64) -> Bounded<MIN, MAX> {
+        Bounded(self.0.saturating_add(rhs).clamp(MIN, MAX))
+    }
+
+    pub fn saturating_sub(self, rhs: i64) -> Bounded<MIN, MAX> {
+        Bounded(self.0.saturating_sub(rhs).clamp(MIN, MAX))
+    }
+}
+
+impl<const MIN: i64, const MAX: i64> TryFrom<i64> for Bounded<MIN, MAX> {
+    type Error = ValidationError;
+
+    fn try_from(value: i64) -> Result<Bounded<MIN, MAX>, ValidationError> {
+        Bounded::new(value)
+    }
+}
+
+impl<const MIN: i64, const MAX: i64> TryFrom<i32> for Bounded<MIN, MAX> {
+    type Error = ValidationError;
+
+    fn try_from(value: i32) -> Result<Bounded<MIN, MAX>, ValidationError> {
+        Bounded::new(i64::from(value))
+    }
+}
+
+impl<const MIN: i64, const MAX: i64> FromStr for Bounded<MIN, MAX> {
+    type Err = ValidationError;
+
+    fn from_str(s: &str) -> Result<Bounded<MIN, MAX>, ValidationError> {
+        Bounded::new(s.trim().parse()?)
+    }
+}
+
+impl<const MIN: i64, const MAX: i64> From<Bounded<MIN, MAX>> for i64 {
+    fn from(value: Bounded<MIN, MAX>) -> i64 {
+        value.0
+    }
+}
+
+impl<const MIN: i64, const MAX: i64> fmt::Display for Bounded<MIN, MAX> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Prime {
+    // The smallest prime greater than this one, if it fits in an `i64`.
+    pub fn next(self) -> Option<Prime> {
+        (self.0 + 1..=i64::MAX).find(|&n| is_prime(n)).map(Prime)
+    }
+}
+
+// Deterministic Miller-Rabin: with these bases, the test never lies for a
+// number below 2^64, and it takes a handful of multiplications where trial
+// division would take up to three billion of them.
+pub fn is_prime(n: i64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    let n = n as u64;
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    if let Some(&base) = BASES.iter().find(|&&base| n.is_multiple_of(base)) {
+        return n == base;
+    }
+
+    // n - 1 = d * 2^s with d odd.
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+
+    let mul_mod = |a: u64, b: u64| (u128::from(a) * u128::from(b) % u128::from(n)) as u64;
+    let pow_mod = |mut base: u64, mut exp: u64| {
+        let mut result = 1;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = mul_mod(result, base);
+            }
+            base = mul_mod(base, base);
+            exp >>= 1;
+        }
+        result
+    };
+
+    BASES.iter().all(|&base| {
+        let mut x = pow_mod(base, d);
+        if x == 1 || x == n - 1 {
+            return true;
+        }
+        for _ in 1..s {
+            x = mul_mod(x, x);
+            if x == n - 1 {
+                return true;
+            }
+        }
+        false
+    })
+}
@@ -0,0 +1,97 @@
+// The local `Person` is only ever printed, so its fields are never read.
+#![allow(dead_code)]
+
+use std::io::{self, Write};
+
+use cmdline::Args;
+
+// This structure cannot be printed either with `fmt::Display` or
+// `fmt::Debug`.
+pub struct UnPrintable(pub i32);
+
+// The `derive` attribute automatically creates the implementation
+// required for this `struct` to be printable with `fmt::Debug`.
+#[derive(Debug)]
+pub struct DebugPrintable(pub i32);
+
+// All `std` library types are automatically printable with `{:?} too
+// Derive the `fmt::Debug` implementation for `Structure` which is a structure
+// that contains a single `i32`.
+#[derive(Debug)]
+pub struct Structure(pub i32);
+
+// Put a `Structure` inside of the structure `Deep`. Make it printable also.
+#[derive(Debug)]
+pub struct Deep(pub Structure);
+
+pub const USAGE: &str = "\
+usage: debug [--name <string>] [--age <u8>]
+
+options:
+    --name <string>    name of the person pretty printed with `{:#?}` (default: Peter)
+    --age <u8>         age of the same person (default: 27)
+    -h, --help         print this message";
+
+// Who gets printed.
+pub struct Params {
+    pub name: String,
+    pub age: u8,
+}
+
+impl Default for Params {
+    fn default() -> Params {
+        Params { name: String::from("Peter"), age: 27 }
+    }
+}
+
+impl Params {
+    pub fn from_args(mut args: Args) -> Result<Params, cmdline::Error> {
+        let default = Params::default();
+        let params = Params {
+            name: args.get("name", default.name)?,
+            age: args.get("age", default.age)?,
+        };
+        args.finish()?;
+        Ok(params)
+    }
+}
+
+pub fn run(out: &mut dyn Write) -> io::Result<()> {
+    run_with(out, &Params::default())
+}
+
+pub fn run_args(args: Args, out: &mut dyn Write) -> Result<(), cmdline::Error> {
+    let params = Params::from_args(args)?;
+    Ok(run_with(out, &params)?)
+}
+
+pub fn run_with(out: &mut dyn Write, params: &Params) -> io::Result<()> {
+    // Printing with `{:?} is similar to printing with `{}`
+    writeln!(out, "{:?} months in a year", 12)?;
+    writeln!(out, "{1:?} {0:?} is the {actor:?} name.",
+            "Slater",
+            "Christian",
+             actor="actor's")?;
+    // `Structure` is printable!
+    writeln!(out, "Now {:?} will print!", Structure(3))?;
+
+    // The problem with `derive` is there is no control over how
+    // the results look. What if we want this to just show a `7`?
+    writeln!(out, "Now {:?} will print", Deep(Structure(7)))?;
+
+    // Rust also provides "pretty printing" with `{:#?}.
+    #[derive(Debug)]
+    struct Person<'a> {
+        name: &'a str,
+        age: u8,
+    }
+
+    let name = params.name.as_str();
+    let age = params.age;
+    let peter = Person {name, age};
+
+    // Pretty print for `Person` struct
+    writeln!(out, "{:#?}", peter)?;
+
+    Ok(())
+}
\ No newline at end of file
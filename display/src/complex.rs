@@ -0,0 +1,467 @@
+// A complex number `real + imag·i`, with the arithmetic of the field: the
+// operators mix `Complex` and `f64` freely, and the usual functions go
+// through the polar form `modulus·e^(argument·i)`:
+//
+//   let z = Complex::new(3.0, 4.0);
+//   z.modulus()             // 5
+//   z * Complex::I          // -4 + 3i
+//   1.0 / z                 // 0.12 - 0.16i
+//   Complex::I.powc(Complex::I)   // i^i = e^(-π/2), a real number
+//
+// Floats round, so results are compared with `approx_eq` rather than `==`.
+
+use std::fmt;
+use std::iter::{Product, Sum};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Complex {
+    pub real: f64,
+    pub imag: f64,
+}
+
+impl Complex {
+    pub const ZERO: Complex = Complex::new(0.0, 0.0);
+    pub const ONE: Complex = Complex::new(1.0, 0.0);
+    pub const I: Complex = Complex::new(0.0, 1.0);
+
+    pub const fn new(real: f64, imag: f64) -> Complex {
+        Complex { real, imag }
+    }
+
+    // The number at distance `modulus` from 0, at angle `argument` (in
+    // radians) from the positive real axis.
+    pub fn from_polar(modulus: f64, argument: f64) -> Complex {
+        Complex::new(modulus * argument.cos(), modulus * argument.sin())
+    }
+
+    // `(modulus, argument)`, the argument in `-π..=π`.
+    pub fn to_polar(self) -> (f64, f64) {
+        (self.modulus(), self.argument())
+    }
+
+    // The mirror image across the real axis: `a + bi` becomes `a - bi`.
+    pub fn conjugate(self) -> Complex {
+        Complex::new(self.real, -self.imag)
+    }
+
+    // `|z|`, computed without overflow for large parts.
+    pub fn modulus(self) -> f64 {
+        self.real.hypot(self.imag)
+    }
+
+    // `|z|²`, cheaper than `modulus` when only comparing sizes.
+    pub fn modulus_squared(self) -> f64 {
+        self.real * self.real + self.imag * self.imag
+    }
+
+    pub fn argument(self) -> f64 {
+        self.imag.atan2(self.real)
+    }
+
+    pub fn recip(self) -> Complex {
+        Complex::ONE / self
+    }
+
+    // `e^(a + bi) = e^a·(cos b + i·sin b)`
+    pub fn exp(self) -> Complex {
+        Complex::from_polar(self.real.exp(), self.imag)
+    }
+
+    // The principal logarithm, whose imaginary part is in `-π..=π`.
+    pub fn ln(self) -> Complex {
+        Complex::new(self.modulus().ln(), self.argument())
+    }
+
+    // The principal square root, whose real part is never negative. Taken
+    // from the cartesian form, as the polar one rounds `sqrt(-4)` to
+    // `1.2e-16 + 2i`.
+    pub fn sqrt(self) -> Complex {
+        if self == Complex::ZERO {
+            return Complex::ZERO;
+        }
+        let root = ((self.modulus() + self.real.abs()) / 2.0).sqrt();
+        let other = self.imag.abs() / (2.0 * root);
+        if self.real >= 0.0 {
+            Complex::new(root, other.copysign(self.imag))
+        } else {
+            Complex::new(other, root.copysign(self.imag))
+        }
+    }
+
+    // `z^w = e^(w·ln z)`, with the principal logarithm. `0^w` is 1 for
+    // `w = 0`, 0 when the real part of `w` is positive, and NaN otherwise.
+    pub fn powc(self, exponent: Complex) -> Complex {
+        if self == Complex::ZERO {
+            return if exponent == Complex::ZERO {
+                Complex::ONE
+            } else if exponent.real > 0.0 {
+                Complex::ZERO
+            } else {
+                Complex::new(f64::NAN, f64::NAN)
+            };
+        }
+        (exponent * self.ln()).exp()
+    }
+
+    // `z^x` for a real `x`: the modulus to the power `x`, the argument times `x`.
+    pub fn powf(self, exponent: f64) -> Complex {
+        let (modulus, argument) = self.to_polar();
+        Complex::from_polar(modulus.powf(exponent), argument * exponent)
+    }
+
+    // Whether `self` and `other` are within `tolerance` of each other,
+    // relative to the larger modulus when it is above 1, absolute below.
+    pub fn approx_eq(self, other: Complex, tolerance: f64) -> bool {
+        let scale = self.modulus().max(other.modulus()).max(1.0);
+        (self - other).modulus() <= tolerance * scale
+    }
+
+    // Smith's algorithm: dividing by the larger part of the divisor first
+    // keeps `c² + d²` from overflowing or underflowing.
+    fn divide(self, rhs: Complex) -> Complex {
+        let Complex { real: a, imag: b } = self;
+        let Complex { real: c, imag: d } = rhs;
+        if c.abs() >= d.abs() {
+            let ratio = d / c;
+            let denominator = c + d * ratio;
+            Complex::new((a + b * ratio) / denominator, (b - a * ratio) / denominator)
+        } else {
+            let ratio = c / d;
+            let denominator = c * ratio + d;
+            Complex::new((a * ratio + b) / denominator, (b * ratio - a) / denominator)
+        }
+    }
+}
+
+impl From<f64> for Complex {
+    fn from(real: f64) -> Complex {
+        Complex::new(real, 0.0)
+    }
+}
+
+// `Complex op Complex`, `Complex op f64` and `f64 op Complex`, plus `op=`.
+// A real operand is used as is rather than made a `Complex` with a zero
+// imaginary part: `2 * (inf + 1i)` would compute `0 * inf`, a NaN.
+macro_rules! binary_op {
+    (
+        $trait:ident::$method:ident, $assign_trait:ident::$assign_method:ident,
+        |$z:ident, $w:ident| $complex:expr,
+        |$zs:ident, $x:ident| $scalar:expr,
+        |$xs:ident, $ws:ident| $scalar_lhs:expr
+    ) => {
+        impl $trait for Complex {
+            type Output = Complex;
+
+            fn $method(self, rhs: Complex) -> Complex {
+                let ($z, $w) = (self, rhs);
+                $complex
+            }
+        }
+
+        impl $trait<f64> for Complex {
+            type Output = Complex;
+
+            fn $method(self, rhs: f64) -> Complex {
+                let ($zs, $x) = (self, rhs);
+                $scalar
+            }
+        }
+
+        impl $trait<Complex> for f64 {
+            type Output = Complex;
+
+            fn $method(self, rhs: Complex) -> Complex {
+                let ($xs, $ws) = (self, rhs);
+                $scalar_lhs
+            }
+        }
+
+        impl $assign_trait for Complex {
+            fn $assign_method(&mut self, rhs: Complex) {
+                *self = $trait::$method(*self, rhs);
+            }
+        }
+
+        impl $assign_trait<f64> for Complex {
+            fn $assign_method(&mut self, rhs: f64) {
+                *self = $trait::$method(*self, rhs);
+            }
+        }
+    };
+}
+
+binary_op!(
+    Add::add, AddAssign::add_assign,
+    |z, w| Complex::new(z.real + w.real, z.imag + w.imag),
+    |z, x| Complex::new(z.real + x, z.imag),
+    |x, w| Complex::new(x + w.real, w.imag)
+);
+
+binary_op!(
+    Sub::sub, SubAssign::sub_assign,
+    |z, w| Complex::new(z.real - w.real, z.imag - w.imag),
+    |z, x| Complex::new(z.real - x, z.imag),
+    |x, w| Complex::new(x - w.real, -w.imag)
+);
+
+binary_op!(
+    Mul::mul, MulAssign::mul_assign,
+    |z, w| Complex::new(z.real * w.real - z.imag * w.imag, z.real * w.imag + z.imag * w.real),
+    |z, x| Complex::new(z.real * x, z.imag * x),
+    |x, w| Complex::new(x * w.real, x * w.imag)
+);
+
+binary_op!(
+    Div::div, DivAssign::div_assign,
+    |z, w| z.divide(w),
+    |z, x| Complex::new(z.real / x, z.imag / x),
+    |x, w| Complex::from(x).divide(w)
+);
+
+impl Neg for Complex {
+    type Output = Complex;
+
+    fn neg(self) -> Complex {
+        Complex::new(-self.real, -self.imag)
+    }
+}
+
+impl Sum for Complex {
+    fn sum<I: Iterator<Item = Complex>>(iter: I) -> Complex {
+        iter.fold(Complex::ZERO, Add::add)
+    }
+}
+
+impl Product for Complex {
+    fn product<I: Iterator<Item = Complex>>(iter: I) -> Complex {
+        iter.fold(Complex::ONE, Mul::mul)
+    }
+}
+
+// `3.3 + 7.2i`, or `3.3 - 7.2i` when the imaginary part is negative, -0
+// included so it reads back the same. A precision applies to both parts:
+// `{:.2}` prints `3.30 - 7.20i`.
+impl fmt::Display for Complex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.imag.is_sign_negative() { '-' } else { '+' };
+        let imag = self.imag.abs();
+        match f.precision() {
+            Some(precision) => write!(f, "{:.*} {} {:.*}i", precision, self.real, sign, precision, imag),
+            None => write!(f, "{} {} {}i", self.real, sign, imag),
+        }
+    }
+}
+
+// The forms of user input, `Display`'s included:
+//
+//   3, -2.5, 1e-3             a real number
+//   2i, -i, 1e3i              an imaginary one
+//   3.3 + 7.2i, 3.3-7.2i      both, the real part first
+//   2∠45°, 2∠0.785            polar: a modulus and an angle, in degrees
+//                             with `°`, in radians without
+//
+// The numbers are those of `f64::from_str`, `inf` and `NaN` included, but
+// with no sign after the one between the parts: `1 - -2i` is an error.
+// Errors give the byte offset of the faulty character.
+impl FromStr for Complex {
+    type Err = ParseComplexError;
+
+    fn from_str(s: &str) -> Result<Complex, ParseComplexError> {
+        Parser { input: s, offset: 0 }.complex()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseComplexErrorKind {
+    Empty,
+    ExpectedNumber,
+    // `1e` or `1e+`: an exponent has digits.
+    ExpectedExponent,
+    // The second part of `a + bi` has no `i`.
+    ExpectedImaginary,
+    // `2i + 3`: the real part comes first.
+    ImaginaryFirst,
+    NegativeModulus,
+    ImaginaryModulus,
+    Unexpected(char),
+}
+
+impl fmt::Display for ParseComplexErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseComplexErrorKind::Empty => write!(f, "empty input"),
+            ParseComplexErrorKind::ExpectedNumber => write!(f, "expected a number or `i`"),
+            ParseComplexErrorKind::ExpectedExponent => write!(f, "expected the digits of the exponent"),
+            ParseComplexErrorKind::ExpectedImaginary => write!(f, "expected an imaginary part, ending in `i`"),
+            ParseComplexErrorKind::ImaginaryFirst => write!(f, "the real part comes before the imaginary one"),
+            ParseComplexErrorKind::NegativeModulus => write!(f, "a modulus cannot be negative"),
+            ParseComplexErrorKind::ImaginaryModulus => write!(f, "a modulus is a real number"),
+            ParseComplexErrorKind::Unexpected(c) => write!(f, "unexpected `{}`", c),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseComplexError {
+    pub offset: usize,
+    pub kind: ParseComplexErrorKind,
+}
+
+impl fmt::Display for ParseComplexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "at byte {}: {}", self.offset, self.kind)
+    }
+}
+
+impl std::error::Error for ParseComplexError {}
+
+// A real or imaginary number, its sign included.
+struct Term {
+    value: f64,
+    imaginary: bool,
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    offset: usize,
+}
+
+impl Parser<'_> {
+    fn rest(&self) -> &str {
+        &self.input[self.offset..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.offset += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.offset += rest.len() - rest.trim_start().len();
+    }
+
+    fn error(&self, offset: usize, kind: ParseComplexErrorKind) -> ParseComplexError {
+        ParseComplexError { offset, kind }
+    }
+
+    // What follows a complete number: the end, or an unexpected char.
+    fn end(&mut self) -> Result<(), ParseComplexError> {
+        self.skip_whitespace();
+        match self.peek() {
+            None => Ok(()),
+            Some(c) => Err(self.error(self.offset, ParseComplexErrorKind::Unexpected(c))),
+        }
+    }
+
+    fn complex(mut self) -> Result<Complex, ParseComplexError> {
+        self.skip_whitespace();
+        if self.peek().is_none() {
+            return Err(self.error(0, ParseComplexErrorKind::Empty));
+        }
+
+        let start = self.offset;
+        let first = self.term(true)?;
+        self.skip_whitespace();
+        let operator = self.offset;
+        if self.eat('∠') {
+            if first.imaginary {
+                return Err(self.error(start, ParseComplexErrorKind::ImaginaryModulus));
+            }
+            if first.value.is_sign_negative() {
+                return Err(self.error(start, ParseComplexErrorKind::NegativeModulus));
+            }
+            self.skip_whitespace();
+            let angle = self.term(true)?;
+            if angle.imaginary {
+                return Err(self.error(self.offset - 1, ParseComplexErrorKind::Unexpected('i')));
+            }
+            let radians = if self.eat('°') { angle.value.to_radians() } else { angle.value };
+            self.end()?;
+            return Ok(Complex::from_polar(first.value, radians));
+        }
+
+        let negative = match self.peek() {
+            None => {
+                return Ok(if first.imaginary { Complex::new(0.0, first.value) } else { Complex::new(first.value, 0.0) });
+            }
+            Some('+') => false,
+            Some('-') => true,
+            Some(c) => return Err(self.error(operator, ParseComplexErrorKind::Unexpected(c))),
+        };
+        if first.imaginary {
+            return Err(self.error(operator, ParseComplexErrorKind::ImaginaryFirst));
+        }
+        self.offset += 1;
+        self.skip_whitespace();
+        let second = self.term(false)?;
+        if !second.imaginary {
+            return Err(self.error(self.offset, ParseComplexErrorKind::ExpectedImaginary));
+        }
+        self.end()?;
+        Ok(Complex::new(first.value, if negative { -second.value } else { second.value }))
+    }
+
+    // A number, or `i` alone, with a sign if `signed`.
+    fn term(&mut self, signed: bool) -> Result<Term, ParseComplexError> {
+        let mut negative = false;
+        if signed && !self.eat('+') {
+            negative = self.eat('-');
+        }
+        let value = match self.number()? {
+            Some(value) => value,
+            // `i` is `1i`, unless it starts `inf`, which `number` took.
+            None if self.peek() == Some('i') => 1.0,
+            None => return Err(self.error(self.offset, ParseComplexErrorKind::ExpectedNumber)),
+        };
+        let imaginary = self.eat('i');
+        Ok(Term { value: if negative { -value } else { value }, imaginary })
+    }
+
+    // An unsigned `f64`, if one starts here.
+    fn number(&mut self) -> Result<Option<f64>, ParseComplexError> {
+        let start = self.offset;
+        let rest = self.rest();
+        for word in ["infinity", "inf", "nan"] {
+            if rest.get(..word.len()).is_some_and(|prefix| prefix.eq_ignore_ascii_case(word)) {
+                self.offset += word.len();
+                return Ok(Some(if word == "nan" { f64::NAN } else { f64::INFINITY }));
+            }
+        }
+
+        let digits = |parser: &mut Parser| {
+            let rest = parser.rest();
+            let count = rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+            parser.offset += count;
+            count
+        };
+        let mut count = digits(self);
+        if self.eat('.') {
+            count += digits(self);
+        }
+        if count == 0 {
+            self.offset = start;
+            return Ok(None);
+        }
+        if self.eat('e') || self.eat('E') {
+            if !self.eat('+') {
+                self.eat('-');
+            }
+            if digits(self) == 0 {
+                return Err(self.error(self.offset, ParseComplexErrorKind::ExpectedExponent));
+            }
+        }
+        // The text is a valid float by now.
+        Ok(Some(self.input[start..self.offset].parse().expect("a float literal")))
+    }
+}
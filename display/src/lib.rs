@@ -0,0 +1,223 @@
+// To customize the putput appearance `fmt::Display` is used and it must be implemented
+// for our type. Implementing it looks like this>
+use std::fmt;
+use std::io::{self, Write};
+use std::str::FromStr;
+
+use cmdline::Args;
+
+mod complex;
+
+pub use complex::{Complex, ParseComplexError, ParseComplexErrorKind};
+
+// Define a structure with which `fmt::Display` will be implemented.
+// This is a tuple struct named `Structure` that contains an `i32`.
+pub struct Structure(pub i32);
+
+// To use the `{}` marker, the trait `fmt::Display` must be implemented
+// manually for the type.
+impl fmt::Display for Structure {
+    // this trait requires `fmt` with this exact signature.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Write strictly the first element into the supplied output
+        // stream: `f`. Returns `fmt::Result` which indicates whether the
+        // operation succeeded or failed. Note that `write!` uses syntax which
+        // is very similar to `println!`.
+        write!(f, "{}", self.0)
+    }
+}
+
+// A structure holding two numbers. `Debug` will be derived so the results can
+// be compared with `Display`.
+#[derive(Debug)]
+pub struct MinMax(pub i64, pub i64);
+
+// Implement `Display` for `MinMax`.
+impl fmt::Display for MinMax {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // use `self.number` to refer to each positional data point.
+        write!(f, "({} {})", self.0, self.1)
+    }
+}
+
+// Define a structure where the fields are nameable for comparison.
+#[derive(Debug, PartialEq)]
+pub struct Point2D {
+    pub x: f64,
+    pub y: f64,
+}
+
+// Similarly implement `Display` for `Point2D`.
+impl fmt::Display for Point2D {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Customize so only `x` and `y` are denoted.
+        write!(f, "x: {}, y; {}", self.x, self.y)
+    }
+}
+
+// `Complex` has a `Display` too, and the arithmetic of complex numbers, in
+// its own module.
+// The way back from `Display`: `FromStr` reads the text `Display` writes, so
+// `x.to_string().parse()` gives `x` again (except for a NaN, which equals
+// nothing). The numbers are read by `f64::from_str`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub input: String,
+    pub reason: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "cannot parse {:?}: {}", self.input, self.reason)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+fn parse_f64(input: &str, text: &str) -> Result<f64, ParseError> {
+    text.parse().map_err(|err| ParseError {
+        input: input.to_string(),
+        reason: format!("invalid number `{}`: {}", text, err),
+    })
+}
+
+// `x: 3.3, y; 7.2`, semicolon included.
+impl FromStr for Point2D {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Point2D, ParseError> {
+        let (x, y) = s
+            .strip_prefix("x: ")
+            .and_then(|rest| rest.split_once(", y; "))
+            .ok_or_else(|| ParseError { input: s.to_string(), reason: "expected `x: <x>, y; <y>`".to_string() })?;
+        Ok(Point2D { x: parse_f64(s, x)?, y: parse_f64(s, y)? })
+    }
+}
+
+pub const USAGE: &str = "\
+usage: display [options]
+
+options:
+    --x <f64>       x coordinate of the point (default: 3.3)
+    --y <f64>       y coordinate of the point (default: 7.2)
+    --real <f64>    real part of the complex number (default: 3.3)
+    --imag <f64>    imaginary part of the complex number (default: 7.2)
+    --complex <complex>
+                    the complex number as a whole, `3.3 + 7.2i`, `-2i` or in
+                    polar form `2∠45°`, instead of --real and --imag
+    -h, --help      print this message";
+
+// The point and the complex number compared with `Display` and `Debug`.
+pub struct Params {
+    pub point: Point2D,
+    pub complex: Complex,
+}
+
+impl Default for Params {
+    fn default() -> Params {
+        Params {
+            point: Point2D { x: 3.3, y: 7.2 },
+            complex: Complex { real: 3.3, imag: 7.2 },
+        }
+    }
+}
+
+impl Params {
+    pub fn from_args(mut args: Args) -> Result<Params, cmdline::Error> {
+        let default = Params::default();
+        let params = Params {
+            point: Point2D {
+                x: args.get("x", default.point.x)?,
+                y: args.get("y", default.point.y)?,
+            },
+            complex: {
+                let complex = Complex {
+                    real: args.get("real", default.complex.real)?,
+                    imag: args.get("imag", default.complex.imag)?,
+                };
+                args.get("complex", complex)?
+            },
+        };
+        args.finish()?;
+        Ok(params)
+    }
+}
+
+pub fn run(out: &mut dyn Write) -> io::Result<()> {
+    run_with(out, &Params::default())
+}
+
+pub fn run_args(args: Args, out: &mut dyn Write) -> Result<(), cmdline::Error> {
+    let params = Params::from_args(args)?;
+    Ok(run_with(out, &params)?)
+}
+
+pub fn run_with(out: &mut dyn Write, params: &Params) -> io::Result<()> {
+    let minmax = MinMax(0, 14);
+
+    writeln!(out, "Compare structures:")?;
+    writeln!(out, "Display: {}", minmax)?;
+    writeln!(out, "Debug: {:?}", minmax)?;
+
+    let big_range = MinMax(-300, 300);
+    let small_range = MinMax(-3, 3);
+
+    writeln!(out, "The big range is {big} and the small reange is {small}",
+            big = big_range,
+             small = small_range)?;
+
+    writeln!(out, "The big range is {big:?} and the small reange is {small:?}",
+            big = big_range,
+            small = small_range)?;
+
+    let point = Point2D { x: params.point.x, y: params.point.y };
+
+    writeln!(out, "Compare points:")?;
+    writeln!(out, "Display: {}", point)?;
+    writeln!(out, "Debug: {:?}", point)?;
+
+    let complex = Complex {
+        real: params.complex.real, imag: params.complex.imag};
+
+    writeln!(out, "Compare complex:")?;
+    writeln!(out, "Display: {}", complex)?;
+    writeln!(out, "Debug: {:?}", complex)?;
+
+    // With the operators of `Complex`, and a precision for the parts
+    let w = Complex::new(1.0, -2.0);
+    writeln!(out, "Arithmetic with w = {}:", w)?;
+    writeln!(out, "z + w = {:.3}", complex + w)?;
+    writeln!(out, "z - w = {:.3}", complex - w)?;
+    writeln!(out, "z * w = {:.3}", complex * w)?;
+    writeln!(out, "z / w = {:.3}", complex / w)?;
+    writeln!(out, "2 * z - 1 = {:.3}", 2.0 * complex - 1.0)?;
+    writeln!(out, "conjugate of z = {}", complex.conjugate())?;
+    let (modulus, argument) = complex.to_polar();
+    writeln!(out, "z in polar form: modulus {:.3}, argument {:.3} rad", modulus, argument)?;
+    writeln!(out, "and back: {:.3}", Complex::from_polar(modulus, argument))?;
+    writeln!(out, "exp(z) = {:.3}", complex.exp())?;
+    writeln!(out, "ln(z) = {:.3}", complex.ln())?;
+    writeln!(out, "sqrt(z) = {:.3}, squared {:.3}", complex.sqrt(), complex.sqrt() * complex.sqrt())?;
+    writeln!(out, "sqrt(-4) = {}", Complex::from(-4.0).sqrt())?;
+    writeln!(out, "i^i = {:.5}", Complex::I.powc(Complex::I))?;
+    // Euler's identity, e^(iπ) + 1 = 0, up to rounding
+    let euler = (Complex::I * std::f64::consts::PI).exp() + 1.0;
+    writeln!(out, "e^(iπ) + 1 = {:e}, zero: {}", euler.modulus(), euler.approx_eq(Complex::ZERO, 1e-12))?;
+
+    // `FromStr` reads more forms than `Display` writes, and points at the
+    // first character it can't read
+    for text in ["-2i", "3.3-7.2i", "1e-3 + 2.5e2i", "2∠45°", "3 + 4", "1 - -2i", "2i + 3"] {
+        match text.parse::<Complex>() {
+            Ok(z) => writeln!(out, "{:<14} = {:.3}", text, z)?,
+            Err(err) => writeln!(out, "{:<14} error {}", text, err)?,
+        }
+    }
+
+    // Error. Both `Debug` and `Display` were implemented, but `{:b}`
+    // requires `fmt::Binary` to be implemented. This will not work.
+    // println!("What does Point2D look like in binary: {:b}?", point);
+    // fmt::Display has been implemented but fmt::Binary has not
+    // and therefore cannot be used.
+
+    Ok(())
+}
\ No newline at end of file
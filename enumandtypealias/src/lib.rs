@@ -0,0 +1,137 @@
+// The `enum` keyword allows the creation of a type which may
+// be one of a few different variants.
+// Any variant which is valid as a `struct` is also valid as an `enum`.
+
+// Create an `enum` to classify a web event. Note how both
+// names and type information together specify the variant:
+// `PageLoad != PageUnload` and `KeyPress(char) != Paste(String)`.
+// Each is different and independent.
+
+// `x` only shows that a variant can be reached through its alias.
+#![allow(unused_variables)]
+
+use std::io::{self, Write};
+
+use cmdline::Args;
+
+pub enum WebEvent {
+    // An `enum` may either be `unit-like`,
+    PageLoad,
+    PageUnload,
+    // like tuple structs,
+    KeyPress(char),
+    Paste(String),
+    // or c-like structures.
+    Click { x: i64, y: i64 },
+}
+
+// A function which takes a `WebEvent` enum as an argument and writes what happened to `out`.
+pub fn inspect(out: &mut dyn Write, event: WebEvent) -> io::Result<()> {
+    match event {
+        WebEvent::PageLoad => writeln!(out, "page loaded"),
+        WebEvent::PageUnload => writeln!(out, "page unloaded"),
+        // Destructure `c` from inside the `enum`.
+        WebEvent::KeyPress(c) => writeln!(out, "key pressed: '{}'.", c),
+        WebEvent::Paste(s) => writeln!(out, "pasted: \"{}\".", s),
+        // Destructure `Click` into `x` and `y`.
+        WebEvent::Click { x, y } => {
+            writeln!(out, "clicked at x={}, y={}.", x, y)
+        },
+    }
+}
+
+// If you use a type alias, you can refer to each enum variant via its alias.
+// This might be useful if the enum's name is too long or too generic and
+// you want to rename it.
+pub enum VeryVerboseEnumOfThingsToDoWithNumbers {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+// Create a type alias
+pub type Operations = VeryVerboseEnumOfThingsToDoWithNumbers;
+
+// One of the places where we most often will see this is `impl` blocks using the `Self` alias.
+impl VeryVerboseEnumOfThingsToDoWithNumbers {
+    pub fn run(&self, x: i32, y: i32)-> i32 {
+        match self {
+            Self::Add => x + y,
+            Self::Subtract => x - y,
+            Self::Multiply => x * y,
+            Self::Divide => x / y,
+        }
+    }
+}
+
+pub const USAGE: &str = "\
+usage: enumandtypealias [options]
+
+options:
+    --key <char>      the key of the `KeyPress` event (default: x)
+    --paste <text>    the text of the `Paste` event (default: my text)
+    --x <i64>         x coordinate of the `Click` event (default: 20)
+    --y <i64>         y coordinate of the `Click` event (default: 80)
+    -h, --help        print this message";
+
+// The data carried by the events handed to `inspect`.
+pub struct Params {
+    pub key: char,
+    pub paste: String,
+    pub click: (i64, i64),
+}
+
+impl Default for Params {
+    fn default() -> Params {
+        Params {
+            key: 'x',
+            paste: "my text".to_owned(),
+            click: (20, 80),
+        }
+    }
+}
+
+impl Params {
+    pub fn from_args(mut args: Args) -> Result<Params, cmdline::Error> {
+        let default = Params::default();
+        let params = Params {
+            key: args.get("key", default.key)?,
+            paste: args.get("paste", default.paste)?,
+            click: (args.get("x", default.click.0)?, args.get("y", default.click.1)?),
+        };
+        args.finish()?;
+        Ok(params)
+    }
+}
+
+pub fn run(out: &mut dyn Write) -> io::Result<()> {
+    run_with(out, &Params::default())
+}
+
+pub fn run_args(args: Args, out: &mut dyn Write) -> Result<(), cmdline::Error> {
+    let params = Params::from_args(args)?;
+    Ok(run_with(out, &params)?)
+}
+
+pub fn run_with(out: &mut dyn Write, params: &Params) -> io::Result<()> {
+    let pressed = WebEvent::KeyPress(params.key);
+    // `to_owned()` creates an owned `String` from a string slice.
+    let pasted = WebEvent::Paste(params.paste.as_str().to_owned());
+    let click = WebEvent::Click { x: params.click.0, y: params.click.1 };
+    let load = WebEvent::PageLoad;
+    let unload = WebEvent::PageUnload;
+
+    inspect(out, pressed)?;
+    inspect(out, pasted)?;
+    inspect(out, click)?;
+    inspect(out, load)?;
+    inspect(out, unload)?;
+
+    // working with alias
+    // we can refer to each variant via its alias, not its long and
+    // inconvenient name.
+    let x = Operations::Add;
+
+    Ok(())
+}
@@ -0,0 +1,157 @@
+// A common way to implement `linked-list` is via `enums`
+
+use std::io::{self, Write};
+
+use cmdline::Args;
+use codec::{Decode, DecodeError, DecodeErrorKind, Decoder, Encode, Encoder};
+
+use crate::List::*;
+
+#[derive(Debug, PartialEq)]
+pub enum List {
+    // Cons: Tuple struct that wraps an element and a pointer to the next node
+    Cons(u32, Box<List>),
+    // Nil: a node that signifies the end of the linked list
+    Nil,
+}
+
+// Methods can be attached to an enum
+impl List {
+    // Create an empty list
+    pub fn new() -> List {
+        // `Nil` has type `List`
+        Nil
+    }
+
+    // Consume a list and return the same list with a new element at its front
+    pub fn prepend(self, elem: u32) -> List {
+        // `Cons` also has a type List
+        Cons(elem, Box::new(self))
+    }
+
+    // Return the lenfght of the list
+    pub fn len(&self) -> u32 {
+        // `self` has to be matched because the behavior of this method
+        // depends on the variant of `self`.
+        // `self` has type `&List` and `*self` has type `List`, matching on a
+        // concrete type `T` is preferred over a match on a reference `&T`.
+        // After Rust 2018 you can use self here and tail (with no ref) below as well,
+        // Rust will infer &s and ref tail.
+        // See https://doc.rust-lang.org/edition-guide/rust-2018/ownership-and-lifetimes/default-match-bindings.html
+        match *self {
+            // can't take ownership of the tail because `self` is borrowed
+            // instead take a reference to the tail
+            Cons(_, ref tail) => 1 + tail.len(),
+            // Base case: an empty list has zero length
+            Nil => 0
+        }
+    }
+
+    // An empty list is just `Nil`
+    pub fn is_empty(&self) -> bool {
+        matches!(*self, Nil)
+    }
+
+    // Return a representation of the list as a (heap allocated) string
+    pub fn stringify(&self) -> String {
+        match *self {
+            Cons(head, ref tail) => {
+                // `format!` is similar to `print!` but returns a heap
+                // allocated string instead of printing to the console
+                format!("{}, {}", head, tail.stringify())
+            },
+            Nil => {
+                "Nil!".to_string()
+            },
+        }
+    }
+}
+
+// The default list is the empty one
+impl Default for List {
+    fn default() -> List {
+        List::new()
+    }
+}
+
+// In binary, each node is a tag byte, 1 for `Cons` followed by its element
+// and 0 for `Nil`: the list 3, 2, 1 is `01 03 01 02 01 01 00` with varints.
+impl Encode for List {
+    fn encode(&self, encoder: &mut Encoder) {
+        let mut node = self;
+        while let Cons(elem, tail) = node {
+            encoder.write_u8(1);
+            elem.encode(encoder);
+            node = tail;
+        }
+        encoder.write_u8(0);
+    }
+}
+
+impl Decode for List {
+    fn decode(decoder: &mut Decoder) -> Result<List, DecodeError> {
+        let mut elems = Vec::new();
+        loop {
+            let offset = decoder.offset();
+            match decoder.read_u8()? {
+                0 => break,
+                1 => elems.push(u32::decode(decoder)?),
+                tag => return Err(DecodeError::new(offset, DecodeErrorKind::InvalidTag { ty: "List", tag })),
+            }
+        }
+        // The list is built from its end.
+        Ok(elems.into_iter().rev().fold(Nil, List::prepend))
+    }
+}
+
+pub const USAGE: &str = "\
+usage: enumlinkedlist [--elements <u32,...>]
+
+options:
+    --elements <u32,...>    the elements prepended to the list, in order (default: 1,2,3)
+    -h, --help              print this message";
+
+// The elements prepended, one after the other, to the empty list.
+pub struct Params {
+    pub elements: Vec<u32>,
+}
+
+impl Default for Params {
+    fn default() -> Params {
+        Params { elements: vec![1, 2, 3] }
+    }
+}
+
+impl Params {
+    pub fn from_args(mut args: Args) -> Result<Params, cmdline::Error> {
+        let default = Params::default();
+        let params = Params { elements: args.get_list("elements", default.elements)? };
+        args.finish()?;
+        Ok(params)
+    }
+}
+
+pub fn run(out: &mut dyn Write) -> io::Result<()> {
+    run_with(out, &Params::default())
+}
+
+pub fn run_args(args: Args, out: &mut dyn Write) -> Result<(), cmdline::Error> {
+    let params = Params::from_args(args)?;
+    Ok(run_with(out, &params)?)
+}
+
+pub fn run_with(out: &mut dyn Write, params: &Params) -> io::Result<()> {
+    // Create an empty linked list
+    let mut list = List::new();
+
+    // Prepend some elements
+    for &elem in &params.elements {
+        list = list.prepend(elem);
+    }
+
+    // Show the final state of the list
+    writeln!(out, "linked list has length: {}", list.len())?;
+    writeln!(out, "{}", list.stringify())?;
+
+    Ok(())
+}
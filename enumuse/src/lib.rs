@@ -0,0 +1,76 @@
+// The `use` declaration can be used so manual scoping isn't needed.
+#![allow(dead_code)]
+
+use std::io::{self, Write};
+
+use cmdline::Args;
+
+pub enum Status {
+    Rich,
+    Poor,
+}
+
+pub enum Work {
+    Civilian,
+    Soldier,
+}
+
+// `enum` can also be used as C-like enums.
+
+// enum with implicit discriminator (starts at 0)
+pub enum Number {
+    Zero,
+    One,
+    Two,
+}
+
+// enum with explicit discriminator
+pub enum Color {
+    Red = 0xff0000,
+    Green = 0x00ff00,
+    Blue = 0x0000ff,
+}
+
+pub const USAGE: &str = "\
+usage: enumuse
+
+This example takes no arguments.";
+
+pub fn run_args(args: Args, out: &mut dyn Write) -> Result<(), cmdline::Error> {
+    args.finish()?;
+    Ok(run(out)?)
+}
+
+pub fn run(out: &mut dyn Write) -> io::Result<()> {
+    // Explicitly `use` each name so they are available without
+    // manual scoping.
+    use crate::Status::{Poor, Rich};
+    // Automatically `use` each name inside `Work`.
+    use crate::Work::*;
+
+    // Equivalent to `Status::Poor`.
+    let status = Poor;
+    // Equivalent to `Work::Civilian`.
+    let work = Civilian;
+
+    match status {
+        // Note the lack of scoping because of the explicit `use` above.
+        Rich => writeln!(out, "The rich have lots of money")?,
+        Poor => writeln!(out, "the poor have no money")?
+    }
+
+    match work {
+        // Note again the lack of scoping.
+        Civilian => writeln!(out, "Civilians work")?,
+        Soldier => writeln!(out, "Soldiers fight")?
+    }
+
+    // `enums` can be cast as integers.
+    writeln!(out, "zero is {}", Number::Zero as i32)?;
+    writeln!(out, "one is {}", Number::One as i32)?;
+
+    writeln!(out, "roses are #{:06x}", Color::Red as i32)?;
+    writeln!(out, "violets are #{:06x}", Color::Blue as i32)?;
+
+    Ok(())
+}
\ No newline at end of file
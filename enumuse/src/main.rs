@@ -1,59 +1,5 @@
-// The `use` declaration can be used so manual scoping isn't needed.
-#![allow(dead_code)]
+use std::process::ExitCode;
 
-enum Status {
-    Rich,
-    Poor,
+fn main() -> ExitCode {
+    cmdline::main(enumuse::USAGE, enumuse::run_args)
 }
-
-enum Work {
-    Civilian,
-    Soldier,
-}
-
-// `enum` can also be used as C-like enums.
-
-// enum with implicit discriminator (starts at 0)
-enum Number {
-    Zero,
-    One,
-    Two,
-}
-
-// enum with explicit discriminator
-enum Color {
-    Red = 0xff0000,
-    Green = 0x00ff00,
-    Blue = 0x0000ff,
-}
-fn main() {
-    // Explicitly `use` each name so they are available without
-    // manual scoping.
-    use crate::Status::{Poor, Rich};
-    // Automatically `use` each name inside `Work`.
-    use crate::Work::*;
-
-    // Equivalent to `Status::Poor`.
-    let status = Poor;
-    // Equivalent to `Work::Civilian`.
-    let work = Civilian;
-
-    match status {
-        // Note the lack of scoping because of the explicit `use` above.
-        Rich => println!("The rich have lots of money"),
-        Poor => println!("the poor have no money")
-    }
-
-    match work {
-        // Note again the lack of scoping.
-        Civilian => println!("Civilians work"),
-        Soldier => println!("Soldiers fight")
-    }
-
-    // `enums` can be cast as integers.
-    println!("zero is {}", Number::Zero as i32);
-    println!("one is {}", Number::One as i32);
-
-    println!("roses are #{:06x}", Color::Red as i32);
-    println!("violets are #{:06x}", Color::Blue as i32);
-}
\ No newline at end of file
@@ -0,0 +1,95 @@
+// A Rust program is (mostly) made up of a series of statements:
+// fn main() {
+    // statement 1
+    // statement 2
+    // statement 3
+// }
+
+// There are a few kinds of statements in Rust.
+// The most common two are declaring a variable binding
+// and using `;` with an expression:
+
+// Expression statements are shown for what they are, even when they do nothing.
+#![allow(path_statements, unused_must_use, clippy::no_effect, clippy::let_unit_value)]
+
+use std::io::{self, Write};
+
+use cmdline::Args;
+
+pub const USAGE: &str = "\
+usage: expressions [--x <u32>]
+
+options:
+    --x <u32>     the value the blocks compute with (default: 5, at most 1625)
+    -h, --help    print this message";
+
+// The inputs of the example, try different values for `x` with `--x`.
+pub struct Params {
+    pub x: u32,
+}
+
+impl Default for Params {
+    fn default() -> Params {
+        Params { x: 5 }
+    }
+}
+
+impl Params {
+    pub fn from_args(mut args: Args) -> Result<Params, cmdline::Error> {
+        let default = Params::default();
+        let params = Params { x: args.get("x", default.x)? };
+        args.finish()?;
+
+        // `x_cube` has to fit in a `u32` too.
+        if params.x > 1625 {
+            return Err(cmdline::Error::Usage(format!("`--x {}` is too big, its cube overflows a u32", params.x)));
+        }
+        Ok(params)
+    }
+}
+
+pub fn run(out: &mut dyn Write) -> io::Result<()> {
+    run_with(out, &Params::default())
+}
+
+pub fn run_args(args: Args, out: &mut dyn Write) -> Result<(), cmdline::Error> {
+    let params = Params::from_args(args)?;
+    Ok(run_with(out, &params)?)
+}
+
+pub fn run_with(out: &mut dyn Write, params: &Params) -> io::Result<()> {
+    // variable binding
+    let x = 5;
+
+    // expression
+    x;
+    x + 1;
+    15;
+
+    // Blocks are expressions too, so they can be used as values in assignments.
+    // The last expression in the block will be assigned to the place expression
+    // such as a local variable. However, if the last expression of the block
+    // ends with a semicolon, the return value will be ().
+    let x = params.x;
+
+    let y = {
+        let x_squared = x * x;
+        writeln!(out, "x_squared is {:?}", x_squared)?;
+        let x_cube = x_squared * x;
+        writeln!(out, "x_cube is {:?}", x_cube)?;
+
+        // This expression will be assigned to `y`
+        x_cube + x_squared + x
+    };
+
+    let z = {
+        // The semicolon suppresses this expression and `()` is assigned to `z`
+        2 * x;
+    };
+
+    writeln!(out, "x is {:?}", x)?;
+    writeln!(out, "y is {:?}", y)?;
+    writeln!(out, "z is {:?}", z)?;
+
+    Ok(())
+}
@@ -0,0 +1,599 @@
+#![allow(unreachable_code)]
+// Not every variant is constructed, and color models keep their usual acronyms.
+#![allow(dead_code, unused_variables, unused_labels)]
+#![allow(clippy::upper_case_acronyms, clippy::never_loop, clippy::match_single_binding)]
+#![allow(clippy::disallowed_names, clippy::single_match, clippy::toplevel_ref_arg)]
+
+use std::io::{self, Write};
+
+use cmdline::Args;
+
+pub enum Color {
+    // These 3 are specified solely by their name.
+    Red,
+    Blue,
+    Green,
+    // These likewise tie `u32` tuples to different names: color models.
+    RGB(u32, u32, u32),
+    HSV(u32, u32, u32),
+    HSL(u32, u32, u32),
+    CMY(u32, u32, u32),
+    CMYK(u32, u32, u32, u32),
+}
+
+pub enum Temperature {
+    Celsius(i32),
+    Farenheit(i32),
+}
+
+
+// A function `age` which returns a 32bit unsigned integer value
+pub fn age() -> u32 {
+    15
+}
+
+pub enum Boh {
+    Bar,
+    Baz,
+    Qux(u32)
+}
+
+pub const USAGE: &str = "\
+usage: flowofcontrol [options]
+
+options:
+    --n <i32>              the number classified by `if`/`else` (default: 20)
+    --limit <u32>          how far the fizzbuzz loops count (default: 100)
+    --number <i32>         the number described by `match` (default: 13)
+    --temperature <i32>    the temperature in Celsius checked by the guards (default: 35)
+    --age <u32>            the age bound with `@` (default: 15)
+    -h, --help             print this message";
+
+// The inputs of the example. Each one is a value the comments below invite
+// you to change: try them from the command line instead.
+pub struct Params {
+    pub n: i32,
+    pub limit: u32,
+    pub number: i32,
+    pub temperature: i32,
+    pub age: u32,
+}
+
+impl Default for Params {
+    fn default() -> Params {
+        Params {
+            n: 20,
+            limit: 100,
+            number: 13,
+            temperature: 35,
+            age: age(),
+        }
+    }
+}
+
+impl Params {
+    pub fn from_args(mut args: Args) -> Result<Params, cmdline::Error> {
+        let default = Params::default();
+        let params = Params {
+            n: args.get("n", default.n)?,
+            limit: args.get("limit", default.limit)?,
+            number: args.get("number", default.number)?,
+            temperature: args.get("temperature", default.temperature)?,
+            age: args.get("age", default.age)?,
+        };
+        args.finish()?;
+        Ok(params)
+    }
+}
+
+pub fn run(out: &mut dyn Write) -> io::Result<()> {
+    run_with(out, &Params::default())
+}
+
+pub fn run_args(args: Args, out: &mut dyn Write) -> Result<(), cmdline::Error> {
+    let params = Params::from_args(args)?;
+    Ok(run_with(out, &params)?)
+}
+
+pub fn run_with(out: &mut dyn Write, params: &Params) -> io::Result<()> {
+    // if/else
+    // Branching with `if-else` is similar to other languages.
+    // Unlike many of them, the boolean condition doesn't need to be surrounded
+    // by parentheses and each condition is followed by a block.
+    // `if-else` conditionals are expressions and all branches must return the same type.
+    let n = params.n;
+
+    if n < 0 {
+        write!(out, "{} is negative", n)?;
+    } else if n > 0 {
+        write!(out, "{} is positive", n)?;
+    } else {
+        write!(out, "{} is zero", n)?;
+    }
+
+    let big_n =
+        if n < 10 && n > -10 {
+            writeln!(out, ", and is a small number, increase ten-fold")?;
+
+            // This expression returns an `i32`.
+            10 * n
+        } else {
+            writeln!(out, ", and is a big number, halve the number")?;
+
+            // This expression must return an `i32` as well
+            n / 2 //try suppressing this expression with a semicolon
+        };
+    writeln!(out, "{:?} -> {:?}", n, big_n)?;
+
+    // loop
+    // Rust provides a `loop` keyword to indicate an infinite loop.
+    // The `break` statement can be used to exit a loop at anytime,
+    // whereas the `continue` statement can be used to skip the rest of the
+    // iteration and start a new one.
+    let mut count = 0u32;
+
+    writeln!(out, "Let's count until infinity...")?;
+
+    // Infinite loop
+    loop {
+        count += 1;
+
+        if count == 3 {
+            writeln!(out, "three")?;
+
+            // Skip the rest of this iteration
+            continue;
+        }
+        writeln!(out, "{}", count)?;
+
+        if count == 5 {
+            writeln!(out, "OK, that's enough")?;
+
+            // Exit the loop
+            break;
+        }
+    }
+
+    // Nesting and labels
+    // It's possible to `break` or `continue` outer loops when dealing with nested loops.
+    // In these cases the loops must be annotated with some 'label' and the label
+    // must be passed to the `break/continue` statement.
+
+    'outer: loop {
+        writeln!(out, "Entered the outer loop")?;
+
+        'inner: loop {
+            writeln!(out, "Entering the inner loop")?;
+
+            // This would break only the inner loop
+            // break;
+
+            // This breaks the outer loop
+            break 'outer;
+        }
+
+        writeln!(out, "This point will never be reached")?;
+    }
+
+    writeln!(out, "Exited the outer loop")?;
+
+    // Returning from loops
+    // One of the uses of a `loop` is to retry an operation until it succeeds.
+    // If the operation returns a value though, you might need to pass it to the
+    // rest of the code: put it after the `break` and it will be returned by the `loop` expression.
+    let mut counter = 0;
+
+    let result = loop {
+        counter += 1;
+
+        if counter == 10 {
+            break counter * 2;
+        }
+    };
+
+    assert_eq!(result, 20);
+
+    // while
+    // The `while` keyword can be used to run a loop while a condition is true
+    // A FizzBuzz using a `while` loop
+    writeln!(out, "Playing fizzbuzz from 1 to {} with a 'while' loop", params.limit)?;
+    // Setting a counter variable
+    let mut n = 1;
+
+    // loop while `n` is not past the limit
+    while n <= params.limit {
+        if n % 15 == 0 {
+            writeln!(out, "fizzbuzz")?;
+        } else if n % 3 == 0 {
+            writeln!(out, "fizz")?;
+        } else if n % 5 == 0 {
+            writeln!(out, "buzz")?;
+        } else {
+            writeln!(out, "{}", n)?;
+        }
+
+        // increment counter
+        n += 1;
+    }
+
+    // for and range
+    // The `for` construct can be used to iterate through an `Iterator`.
+    // One of easiest ways to create an iterator is to use range notation `a..b`.
+    // This yelds values from `a` (inclusive) to `b` (exclusive) in steps of one.
+    // Write FizzBuzz using a `for` loop.
+    writeln!(out, "Playing fizzbuzz from 1 to {} with a 'for' loop", params.limit)?;
+    for n in 1..=params.limit { //`a..=b` means both ends are inclusive, with the default limit this is `1..101`
+        if n % 15 == 0 {
+            writeln!(out, "fizzbuzz")?;
+        } else if n % 3 == 0 {
+            writeln!(out, "fizz")?;
+        } else if n % 5 == 0 {
+            writeln!(out, "buzz")?;
+        } else {
+            writeln!(out, "{}", n)?;
+        }
+    }
+
+    // for and iterators
+    // the `for in` construct is able to interact with an `Iterator` in several ways.
+    // `iter`, `into_iter` and `iter_mut` all handle the conversion of a collection into an interator in different ways, by providing different views on the data within.
+
+    // `iter` borrows each element of the collection through each iteration, leaving the collection untouched and available for reuse after the loop.
+    writeln!(out, "using 'iter'")?;
+    let names = vec!["Bob", "John", "Frank"];
+
+    for name in names.iter() {
+        match name {
+            &"Frank" => writeln!(out, "There is Frank among us")?,
+            // TODO Try deleting the & and matching just "Frank"
+            _ => writeln!(out, "Hello {}", name)?,
+        }
+    }
+
+    writeln!(out, "names: {:?}", names)?;
+
+    // `into_iter` consumes the colelction so that on each iteration the exact data is provided.
+    // Once the collection has been consumed it is no longer available fo reuse as it has been
+    // 'moved' within the loop
+    writeln!(out, "using 'into_iter'")?;
+    let names = vec!["Bob", "Frank", "Ferris"];
+
+    for name in names.into_iter() {
+        match name {
+            "Ferris" => writeln!(out, "There is a rustacean among us!")?,
+            _ => writeln!(out, "Hello {}", name)?,
+        }
+    }
+
+    // println!("names: {:?}", newnames);
+    // FIXME ^ Comment out this line
+
+    // `iter_mut` borrows each element of the collection allowing for the collection to be modified in place
+    writeln!(out, "using 'iter_mut'")?;
+    let mut names = vec!["Bob", "Frank", "Ferris"];
+
+    for name in names.iter_mut() {
+        *name = match name {
+            &mut "Ferris" => "There is a rustacean among us!",
+            _ => "Hello",
+        }
+    }
+
+    writeln!(out, "names: {:?}", names)?;
+
+    // match
+    // Rust provides pattern matching via the `match` keyword which can be used like a C switch.
+    // The first matching arm is evaluated and all possible values must be covered.
+    let number = params.number; //try different values for number with `--number`
+
+    writeln!(out, "Tell me about {}", number)?;
+    match number {
+        // match a single value
+        1 => writeln!(out, "One")?,
+        // match several values
+        2 | 3| 5 | 7 | 11 | 13 => writeln!(out, "This is a prime")?,
+        // TODO try adding 13 to the list of prime values
+        13..=19 => writeln!(out, "A teen")?,
+        // handle the rest of the cases
+        _ => writeln!(out, "Nothing special")?,
+        // TODO try commenting out this catch-all arm.
+    }
+
+    let boolean = true;
+    // Match is an expression too
+    let binary = match boolean {
+        // The arms of a match must cover all the possible values
+        false => 0,
+        true => 1,
+        // TODO try commenting out one of these arms.
+    };
+
+    writeln!(out, "{} -> {}", boolean, binary)?;
+
+    // Destructuring: a `match` block can destructure items in a variety of ways
+    // 1) Destructuring Tuples
+    // 2) Destructuring Arrays and Slices
+    // 3) Destructuring Enums
+    // 4) Destructuring Pointers
+    // 5) Destructuring Structures
+
+    // Destructuring Tuples
+    let triple = (0, -2, 3);
+    // TODO ^ Try different values for `triple`
+
+    writeln!(out, "Tell me about {:?}", triple)?;
+    // Match can be used to destructure a tuple
+    match triple {
+        // Destructure the second and third elements
+        (0, y, z) => writeln!(out, "First is `0`, `y` is {:?}, and `z` is {:?}", y, z)?,
+        (1, ..)  => writeln!(out, "First is `1` and the rest doesn't matter")?,
+        (.., 2)  => writeln!(out, "last is `2` and the rest doesn't matter")?,
+        (3, .., 4)  => writeln!(out, "First is `3`, last is `4`, and the rest doesn't matter")?,
+        // `..` can be used to ignore the rest of the tuple
+        _      => writeln!(out, "It doesn't matter what they are")?,
+        // `_` means don't bind the value to a variable
+    }
+
+    // Destructuring Arrays and Slices
+        // Try changing the values in the array, or make it a slice!
+    let array = [1, -2, 6];
+
+    match array {
+        // Binds the second and the third elements to the respective variables
+        [0, second, third] =>
+            writeln!(out, "array[0] = 0, array[1] = {}, array[2] = {}", second, third)?,
+
+        // Single values can be ignored with _
+        [1, _, third] => writeln!(out, 
+            "array[0] = 1, array[2] = {} and array[1] was ignored",
+            third
+        )?,
+
+        // You can also bind some and ignore the rest
+        [-1, second, ..] => writeln!(out, 
+            "array[0] = -1, array[1] = {} and all the other ones were ignored",
+            second
+        )?,
+        // The code below would not compile
+        // [-1, second] => ...
+
+        // Or store them in another array/slice (the type depends on
+        // that of the value that is being matched against)
+        [3, second, tail @ ..] => writeln!(out, 
+            "array[0] = 3, array[1] = {} and the other elements were {:?}",
+            second, tail
+        )?,
+
+        // Combining these patterns, we can, for example, bind the first and
+        // last values, and store the rest of them in a single array
+        [first, middle @ .., last] => writeln!(out, 
+            "array[0] = {}, middle = {:?}, array[2] = {}",
+            first, middle, last
+        )?,
+    }
+
+    // Destructurin Enums
+    // `allow dead_code` required to silence warnings because only one variant is used.
+    let color = Color::CMYK(122, 17, 40, 1);
+    // TODO try differnt variants for `color`
+
+    writeln!(out, "What color is it?")?;
+    // An `enum` can be destructured using a `match`.
+    match color {
+        Color::Red => writeln!(out, "The color is red")?,
+        Color::Blue => writeln!(out, "The color is blue")?,
+        Color::Green => writeln!(out, "The color is green")?,
+        Color::RGB(r, g, b) =>
+            writeln!(out, "Red: {}, Green: {}, Blue: {}", r, g, b)?,
+        Color::HSV(h, s, v) =>
+            writeln!(out, "Hue: {}, Saturation: {}, value: {}", h, s, v)?,
+        Color::HSL(h, s, l) =>
+            writeln!(out, "Hue: {}, Saturation: {}, Lightness: {}", h, s, l)?,
+        Color::CMY(c, m, y) =>
+            writeln!(out, "Cyan: {}, Magenta: {}, Yellow: {}", c, m, y)?,
+        Color::CMYK(c, m, y, k) =>
+            writeln!(out, "Cyan: {}, Magenta: {}, Yellow: {}, key (black): {}", c, m, y, k)?,
+        // Don't need another arm because alla variants have been examined.
+    }
+
+    // Destructuring pointers&references
+    // For pointers a distinction needs to be made between destructuring and dereferencing
+    // as they are different concepts
+    // Dereferencing uses `*`
+    // Destructuring uses `&`, `ref` and `ref mut`.
+
+    // Assign a reference of type `i32`. The `&val` signifies there is a reference being assigned.
+    let reference = &4;
+
+    match reference {
+        // If `reference` is pattern matched against `&val`, it results in a comparison like:
+        // `&i32`
+        // `&val`
+        // We see that if the matching `&`s are dropped, then the `i32` should be assigned to `val`.
+        &val => writeln!(out, "Got a value via destructuring: {:?}", val)?,
+    }
+
+    // To avoid the `&`, you dereference before matching.
+    match *reference {
+        val => writeln!(out, "Got a value via dereferencing: {:?}", val)?,
+    }
+
+    // What if we don't start with a reference? `reference` was a `&`
+    // because the right side was already a reference.
+    // This is not a reference because the right side is not one.
+    let _not_a_reference = 3;
+
+    // Rust provides `ref` for exactly this purpose. It modifies the assignment
+    // so that a reference is created for the element; this reference is assigned.
+    let ref _is_a_reference = 3;
+
+    // Accordingly by defining 2 values without references, references can be retrieved
+    // via `ref` and `ref mut`.
+    let value = 5;
+    let mut mut_value = 6;
+
+    // Use `ref` keyword to create a reference
+    match value {
+        ref r => writeln!(out, "Got a reference to a value: {:?}", r)?,
+    }
+
+    // And we can use `ref mut similarly
+    match mut_value {
+        ref mut m => {
+            // Got a reference. Gotta dereference it before we can add anything to it.
+            *m += 10;
+            writeln!(out, "We added 10. `mut_value`: {:?}", m)?;
+        },
+    }
+
+    // Destructuring structs
+    struct Foo {
+        x: (u32, u32),
+        y: u32,
+    }
+
+    // Try channging the values in the struct to see what happens
+    let foo = Foo { x: (1, 2), y: 3 };
+
+    match foo {
+        Foo { x: (1, b), y } => writeln!(out, "First of x is 1, b is {}, y is {}", b, y)?,
+
+        // You can destructure structs and rename the variables, the order is not important
+        Foo { y: 2, x: i } => writeln!(out, "y is 2, i = {:?}", i)?,
+
+        // And you can also ignore some variables
+        Foo { y, .. } => writeln!(out, "y = {}, we don-t care about x", y)?,
+
+        // This will give an error: pattern does not mention field `x`
+        // Foo { y } => println!("y = {}", y),
+    }
+
+    // Guards
+    // A `match` `gaurd` can be added to filter the arm
+    let temperature = Temperature::Celsius(params.temperature);
+    // TODO try different values for `temperature` with `--temperature`
+
+    match temperature {
+        Temperature::Celsius(t) if t > 30 => writeln!(out, "{}C is above 30 Celsius", t)?,
+        // the `if` condition part is a `guard`
+        Temperature::Celsius(t) => writeln!(out, "{}C is below 30 Celsius", t)?,
+
+        Temperature::Farenheit(t) if t > 86 => writeln!(out, "{}F is above 86 Farenheit", t)?,
+        Temperature::Farenheit(t) => writeln!(out, "{}F is below 86 Farenheit", t)?,
+    }
+
+    // Binding
+    // Indirectly accessing a variable makes it impossible to branch and use that variable
+    // without re-binding. `match` provides the `@` sigil for binding values to names.
+    writeln!(out, "Tell me what type of person you are")?;
+
+    match params.age {
+        0             => writeln!(out, "I haven't celebrated my first birthday yet")?,
+        // Could `match` 1 ..=12 directly but then what age would the child be?
+        // Instead bind to `n` for the sequence 1 ..=12. Now the age can be reported.
+        n @ 1  ..= 12 => writeln!(out, "I'm a child of age: {:?}", n)?,
+        n @ 13 ..= 19 => writeln!(out, "I'm a teen of age: {:?}", n)?,
+        // Nothing bound. Return the result.
+        n             => writeln!(out, "I'm an old person of age: {:?}", n)?,
+    }
+
+    // if let
+    // For some use cases, when matching enums, `match` is awkward. For example:
+    // Make `optional` of type `Option<i32>`
+    let optional = Some(7);
+
+    match optional {
+        Some(i) => {
+            writeln!(out, "This is a really long string and `{:?}`", i)?;
+            // ^ Needed 2 indentations just so we could destructure `i` from the option.
+        },
+        _ => {},
+        // ^ Required because `match` is exhaustive. Doesn't it seem like wasted space?
+    };
+
+    // `if let` is cleaner for this use case and in addition allows various failure options to be specified.
+
+    // All have type `Option<i32>`
+    let number = Some(7);
+    let letter: Option<i32> = None;
+    let emoticon: Option<i32> = None;
+
+    // The `if let` constructs reads: "if `let` destructures `number` into `Some(i)`,
+    // evaluate the block (`{}`)".
+    if let Some(i) = number {
+        writeln!(out, "Matched {:?}", i)?;
+    }
+
+    // If you need to specifiy a failure, use an `else` block:
+    if let Some(i) = letter {
+        writeln!(out, "Matched {:?}", i)?;
+    } else {
+        // Destructure failed. Change to the failure case.
+        writeln!(out, "Didn't match a number. Let's go with a letter.")?;
+    }
+
+    // Provide an altered failing condition.
+    let i_like_letters = false;
+
+    if let Some(i) = emoticon {
+        writeln!(out, "Matched {:?}", i)?;
+    // Destructure failed. Evaluate an `else if` condition to see if
+    // the alternate failure branch should be taken:
+    } else if i_like_letters {
+        writeln!(out, "Didn't match a number. Let's go with a letter.")?;
+    } else {
+        // The condition evaluated false. This branch is the default:
+        writeln!(out, "I don't like letters. Let's go with an emoticon.")?;
+    }
+
+    // In the same way `if let` can be used to match any enum value:
+    // Create example variables
+    let a = Boh::Bar;
+    let b = Boh::Baz;
+    let c = Boh::Qux(100);
+
+    // Variable a matches Foo::Bar
+    if let Boh::Bar = a {
+        writeln!(out, "a is foobar")?;
+    }
+
+    // Variable b does not match Foo::Bar
+    // So this will print nothing
+    if let Boh::Bar = b {
+        writeln!(out, "b is foobar")?;
+    }
+
+    // Variable c matches Foo::Qux which has a value
+    // Similar to Some() in the previous example
+    if let Boh::Qux(value) = c {
+        writeln!(out, "c is {}", value)?;
+    }
+
+    // Binding also works with `if let`
+    if let Boh::Qux(value @ 100) = c {
+        writeln!(out, "c is one hundred")?;
+    }
+
+    // while let
+    // Similar to `if let`, `while let` can make awkward `match` sequences more tolerable.
+        // Make `optional` of type `Option<i32>`
+    let mut optional = Some(0);
+
+    // This reads: "while `let` destructures `optional` into
+    // `Some(i)`, evaluate the block (`{}`). Else `break`.
+    while let Some(i) = optional {
+        if i > 9 {
+            writeln!(out, "Greater than 9, quit!")?;
+            optional = None;
+        } else {
+            writeln!(out, "`i` is `{:?}`. Try again.", i)?;
+            optional = Some(i + 1);
+        }
+        // ^ Less rightward drift and doesn't require
+        // explicitly handling the failing case.
+    }
+    // ^ `if let` had additional optional `else`/`else if`
+    // clauses. `while let` does not have these.
+
+    Ok(())
+}
@@ -0,0 +1,109 @@
+/* This is another type of comment, a block comment.
+In general line comments are the recommended comment style.
+But block comments are extremely useful for temporarily disabling chunks of code.
+Printing in Rust is handled by a series of `macros` defined in `std::fmt`, some of which include
+`format!`, `print!`, `println!`, `eprint!`, `eprintln!`.
+*/
+
+// Literal arguments are kept to show how `{}` gets replaced.
+#![allow(clippy::write_literal)]
+
+use std::io::{self, Write};
+
+use cmdline::Args;
+
+pub const USAGE: &str = "\
+usage: formattedprint [--number <f64>] [--width <usize>]
+
+options:
+    --number <f64>     the number right-aligned at the end (default: 1)
+    --width <usize>    the width it is aligned to (default: 5)
+    -h, --help         print this message";
+
+// The value captured by `{number:>width$}` and its width.
+pub struct Params {
+    pub number: f64,
+    pub width: usize,
+}
+
+impl Default for Params {
+    fn default() -> Params {
+        Params { number: 1.0, width: 5 }
+    }
+}
+
+impl Params {
+    pub fn from_args(mut args: Args) -> Result<Params, cmdline::Error> {
+        let default = Params::default();
+        let params = Params {
+            number: args.get("number", default.number)?,
+            width: args.get("width", default.width)?,
+        };
+        args.finish()?;
+        Ok(params)
+    }
+}
+
+pub fn run(out: &mut dyn Write) -> io::Result<()> {
+    run_with(out, &Params::default())
+}
+
+pub fn run_args(args: Args, out: &mut dyn Write) -> Result<(), cmdline::Error> {
+    let params = Params::from_args(args)?;
+    Ok(run_with(out, &params)?)
+}
+
+pub fn run_with(out: &mut dyn Write, params: &Params) -> io::Result<()> {
+    // in general the {} will be replaced with any arguments
+    // Here it will be stringified.
+    writeln!(out, "{} days", 31)?;
+
+    // Positional arguments can be used. Specifying an integer inside `{}`
+    // determines which additional argument will be replaced. Arguments start
+    // at 0 immediately after the format string
+    writeln!(out, "{0}, this is {1}. {1}, this is {0}", "Alice", "Bob")?;
+
+    // As can named arguments
+    writeln!(out, "{subject} {verb} {object}",
+            object="the lazy dog",
+            subject="the quick brown fox",
+            verb="jumps over")?;
+
+    // Different formatting can be invoked by specifying the format character after a
+    // `:` character.
+    writeln!(out, "Base 10 repr:                {}",   69420)?;
+    writeln!(out, "Base 2 (binary) repr:        {:b}", 69420)?;
+    writeln!(out, "Base 8 (octal) repr:         {:o}", 69420)?;
+    writeln!(out, "Base 16 (hexadecimal) repr:  {:x}", 69420)?;
+    writeln!(out, "Base 16 (hexadecimal) repr:  {:X}", 69420)?;
+
+    // We can right-align text with a specified width. This will output
+    // "    1". 4 white spaces and a "1", for a total width of 5.
+    writeln!(out, "{number:>5}", number=1)?;
+
+    // We can pad numbers with extra zeroes. This will output "00001".
+    writeln!(out, "{number:0>5}", number=1)?;
+
+    // We can use named arguments in the format specifier by appending a `$` character.
+    writeln!(out, "{number:0>width$}", number=1, width=5)?;
+
+    // Rust even checks to make sure the correct number of arguments are used.
+    writeln!(out, "My name is {0}, {1} {0}", "Bond", "James")?;
+
+    // Only types that implement `fmt::Display` can be formatted with `{}`.
+    // User-defined types do not implement `fmt::Display` by default.
+
+    #[allow(dead_code)]
+    struct Structure(i32);
+
+    // This will not compile because `Structure` does not implement `fmt::Display`
+    // println!("This struct `{}` won't print...", Structure(3));
+
+    // For Rust 1.58 and above, we can directly capture the argument from a surrounding variable.
+    // Just like the above, this will output "     1". 5 white spaces and a "1".
+    let number: f64 = params.number;
+    let width: usize = params.width;
+    writeln!(out, "{number:>width$}")?;
+
+    Ok(())
+}
\ No newline at end of file
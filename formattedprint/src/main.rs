@@ -1,64 +1,5 @@
-/* This is another type of comment, a block comment.
-In general line comments are the recommended comment style.
-But block comments are extremely useful for temporarily disabling chunks of code.
-Printing in Rust is handled by a series of `macros` defined in `std::fmt`, some of which include
-`format!`, `print!`, `println!`, `eprint!`, `eprintln!`.
-*/
+use std::process::ExitCode;
 
-fn main() {
-    // in general the {} will be replaced with any arguments
-    // Here it will be stringified.
-    println!("{} days", 31);
-
-    // Positional arguments can be used. Specifying an integer inside `{}`
-    // determines which additional argument will be replaced. Arguments start
-    // at 0 immediately after the format string
-    println!("{0}, this is {1}. {1}, this is {0}", "Alice", "Bob");
-
-    // As can named arguments
-    println!("{subject} {verb} {object}",
-            object="the lazy dog",
-            subject="the quick brown fox",
-            verb="jumps over");
-
-    // Different formatting can be invoked by specifying the format character after a
-    // `:` character.
-    println!("Base 10 repr:                {}",   69420);
-    println!("Base 2 (binary) repr:        {:b}", 69420);
-    println!("Base 8 (octal) repr:         {:o}", 69420);
-    println!("Base 16 (hexadecimal) repr:  {:x}", 69420);
-    println!("Base 16 (hexadecimal) repr:  {:X}", 69420);
-
-    // We can right-align text with a specified width. This will output
-    // "    1". 4 white spaces and a "1", for a total width of 5.
-    println!("{number:>5}", number=1);
-
-    // We can pad numbers with extra zeroes. This will output "00001".
-    println!("{number:0>5}", number=1);
-
-    // We can use named arguments in the format specifier by appending a `$` character.
-    println!("{number:0>width$}", number=1, width=5);
-
-    // Rust even checks to make sure the correct number of arguments are used.
-    println!("My name is {0}, {1} {0}", "Bond", "James");
-
-    // Only types that implement `fmt::Display` can be formatted with `{}`.
-    // User-defined types do not implement `fmt::Display` by default.
-
-    #[allow(dead_code)]
-    struct Structure(i32);
-
-    // This will not compile because `Structure` does not implement `fmt::Display`
-    // println!("This struct `{}` won't print...", Structure(3));
-
-    // For Rust 1.58 and above, we can directly capture the argument from a surrounding variable.
-    // Just like the above, this will output "     1". 5 white spaces and a "1".
-    let number: f64 = 1.0;
-    let width: usize = 5;
-    println!("{number:>width$}");
-
-
-
-
-
-}
\ No newline at end of file
+fn main() -> ExitCode {
+    cmdline::main(formattedprint::USAGE, formattedprint::run_args)
+}
@@ -0,0 +1,183 @@
+// The formatting functionality is implemented via `traits` and there is
+// one trait for each argument type.
+// The most common formatting `trait` is `Display`, which handles cases
+// where the argument type is left unspecified: `{}` for instance.
+
+use std::fmt::{self, Formatter, Display};
+use std::io::{self, Write};
+use std::str::FromStr;
+
+use cmdline::Args;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct City {
+    pub name: String,
+    // Latitude
+    pub lat: f32,
+    // Longitude
+    pub lon: f32,
+}
+
+impl Display for City {
+    // `f` is a buffer and this method must write the formatted string into it.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let lat_c = if self.lat >= 0.0 { 'N' } else { 'S'};
+        let lon_c = if self.lon >= 0.0 { 'E' } else { 'W'};
+
+        // `write!` is like `format!`, but it will write the formatted strinf
+        // into a buffer (the first argument)
+        write!(f, "{}: {:.3}°{} {:.3}°{}",
+            self.name, self.lat.abs(), lat_c, self.lon.abs(), lon_c)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Color {
+    pub red: u8,
+    pub green: u8,
+    pub blue: u8,
+}
+
+impl Display for Color {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "RGB ({},{},{}) 0x{:0>2x?}{:0>2x?}{:0>2x?}", self.red, self.green, self.blue, self.red, self.green, self.blue)
+    }
+}
+
+// `FromStr` reads back what `Display` writes. A `City` only keeps three
+// decimals of its coordinates, so only a city whose coordinates have at most
+// three decimals comes back identical.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub input: String,
+    pub reason: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "cannot parse {:?}: {}", self.input, self.reason)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+fn parse_error(input: &str, reason: impl Into<String>) -> ParseError {
+    ParseError { input: input.to_string(), reason: reason.into() }
+}
+
+// Text that reads as a value but isn't the way `Display` writes it, such as
+// `007` or `53.3480°N`, is an error too: parsing inverts `Display` exactly.
+fn canonical<T: Display>(input: &str, value: T) -> Result<T, ParseError> {
+    let written = value.to_string();
+    if written != input {
+        return Err(parse_error(input, format!("not written the way `Display` writes it, {:?}", written)));
+    }
+    Ok(value)
+}
+
+// One coordinate, `53.348°N`: the number is never signed, the direction
+// letter gives the sign.
+fn parse_coordinate(input: &str, text: &str, positive: char, negative: char) -> Result<f32, ParseError> {
+    let expected = || parse_error(input, format!("expected a coordinate such as `12.345°{}`, found `{}`", positive, text));
+    let (number, direction) = text.split_once('°').ok_or_else(expected)?;
+    let sign = match direction {
+        d if d.len() == 1 && d.starts_with(positive) => 1.0,
+        d if d.len() == 1 && d.starts_with(negative) => -1.0,
+        _ => return Err(expected()),
+    };
+    if number.starts_with(['+', '-']) {
+        return Err(expected());
+    }
+    let value: f32 = number.parse().map_err(|err| parse_error(input, format!("invalid number `{}`: {}", number, err)))?;
+    Ok(sign * value)
+}
+
+// `Dublin: 53.348°N 6.260°W`. The name is everything before the last `: `.
+impl FromStr for City {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<City, ParseError> {
+        let (name, coordinates) = s.rsplit_once(": ").ok_or_else(|| parse_error(s, "expected `<name>: <lat> <lon>`"))?;
+        let (lat, lon) = coordinates.split_once(' ').ok_or_else(|| parse_error(s, "expected `<lat> <lon>`"))?;
+        let city = City {
+            name: name.to_string(),
+            lat: parse_coordinate(s, lat, 'N', 'S')?,
+            lon: parse_coordinate(s, lon, 'E', 'W')?,
+        };
+        canonical(s, city)
+    }
+}
+
+// `RGB (128,255,90) 0x80ff5a`. The hex code must match the components.
+impl FromStr for Color {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Color, ParseError> {
+        let (components, hex) = s
+            .strip_prefix("RGB (")
+            .and_then(|rest| rest.split_once(") 0x"))
+            .ok_or_else(|| parse_error(s, "expected `RGB (<red>,<green>,<blue>) 0x<hex>`"))?;
+
+        let mut values = [0u8; 3];
+        let mut parts = components.split(',');
+        for value in &mut values {
+            let part = parts.next().ok_or_else(|| parse_error(s, "expected three components"))?;
+            if !part.starts_with(|c: char| c.is_ascii_digit()) {
+                return Err(parse_error(s, format!("invalid component `{}`", part)));
+            }
+            *value = part.parse().map_err(|err| parse_error(s, format!("invalid component `{}`: {}", part, err)))?;
+        }
+        if parts.next().is_some() {
+            return Err(parse_error(s, "expected three components"));
+        }
+
+        let [red, green, blue] = values;
+        let color = Color { red, green, blue };
+        if hex != format!("{:02x}{:02x}{:02x}", red, green, blue) {
+            return Err(parse_error(s, format!("the hex code `0x{}` doesn't match the components", hex)));
+        }
+        canonical(s, color)
+    }
+}
+
+pub const USAGE: &str = "\
+usage: formatting
+
+This example takes no arguments.";
+
+pub fn run_args(args: Args, out: &mut dyn Write) -> Result<(), cmdline::Error> {
+    args.finish()?;
+    Ok(run(out)?)
+}
+
+// The coordinates are the original ones, more precise than an `f32` holds.
+#[allow(clippy::excessive_precision)]
+pub fn run(out: &mut dyn Write) -> io::Result<()> {
+    for city in [
+        City { name: "Dublin".to_string(), lat: 53.347778, lon: -6.259722 },
+        City { name: "Oslo".to_string(), lat: 59.95, lon: 10.75 },
+        City { name: "Vancouver".to_string(), lat: 49.25, lon: -123.1},
+    ].iter() {
+        writeln!(out, "{}", *city)?;
+    }
+    for color in [
+        Color { red: 128, green: 255, blue: 90 },
+        Color { red: 0, green: 3, blue: 254 },
+        Color { red: 0, green: 0, blue: 0 },
+    ].iter() {
+        // Switch this to use {} once you've added an implementation
+        // for `fmt::Display`.
+        writeln!(out, "{:?}", *color)?;
+    }
+    for color in [
+        Color { red: 128, green: 255, blue: 90 },
+        Color { red: 0, green: 3, blue: 254 },
+        Color { red: 0, green: 0, blue: 0 },
+    ].iter() {
+        // Switch this to use {} once you've added an implementation
+        // for `fmt::Display`.
+        writeln!(out, "{}", *color)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,334 @@
+// The Mandelbrot and Julia sets, drawn with `display::Complex`. Both come
+// from the same iteration, `z = z² + c`: once `|z|` is above 2 it runs off to
+// infinity, otherwise it may stay bounded forever.
+//
+// The Mandelbrot set is the set of `c` for which the orbit of 0 stays
+// bounded; a Julia set, for one fixed `c`, the set of starting points `z`
+// whose orbit does. Each point of the view is colored by its escape time,
+// the number of iterations it took to leave the disk of radius 2, and the
+// points still inside after `--iterations` are taken to be in the set.
+//
+// The rows of the view are shared out between threads, and `--bench` times
+// the rendering: nearly all of it is `Complex` multiplications and additions.
+
+use std::f64::consts::{LN_2, TAU};
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::Mutex;
+use std::thread;
+use std::time::Instant;
+
+use cmdline::Args;
+use display::Complex;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Fractal {
+    Mandelbrot,
+    // The Julia set of `c`.
+    Julia(Complex),
+}
+
+impl Fractal {
+    // The orbit of the point `point` of the view.
+    pub fn orbit(self, point: Complex, limit: u32) -> Orbit {
+        match self {
+            Fractal::Mandelbrot => orbit(Complex::ZERO, point, limit),
+            Fractal::Julia(c) => orbit(point, c, limit),
+        }
+    }
+}
+
+impl fmt::Display for Fractal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Fractal::Mandelbrot => write!(f, "Mandelbrot set"),
+            Fractal::Julia(c) => write!(f, "Julia set of c = {}", c),
+        }
+    }
+}
+
+// Where the orbit of `z` under `z² + c` stood when the iteration stopped.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Orbit {
+    pub iterations: u32,
+    pub z: Complex,
+}
+
+impl Orbit {
+    pub fn escaped(&self) -> bool {
+        self.z.modulus_squared() > 4.0
+    }
+
+    // The escape time made continuous, so colors blend instead of forming
+    // bands: the further `z` went past 2, the less of the last iteration
+    // counts.
+    pub fn smooth(&self) -> f64 {
+        self.iterations as f64 + 1.0 - self.z.modulus().ln().ln() / LN_2
+    }
+}
+
+// Iterate `z = z² + c` until `|z| > 2`, compared squared to skip the square
+// root, or `limit` iterations.
+pub fn orbit(mut z: Complex, c: Complex, limit: u32) -> Orbit {
+    let mut iterations = 0;
+    while iterations < limit && z.modulus_squared() <= 4.0 {
+        z = z * z + c;
+        iterations += 1;
+    }
+    Orbit { iterations, z }
+}
+
+// A grid of `columns` by `rows` cells over the complex plane, `span` wide on
+// the real axis. A cell is `aspect` times as tall as it is wide: about 2 for
+// a character of the terminal, 1 for a pixel.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Viewport {
+    pub center: Complex,
+    pub span: f64,
+    pub columns: usize,
+    pub rows: usize,
+    pub aspect: f64,
+}
+
+impl Viewport {
+    // The point at the center of a cell, row 0 at the top.
+    pub fn point(&self, column: usize, row: usize) -> Complex {
+        let step = self.span / self.columns as f64;
+        let x = column as f64 + 0.5 - self.columns as f64 / 2.0;
+        let y = row as f64 + 0.5 - self.rows as f64 / 2.0;
+        self.center + Complex::new(x * step, -y * step * self.aspect)
+    }
+}
+
+// The orbit of every cell of `viewport`, row after row. Threads take the
+// next row left until there is none, since the rows through the set take
+// far longer than the others; there are never more threads than rows.
+pub fn render(fractal: Fractal, viewport: &Viewport, limit: u32, threads: usize) -> Vec<Orbit> {
+    let mut orbits = vec![Orbit { iterations: 0, z: Complex::ZERO }; viewport.columns * viewport.rows];
+    let rows = Mutex::new(orbits.chunks_mut(viewport.columns).enumerate());
+
+    thread::scope(|scope| {
+        for _ in 0..threads.min(viewport.rows) {
+            scope.spawn(|| loop {
+                // The lock is released at the end of the statement, before
+                // the row is rendered.
+                let next = rows.lock().unwrap().next();
+                let Some((row, cells)) = next else { break };
+                for (column, cell) in cells.iter_mut().enumerate() {
+                    *cell = fractal.orbit(viewport.point(column, row), limit);
+                }
+            });
+        }
+    });
+    orbits
+}
+
+// From the fastest to escape to the slowest, the set itself is `@`.
+const PALETTE: &[u8] = b" .:-=+*%#";
+
+fn ascii(orbit: &Orbit, limit: u32) -> char {
+    if !orbit.escaped() {
+        return '@';
+    }
+    // Most points escape within a few iterations: a log scale spreads them.
+    let level = (orbit.iterations as f64).ln() / (limit.max(2) as f64).ln();
+    PALETTE[((level * PALETTE.len() as f64) as usize).min(PALETTE.len() - 1)] as char
+}
+
+pub fn write_ascii(out: &mut dyn Write, viewport: &Viewport, orbits: &[Orbit], limit: u32) -> io::Result<()> {
+    for row in orbits.chunks(viewport.columns) {
+        let line: String = row.iter().map(|orbit| ascii(orbit, limit)).collect();
+        writeln!(out, "{}", line.trim_end())?;
+    }
+    Ok(())
+}
+
+// A color wheel turning with the smooth escape time, the set in black.
+fn rgb(orbit: &Orbit) -> [u8; 3] {
+    if !orbit.escaped() {
+        return [0, 0, 0];
+    }
+    let turn = orbit.smooth() / 32.0;
+    [0.0, 1.0 / 3.0, 2.0 / 3.0].map(|phase| (127.5 - 127.5 * (TAU * (turn + phase)).cos()) as u8)
+}
+
+// A binary PPM: a short text header, then three bytes per pixel.
+pub fn write_ppm(out: &mut dyn Write, viewport: &Viewport, orbits: &[Orbit]) -> io::Result<()> {
+    write!(out, "P6\n{} {}\n255\n", viewport.columns, viewport.rows)?;
+    let pixels: Vec<u8> = orbits.iter().flat_map(rgb).collect();
+    out.write_all(&pixels)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Set {
+    Mandelbrot,
+    Julia,
+}
+
+impl FromStr for Set {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Set, String> {
+        match s {
+            "mandelbrot" => Ok(Set::Mandelbrot),
+            "julia" => Ok(Set::Julia),
+            _ => Err("expected `mandelbrot` or `julia`".to_string()),
+        }
+    }
+}
+
+pub const USAGE: &str = "\
+usage: fractals [options]
+
+options:
+    --set <set>            `mandelbrot` or `julia` (default: mandelbrot)
+    --c <complex>          the constant of the Julia set, with `--set julia`
+                           (default: -0.8 + 0.156i)
+    --center <complex>     the center of the view (default: -0.75 for the
+                           Mandelbrot set, 0 for a Julia set)
+    --span <f64>           the width of the view on the real axis (default: 3)
+    --iterations <u32>     the iterations before a point is taken to be in
+                           the set (default: 100)
+    --columns <usize>      the width, in characters or pixels (default: 72)
+    --rows <usize>         the height, in characters or pixels (default: 28)
+    --ppm <path>           write a PPM image to <path> instead of drawing
+                           in the terminal
+    --threads <usize>      the threads rendering the rows, at most 256
+                           (default: one per CPU)
+    --bench                print how long the rendering took
+    -h, --help             print this message";
+
+// 4096 by 4096, already some 400 MB of orbits.
+pub const MAX_CELLS: usize = 1 << 24;
+
+// More than enough to time the rendering on any machine, far below the
+// thread limits of most.
+pub const MAX_THREADS: usize = 256;
+
+pub struct Params {
+    pub fractal: Fractal,
+    pub center: Complex,
+    pub span: f64,
+    pub iterations: u32,
+    pub columns: usize,
+    pub rows: usize,
+    pub ppm: Option<PathBuf>,
+    pub threads: usize,
+    pub bench: bool,
+}
+
+impl Default for Params {
+    fn default() -> Params {
+        Params {
+            fractal: Fractal::Mandelbrot,
+            center: Complex::new(-0.75, 0.0),
+            span: 3.0,
+            iterations: 100,
+            columns: 72,
+            rows: 28,
+            ppm: None,
+            threads: thread::available_parallelism().map_or(1, |n| n.get().min(MAX_THREADS)),
+            bench: false,
+        }
+    }
+}
+
+impl Params {
+    pub fn from_args(mut args: Args) -> Result<Params, cmdline::Error> {
+        let default = Params::default();
+        let set = args.get("set", Set::Mandelbrot)?;
+        let c = args.get_opt("c")?;
+        let (fractal, center) = match (set, c) {
+            (Set::Mandelbrot, None) => (Fractal::Mandelbrot, default.center),
+            (Set::Mandelbrot, Some(_)) => {
+                return Err(cmdline::Error::Usage("`--c` is the constant of a Julia set, it needs `--set julia`".to_string()));
+            }
+            (Set::Julia, c) => (Fractal::Julia(c.unwrap_or(Complex::new(-0.8, 0.156))), Complex::ZERO),
+        };
+        let params = Params {
+            fractal,
+            center: args.get("center", center)?,
+            span: args.get("span", default.span)?,
+            iterations: args.get("iterations", default.iterations)?,
+            columns: args.get("columns", default.columns)?,
+            rows: args.get("rows", default.rows)?,
+            ppm: args.get_opt("ppm")?,
+            threads: args.get("threads", default.threads)?,
+            bench: args.switch("bench")?,
+        };
+        args.finish()?;
+
+        for (name, value) in [("iterations", params.iterations as usize), ("columns", params.columns), ("rows", params.rows), ("threads", params.threads)] {
+            if value == 0 {
+                return Err(cmdline::Error::Usage(format!("`--{}` must be at least 1", name)));
+            }
+        }
+        if params.columns.checked_mul(params.rows).is_none_or(|cells| cells > MAX_CELLS) {
+            return Err(cmdline::Error::Usage(format!(
+                "`--columns {} --rows {}` is too large, the view is limited to {} cells",
+                params.columns, params.rows, MAX_CELLS
+            )));
+        }
+        if params.threads > MAX_THREADS {
+            return Err(cmdline::Error::Usage(format!("`--threads` must be at most {}, not {}", MAX_THREADS, params.threads)));
+        }
+        if !(params.span > 0.0 && params.span.is_finite()) {
+            return Err(cmdline::Error::Usage(format!("`--span` must be positive, not {}", params.span)));
+        }
+        Ok(params)
+    }
+}
+
+pub fn run(out: &mut dyn Write) -> io::Result<()> {
+    run_with(out, &Params::default())
+}
+
+pub fn run_args(args: Args, out: &mut dyn Write) -> Result<(), cmdline::Error> {
+    let params = Params::from_args(args)?;
+    Ok(run_with(out, &params)?)
+}
+
+pub fn run_with(out: &mut dyn Write, params: &Params) -> io::Result<()> {
+    // A character is about twice as tall as it is wide, a pixel square.
+    let aspect = if params.ppm.is_some() { 1.0 } else { 2.0 };
+    let viewport = Viewport { center: params.center, span: params.span, columns: params.columns, rows: params.rows, aspect };
+
+    let start = Instant::now();
+    let orbits = render(params.fractal, &viewport, params.iterations, params.threads);
+    let elapsed = start.elapsed();
+
+    match &params.ppm {
+        Some(path) => {
+            let mut file = BufWriter::new(File::create(path)?);
+            write_ppm(&mut file, &viewport, &orbits)?;
+            file.flush()?;
+            writeln!(out, "{}: wrote {}, {}x{} pixels", params.fractal, path.display(), params.columns, params.rows)?;
+        }
+        None => {
+            writeln!(out, "{}, centered on {}, {} wide, {} iterations", params.fractal, params.center, params.span, params.iterations)?;
+            write_ascii(out, &viewport, &orbits, params.iterations)?;
+        }
+    }
+
+    // The same whatever the number of threads.
+    let iterations: u64 = orbits.iter().map(|orbit| orbit.iterations as u64).sum();
+    let inside = orbits.iter().filter(|orbit| !orbit.escaped()).count();
+    writeln!(out, "{} points, {} in the set, {} iterations", orbits.len(), inside, iterations)?;
+
+    if params.bench {
+        let seconds = elapsed.as_secs_f64();
+        let threads = params.threads.min(params.rows);
+        writeln!(
+            out,
+            "rendered in {:.3} ms on {} thread{}, {:.1} million iterations per second",
+            seconds * 1000.0,
+            threads,
+            if threads == 1 { "" } else { "s" },
+            iterations as f64 / seconds / 1e6
+        )?;
+    }
+    Ok(())
+}
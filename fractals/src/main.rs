@@ -0,0 +1,5 @@
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    cmdline::main(fractals::USAGE, fractals::run_args)
+}
@@ -0,0 +1,221 @@
+// Functions are declared using th e`fn` keyword.
+// Its arguments are type annotated, like variables, and
+// if the function returns a value, the return type must be specified after an arrow `->`
+// The final expression in the the function will be used as the return value.
+// Alternatively, the `return` statement can be used to return a valure earlier
+// from within the function, even from inside loops or if statements.
+
+// Let's rewrite FizzBuzz using functions.
+// Unlike C or C++ there is no restrictions on the order of function definitions.
+
+// Associated functions and methods
+// Some functions are connected to a particular type.
+// These come in two forms: associated functions and methods.
+// Associated functions are  functions that are defined on a type generally,
+// while methods are associated functions that are called on a particular instance of a type.
+
+// `is_divisible_by` spells out the modulo check on purpose.
+#![allow(clippy::manual_is_multiple_of)]
+
+use std::io::{self, Write};
+
+use cmdline::Args;
+use codec::{Decode, DecodeError, Decoder, Encode, Encoder};
+
+#[derive(Debug, PartialEq)]
+pub struct Point {
+    pub x: f64,
+    pub y: f64,
+}
+
+// Implementation block, all `Point` associated functions and methods go in here.
+impl Point {
+    // This is an associated function because this function is associated with a particular type
+    // that is Point.
+    // Associated functions don't need to be called with an instance.
+    // These functions are generally used like constructors.
+    pub fn origin() -> Point {
+        Point { x: 0.0, y: 0.0 }
+    }
+
+    // Another associated function taking two arguments:
+    pub fn new(x: f64, y: f64) -> Point {
+        Point { x, y }
+    }
+}
+
+// In binary, a point is `x` then `y`.
+impl Encode for Point {
+    fn encode(&self, encoder: &mut Encoder) {
+        self.x.encode(encoder);
+        self.y.encode(encoder);
+    }
+}
+
+impl Decode for Point {
+    fn decode(decoder: &mut Decoder) -> Result<Point, DecodeError> {
+        Ok(Point::new(f64::decode(decoder)?, f64::decode(decoder)?))
+    }
+}
+
+pub struct Rectangle {
+    pub p1: Point,
+    pub p2: Point,
+}
+
+impl Rectangle {
+    // This is a method
+    // `&self` is sugar for `self: &Self`, where `Self` is the type of the caller object.
+    // In this case `Self` is a `Rectangle`.
+    pub fn area(&self) -> f64 {
+        // `self` gives access to the struct fields via the dot operator
+        let Point { x: x1, y: y1 } = self.p1;
+        let Point { x: x2, y: y2 } = self.p2;
+
+        // `abs` is a `f64` method that returns the absolute value of the caller.
+        ((x1 - x2) * (y1 - y2)).abs()
+    }
+
+    pub fn perimeter(&self) -> f64 {
+        let Point { x: x1, y: y1 } = self.p1;
+        let Point { x: x2, y: y2 } = self.p2;
+
+        2.0 * ((x1 - x2).abs() + (y1 - y2).abs())
+    }
+
+    // This method requires the caller object to be mutable
+    // `&mut self` desugars to `self: &mut Self`
+    pub fn translate(&mut self, x: f64, y: f64) {
+        self.p1.x += x;
+        self.p2.x += x;
+
+        self.p1.y += y;
+        self.p2.y += y;
+
+    }
+}
+// `Pair` owns resources: two heap allocated integers.
+pub struct Pair(pub Box<i32>, pub Box<i32>);
+
+impl Pair {
+    // This method consumes the resources of the caller object
+    // `self` desugars to `self: Self`.
+    pub fn destroy(self, out: &mut dyn Write) -> io::Result<()> {
+        // Destructure `self`
+        let Pair(first, second) = self;
+
+        writeln!(out, "Destroying Pair ({}, {})", first, second)?;
+
+        // `first` and `second` go out of scope and get freed.
+        Ok(())
+    }
+}
+pub const USAGE: &str = "\
+usage: functions [--limit <u32>]
+
+options:
+    --limit <u32>    how far fizzbuzz counts (default: 100)
+    -h, --help       print this message";
+
+// The inputs of the example, try different values for `limit` with `--limit`.
+pub struct Params {
+    pub limit: u32,
+}
+
+impl Default for Params {
+    fn default() -> Params {
+        Params { limit: 100 }
+    }
+}
+
+impl Params {
+    pub fn from_args(mut args: Args) -> Result<Params, cmdline::Error> {
+        let default = Params::default();
+        let params = Params { limit: args.get("limit", default.limit)? };
+        args.finish()?;
+        Ok(params)
+    }
+}
+
+pub fn run(out: &mut dyn Write) -> io::Result<()> {
+    run_with(out, &Params::default())
+}
+
+pub fn run_args(args: Args, out: &mut dyn Write) -> Result<(), cmdline::Error> {
+    let params = Params::from_args(args)?;
+    Ok(run_with(out, &params)?)
+}
+
+pub fn run_with(out: &mut dyn Write, params: &Params) -> io::Result<()> {
+    // We can use this function here and define it somewhere later.
+    fizzbuzz_to(out, params.limit)
+}
+
+// Function that returns a boolean value
+pub fn is_divisible_by(lhs: u32, rhs: u32) -> bool {
+    // Corner case, ealy return
+    if rhs == 0 {
+        return false;
+    }
+
+    // This is an expression, the `return` keyword is not necessary here.
+    lhs % rhs == 0
+}
+
+// Functions that don't return a value, actually return the unit type `()`.
+// Writing to `out` can fail though, so here the `()` comes wrapped in an `io::Result`.
+pub fn fizzbuzz(out: &mut dyn Write, n: u32) -> io::Result<()> {
+    if is_divisible_by(n, 15) {
+        writeln!(out, "fizzbuzz")?;
+    } else if is_divisible_by(n, 3) {
+        writeln!(out, "fizz")?;
+    } else if is_divisible_by(n, 5) {
+        writeln!(out, "buzz")?;
+    } else {
+        writeln!(out, "{}", n)?;
+    }
+
+    Ok(())
+}
+
+// When a function returns the unit type `()`, the return type can be omitted from the signature.
+// `?` hands any error from `fizzbuzz` back to our caller.
+pub fn fizzbuzz_to(out: &mut dyn Write, n: u32) -> io::Result<()> {
+    for n in 1..=n {
+        fizzbuzz(out, n)?;
+    }
+
+    // Assocaited functions and methods
+    let rectangle = Rectangle {
+        p1: Point::origin(),
+        p2: Point::new(3.0, 4.0),
+    };
+
+    // Methods are called using the dot operator.
+    // Note that the first argument `&self` is implicitly passed so that
+    // `rectangle.perimeter()` === `Rectangle::perimeter(&rectangle)`.
+    writeln!(out, "Rectangle perimeter: {}", rectangle.perimeter())?;
+    writeln!(out, "Rectangle area: {}", rectangle.area())?;
+
+    let mut square = Rectangle {
+        p1: Point::origin(),
+        p2: Point::new(1.0, 1.0),
+    };
+
+    // Error! `rectangle` is immutable, but this method requires a mutable object
+    // rectangle.translate(1.0, 0.0);
+    // TODO ^ try uncommenting this line
+
+    // Okay! Mutable objects can call mutable methods
+    square.translate(1.0, 1.0);
+
+    let pair = Pair(Box::new(1), Box::new(2));
+
+    pair.destroy(out)?;
+
+    // Error! Previous `destroy` call "consumed" `pair`.
+    // pair.destroy(out)?;
+    // TODO ^ try cuncommenting this line.
+
+    Ok(())
+}
@@ -0,0 +1,275 @@
+// Integer arithmetic that reports overflow instead of panicking or wrapping.
+// A plain `200u8 + 100` panics in a debug build and wraps to 44 in a release
+// build. `Checked` wraps too, but remembers the first operation that
+// overflowed, and carries it through the rest of the expression:
+//
+//   let total = (Checked::new(200u8) + 100) / 2 + 1;
+//   total.get()    // Err(`200 + 100` overflows u8)
+//
+// so the whole expression is written once, with its usual operators, and
+// checked once at the end. Dividing by zero, `MIN / -1` and shifting by the
+// width of the type or more count as overflows, as they do for `checked_*`;
+// bits shifted out of the value don't.
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign};
+
+use crate::literal::IntType;
+
+use self::sealed::Sealed;
+
+pub trait Int: Copy + fmt::Display + Sealed {
+    const TYPE: IntType;
+}
+
+mod sealed {
+    // Each operation returns the checked result, or the wrapped one.
+    pub trait Sealed: Sized {
+        fn is_zero(&self) -> bool;
+        fn add(self, rhs: Self) -> Result<Self, Self>;
+        fn sub(self, rhs: Self) -> Result<Self, Self>;
+        fn mul(self, rhs: Self) -> Result<Self, Self>;
+        fn div(self, rhs: Self) -> Result<Self, Self>;
+        fn rem(self, rhs: Self) -> Result<Self, Self>;
+        fn neg(self) -> Result<Self, Self>;
+        fn shl(self, rhs: u32) -> Result<Self, Self>;
+        fn shr(self, rhs: u32) -> Result<Self, Self>;
+    }
+}
+
+// `wrapping_div` and `wrapping_rem` panic on a zero divisor, which has no
+// wrapped result: 0 stands for it.
+macro_rules! int {
+    ($($ty:ident: $int_type:ident),*) => {$(
+        impl Int for $ty {
+            const TYPE: IntType = IntType::$int_type;
+        }
+
+        impl Sealed for $ty {
+            fn is_zero(&self) -> bool {
+                *self == 0
+            }
+
+            fn add(self, rhs: $ty) -> Result<$ty, $ty> {
+                self.checked_add(rhs).ok_or(self.wrapping_add(rhs))
+            }
+
+            fn sub(self, rhs: $ty) -> Result<$ty, $ty> {
+                self.checked_sub(rhs).ok_or(self.wrapping_sub(rhs))
+            }
+
+            fn mul(self, rhs: $ty) -> Result<$ty, $ty> {
+                self.checked_mul(rhs).ok_or(self.wrapping_mul(rhs))
+            }
+
+            fn div(self, rhs: $ty) -> Result<$ty, $ty> {
+                self.checked_div(rhs).ok_or(if rhs == 0 { 0 } else { self.wrapping_div(rhs) })
+            }
+
+            fn rem(self, rhs: $ty) -> Result<$ty, $ty> {
+                self.checked_rem(rhs).ok_or(if rhs == 0 { 0 } else { self.wrapping_rem(rhs) })
+            }
+
+            fn neg(self) -> Result<$ty, $ty> {
+                self.checked_neg().ok_or(self.wrapping_neg())
+            }
+
+            fn shl(self, rhs: u32) -> Result<$ty, $ty> {
+                self.checked_shl(rhs).ok_or(self.wrapping_shl(rhs))
+            }
+
+            fn shr(self, rhs: u32) -> Result<$ty, $ty> {
+                self.checked_shr(rhs).ok_or(self.wrapping_shr(rhs))
+            }
+        }
+    )*};
+}
+
+int!(i8: I8, i16: I16, i32: I32, i64: I64, i128: I128, isize: Isize, u8: U8, u16: U16, u32: U32, u64: U64, u128: U128, usize: Usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    Shl,
+    Shr,
+}
+
+impl fmt::Display for Op {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Op::Add => "+",
+            Op::Sub => "-",
+            Op::Mul => "*",
+            Op::Div => "/",
+            Op::Rem => "%",
+            Op::Shl => "<<",
+            Op::Shr => ">>",
+        })
+    }
+}
+
+// The first operation of an expression that overflowed, with its operands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Overflow<T> {
+    Binary { op: Op, lhs: T, rhs: T },
+    Shift { op: Op, lhs: T, rhs: u32 },
+    Neg(T),
+}
+
+impl<T: Int> fmt::Display for Overflow<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let ty = T::TYPE.name();
+        match *self {
+            Overflow::Binary { op: op @ (Op::Div | Op::Rem), lhs, rhs } if rhs.is_zero() => {
+                write!(f, "`{} {} {}` divides by zero", lhs, op, rhs)
+            }
+            Overflow::Binary { op, lhs, rhs } => write!(f, "`{} {} {}` overflows {}", lhs, op, rhs, ty),
+            Overflow::Shift { op, lhs, rhs } => {
+                write!(f, "`{} {} {}` shifts by the width of {}, {} bits, or more", lhs, op, rhs, ty, T::TYPE.bits())
+            }
+            Overflow::Neg(value) => write!(f, "`-({})` overflows {}", value, ty),
+        }
+    }
+}
+
+impl<T: Int + fmt::Debug> std::error::Error for Overflow<T> {}
+
+// An integer, and the first overflow of the operations that computed it.
+// After an overflow the value is the wrapped one, as in a release build.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Checked<T> {
+    value: T,
+    overflow: Option<Overflow<T>>,
+}
+
+impl<T: Int> Checked<T> {
+    pub fn new(value: T) -> Checked<T> {
+        Checked { value, overflow: None }
+    }
+
+    // The value, unless an operation overflowed on the way.
+    pub fn get(self) -> Result<T, Overflow<T>> {
+        match self.overflow {
+            None => Ok(self.value),
+            Some(overflow) => Err(overflow),
+        }
+    }
+
+    // The value, wrapped if an operation overflowed.
+    pub fn wrapped(self) -> T {
+        self.value
+    }
+
+    pub fn overflow(self) -> Option<Overflow<T>> {
+        self.overflow
+    }
+
+    pub fn overflowed(self) -> bool {
+        self.overflow.is_some()
+    }
+
+    // The result of an operation on `self`, keeping the first overflow: the
+    // lhs is evaluated before the rhs, and both before the operation.
+    fn then(self, rhs: Option<Overflow<T>>, result: Result<T, T>, overflow: Overflow<T>) -> Checked<T> {
+        match result {
+            Ok(value) => Checked { value, overflow: self.overflow.or(rhs) },
+            Err(value) => Checked { value, overflow: self.overflow.or(rhs).or(Some(overflow)) },
+        }
+    }
+}
+
+impl<T: Int> From<T> for Checked<T> {
+    fn from(value: T) -> Checked<T> {
+        Checked::new(value)
+    }
+}
+
+// The value, or why there is none.
+impl<T: Int> fmt::Display for Checked<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.overflow {
+            None => write!(f, "{}", self.value),
+            Some(overflow) => write!(f, "overflow, {}", overflow),
+        }
+    }
+}
+
+// `Checked<T> op Checked<T>` and `Checked<T> op T`, plus `op=`.
+macro_rules! binary_op {
+    ($($trait:ident $method:ident, $assign_trait:ident $assign_method:ident => $op:ident;)*) => {$(
+        impl<T: Int> $trait for Checked<T> {
+            type Output = Checked<T>;
+
+            fn $method(self, rhs: Checked<T>) -> Checked<T> {
+                let result = Sealed::$method(self.value, rhs.value);
+                self.then(rhs.overflow, result, Overflow::Binary { op: Op::$op, lhs: self.value, rhs: rhs.value })
+            }
+        }
+
+        impl<T: Int> $trait<T> for Checked<T> {
+            type Output = Checked<T>;
+
+            fn $method(self, rhs: T) -> Checked<T> {
+                self.$method(Checked::new(rhs))
+            }
+        }
+
+        impl<T: Int> $assign_trait for Checked<T> {
+            fn $assign_method(&mut self, rhs: Checked<T>) {
+                *self = $trait::$method(*self, rhs);
+            }
+        }
+
+        impl<T: Int> $assign_trait<T> for Checked<T> {
+            fn $assign_method(&mut self, rhs: T) {
+                *self = $trait::$method(*self, rhs);
+            }
+        }
+    )*};
+}
+
+binary_op! {
+    Add add, AddAssign add_assign => Add;
+    Sub sub, SubAssign sub_assign => Sub;
+    Mul mul, MulAssign mul_assign => Mul;
+    Div div, DivAssign div_assign => Div;
+    Rem rem, RemAssign rem_assign => Rem;
+}
+
+// The shift amount is a plain `u32`, as for `checked_shl`.
+macro_rules! shift_op {
+    ($($trait:ident $method:ident, $assign_trait:ident $assign_method:ident => $op:ident;)*) => {$(
+        impl<T: Int> $trait<u32> for Checked<T> {
+            type Output = Checked<T>;
+
+            fn $method(self, rhs: u32) -> Checked<T> {
+                let result = Sealed::$method(self.value, rhs);
+                self.then(None, result, Overflow::Shift { op: Op::$op, lhs: self.value, rhs })
+            }
+        }
+
+        impl<T: Int> $assign_trait<u32> for Checked<T> {
+            fn $assign_method(&mut self, rhs: u32) {
+                *self = $trait::$method(*self, rhs);
+            }
+        }
+    )*};
+}
+
+shift_op! {
+    Shl shl, ShlAssign shl_assign => Shl;
+    Shr shr, ShrAssign shr_assign => Shr;
+}
+
+impl<T: Int> Neg for Checked<T> {
+    type Output = Checked<T>;
+
+    fn neg(self) -> Checked<T> {
+        let result = Sealed::neg(self.value);
+        self.then(None, result, Overflow::Neg(self.value))
+    }
+}
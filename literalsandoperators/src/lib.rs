@@ -0,0 +1,90 @@
+// integers, floats, characters, strings booleans and the unit type `()`
+// can be expressed using literals.
+// Integers can also be expressed using hexadecimal, octal or binary notation
+// using these prefixes respectively: `0x`, `0o`, `0b`.
+// Underscores can be inserted in numeric literals to improve readability.
+// For example `1_000` is the same as `1000` and `0.000_001` is the same as `0.000001`.
+// We need to tell the compiler the type of the literals we use.
+// For now we'll user `u32` suffix to indicate that the literal is an unsigned 32-bit integer
+//  and `i32` suffix to indicate that it's a signed 32-bit integer.
+
+// The boolean operators are applied to literals to show their truth tables.
+#![allow(clippy::nonminimal_bool, clippy::overly_complex_bool_expr)]
+
+use std::io::{self, Write};
+
+use cmdline::Args;
+
+pub mod checked;
+pub mod literal;
+
+pub use checked::{Checked, Overflow};
+pub use literal::{parse_int_literal, IntLiteral, IntType, LiteralError, LiteralErrorKind};
+
+pub const USAGE: &str = "\
+usage: literalsandoperators
+
+This example takes no arguments.";
+
+pub fn run_args(args: Args, out: &mut dyn Write) -> Result<(), cmdline::Error> {
+    args.finish()?;
+    Ok(run(out)?)
+}
+
+pub fn run(out: &mut dyn Write) -> io::Result<()> {
+    // Integer addition
+    writeln!(out, "1 + 2 = {}", 1u32 + 2)?;
+
+    // Integer subtraction
+    writeln!(out, "1 - 2 = {}", 1i32 - 2)?;
+    // TODO ^ Try changing `1i32` to `1u32` to see why the type matters.
+    // error: compiler errors out with: this arithmetic operation will overflow
+
+    // Short-circuiting boolean logic
+    writeln!(out, "true AND false is {}", true && false)?;
+    writeln!(out, "true OR false is {}", true || false)?;
+    writeln!(out, "NOT true is {}", !true)?;
+
+    // Bitwise operations
+    writeln!(out, "0011 AND 0101 is {:04b}", 0b0011u32 & 0b0101)?;
+    writeln!(out, "0011 OR 0101 is {:04b}", 0b0011u32 | 0b0101)?;
+    writeln!(out, "0011 XOR 0101 is {:04b}", 0b0011u32 ^ 0b0101)?;
+    writeln!(out, "1 << 5 is {}", 1u32 << 5)?;
+    writeln!(out, "0x80 >> 2 is 0x{:x}", 0x80u32 >> 2)?;
+
+    // `1u32 << 32` or `u8::MAX + 1` panic in a debug build, and silently wrap
+    // in a release build. `Checked` carries the first overflow of a whole
+    // expression to its end instead
+    let x = Checked::new(200u8);
+    writeln!(out, "(200u8 + 100) / 2 + 1 is {}", (x + 100) / 2 + 1)?;
+    writeln!(out, "200u8 / 2 + 100 / 2 + 1 is {}", x / 2 + Checked::new(100) / 2 + 1)?;
+    writeln!(out, "-(-128i8) is {}", -Checked::new(-128i8))?;
+    writeln!(out, "(1u32 << 31) * 2 is {}", (Checked::new(1u32) << 31) * 2)?;
+    writeln!(out, "1u32 << 32 is {}", Checked::new(1u32) << 32)?;
+    let mut total = Checked::new(i32::MAX);
+    total -= 10;
+    total += 20;
+    total -= 30;
+    writeln!(out, "i32::MAX - 10 + 20 - 30 is {}, wrapped to {}", total, total.wrapped())?;
+    writeln!(out, "7 % (3 - 3) is {}", Checked::new(7) % (Checked::new(3) - 3))?;
+
+    // Use underscores to improve readablity
+    writeln!(out, "One million is written as {}", 1_000_000u32)?;
+
+    // The same literals, read at run time
+    let literals = [
+        "0b0011", "0x80", "1_000_000u32", "0xffu8", "-128i8", "0b_1111_0000", "-2147483648", "3000000000", "0b1021", "256u8",
+        "-1u8", "0x_", "_1", "12abc",
+    ];
+    for text in literals {
+        match parse_int_literal(text) {
+            Ok(literal) => match literal.ty() {
+                Some(ty) => writeln!(out, "{:<14} = {}, of type {}", text, literal, ty)?,
+                None => writeln!(out, "{:<14} = {}, out of range for the default i32, needs a suffix", text, literal)?,
+            },
+            Err(err) => writeln!(out, "{:<14} error {}", text, err)?,
+        }
+    }
+
+    Ok(())
+}
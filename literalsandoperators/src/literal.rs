@@ -0,0 +1,285 @@
+// The integer literals of the source code, parsed at run time. `"5".parse()`
+// only takes decimal digits, while the compiler also accepts:
+//
+//   0x80, 0o17, 0b0011       a radix prefix, always lowercase
+//   1_000_000, 0b_1111_0000  underscores anywhere but first
+//   255u8, 1_000_000u32      a type suffix, possibly after an underscore
+//
+// `parse_int_literal` accepts the same grammar, plus a leading `-`, and
+// returns the value with the type the literal declares. As in the compiler,
+// a suffixed literal must fit its type, `256u8` doesn't, and an unsuffixed
+// one anything up to `u128::MAX`, since its type is inferred.
+//
+// Errors give the byte offset of the faulty character, so they can be shown
+// under the input:
+//
+//   0b1021
+//      ^ invalid digit `2` for a base 2 literal
+
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IntType {
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+    Isize,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    Usize,
+}
+
+impl IntType {
+    pub const ALL: [IntType; 12] = [
+        IntType::I8,
+        IntType::I16,
+        IntType::I32,
+        IntType::I64,
+        IntType::I128,
+        IntType::Isize,
+        IntType::U8,
+        IntType::U16,
+        IntType::U32,
+        IntType::U64,
+        IntType::U128,
+        IntType::Usize,
+    ];
+
+    // The suffix naming the type, which is also its name.
+    pub fn name(self) -> &'static str {
+        match self {
+            IntType::I8 => "i8",
+            IntType::I16 => "i16",
+            IntType::I32 => "i32",
+            IntType::I64 => "i64",
+            IntType::I128 => "i128",
+            IntType::Isize => "isize",
+            IntType::U8 => "u8",
+            IntType::U16 => "u16",
+            IntType::U32 => "u32",
+            IntType::U64 => "u64",
+            IntType::U128 => "u128",
+            IntType::Usize => "usize",
+        }
+    }
+
+    pub fn from_suffix(suffix: &str) -> Option<IntType> {
+        IntType::ALL.into_iter().find(|ty| ty.name() == suffix)
+    }
+
+    // `isize` and `usize` are as wide as a pointer of the target.
+    pub fn bits(self) -> u32 {
+        match self {
+            IntType::I8 | IntType::U8 => 8,
+            IntType::I16 | IntType::U16 => 16,
+            IntType::I32 | IntType::U32 => 32,
+            IntType::I64 | IntType::U64 => 64,
+            IntType::I128 | IntType::U128 => 128,
+            IntType::Isize | IntType::Usize => usize::BITS,
+        }
+    }
+
+    pub fn is_signed(self) -> bool {
+        matches!(self, IntType::I8 | IntType::I16 | IntType::I32 | IntType::I64 | IntType::I128 | IntType::Isize)
+    }
+
+    // The largest magnitude the type holds with the given sign: 127 and 128
+    // for `i8`, since `-128i8` is fine.
+    fn max_magnitude(self, negative: bool) -> u128 {
+        let bits = self.bits();
+        match (self.is_signed(), negative) {
+            (false, _) => u128::MAX >> (128 - bits),
+            (true, false) => (1u128 << (bits - 1)) - 1,
+            (true, true) => 1u128 << (bits - 1),
+        }
+    }
+}
+
+impl fmt::Display for IntType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IntLiteral {
+    pub negative: bool,
+    // The absolute value: `u128` holds that of every integer type.
+    pub magnitude: u128,
+    pub radix: u32,
+    // `None` without a suffix, when the compiler infers the type.
+    pub suffix: Option<IntType>,
+}
+
+impl IntLiteral {
+    // The type of the literal: its suffix, or `i32` when nothing else says
+    // otherwise. `None` for an unsuffixed value out of the range of `i32`,
+    // such as `3000000000`: the compiler rejects it unless the context gives
+    // it a wider type.
+    pub fn ty(&self) -> Option<IntType> {
+        self.suffix.or(Some(IntType::I32).filter(|&ty| self.fits(ty)))
+    }
+
+    // The value is one of `ty`.
+    pub fn fits(&self, ty: IntType) -> bool {
+        (ty.is_signed() || !self.negative || self.magnitude == 0) && self.magnitude <= ty.max_magnitude(self.negative)
+    }
+
+    // The value, if it fits an `i128`: all but the largest `u128`s do.
+    pub fn to_i128(&self) -> Option<i128> {
+        let magnitude = i128::try_from(self.magnitude).ok();
+        if self.negative {
+            // -2^127 is the one negative value whose magnitude is no `i128`.
+            magnitude.map(|m| -m).or((self.magnitude == 1 << 127).then_some(i128::MIN))
+        } else {
+            magnitude
+        }
+    }
+}
+
+// The value in decimal, followed by its suffix: `0xffu8` is `255u8`.
+impl fmt::Display for IntLiteral {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.negative && self.magnitude != 0 {
+            write!(f, "-")?;
+        }
+        write!(f, "{}", self.magnitude)?;
+        match self.suffix {
+            Some(ty) => write!(f, "{}", ty),
+            None => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LiteralErrorKind {
+    // The input ended, or something else came, where a digit was needed.
+    ExpectedDigit,
+    // An underscore before the first digit: `_1` is an identifier.
+    LeadingUnderscore,
+    InvalidDigit { digit: char, radix: u32 },
+    // Whatever follows the digits is not an integer type.
+    InvalidSuffix(String),
+    // The value doesn't fit the type of the suffix.
+    OutOfRange(IntType),
+    // No suffix, and the value doesn't even fit a `u128`.
+    TooLarge,
+    // `-1u8`: an unsigned value can't be negated.
+    NegativeUnsigned(IntType),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LiteralError {
+    // Byte offset of the character the error is about.
+    pub offset: usize,
+    pub kind: LiteralErrorKind,
+}
+
+impl fmt::Display for LiteralErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LiteralErrorKind::ExpectedDigit => write!(f, "expected a digit"),
+            LiteralErrorKind::LeadingUnderscore => write!(f, "a literal cannot start with `_`"),
+            LiteralErrorKind::InvalidDigit { digit, radix } => {
+                write!(f, "invalid digit `{}` for a base {} literal", digit, radix)
+            }
+            LiteralErrorKind::InvalidSuffix(suffix) => write!(f, "invalid suffix `{}` for an integer literal", suffix),
+            LiteralErrorKind::OutOfRange(ty) => write!(f, "literal out of range for `{}`", ty),
+            LiteralErrorKind::TooLarge => write!(f, "integer literal is too large"),
+            LiteralErrorKind::NegativeUnsigned(ty) => write!(f, "cannot negate an unsigned `{}` literal", ty),
+        }
+    }
+}
+
+impl fmt::Display for LiteralError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "at byte {}: {}", self.offset, self.kind)
+    }
+}
+
+impl std::error::Error for LiteralError {}
+
+pub fn parse_int_literal(text: &str) -> Result<IntLiteral, LiteralError> {
+    let error = |offset, kind| Err(LiteralError { offset, kind });
+    let bytes = text.as_bytes();
+
+    let negative = text.starts_with('-');
+    let start = usize::from(negative);
+    match bytes.get(start) {
+        Some(b'0'..=b'9') => {}
+        Some(b'_') => return error(start, LiteralErrorKind::LeadingUnderscore),
+        _ => return error(start, LiteralErrorKind::ExpectedDigit),
+    }
+
+    let (radix, digits_start) = match text[start..].get(..2) {
+        Some("0x") => (16, start + 2),
+        Some("0o") => (8, start + 2),
+        Some("0b") => (2, start + 2),
+        _ => (10, start),
+    };
+
+    // The digits run up to the suffix. Like the compiler, take every decimal
+    // digit whatever the radix, to report `0b102` as a bad digit rather than
+    // a bad suffix.
+    let is_digit = |b: u8| b == b'_' || b.is_ascii_digit() || (radix == 16 && b.is_ascii_hexdigit());
+    let digits_end = bytes[digits_start..].iter().position(|&b| !is_digit(b)).map_or(text.len(), |i| digits_start + i);
+
+    let mut any_digit = false;
+    for (offset, &b) in bytes.iter().enumerate().take(digits_end).skip(digits_start) {
+        if b == b'_' {
+            continue;
+        }
+        let digit = char::from(b);
+        if digit.to_digit(radix).is_none() {
+            return error(offset, LiteralErrorKind::InvalidDigit { digit, radix });
+        }
+        any_digit = true;
+    }
+    if !any_digit {
+        return error(digits_end, LiteralErrorKind::ExpectedDigit);
+    }
+
+    let suffix = match &text[digits_end..] {
+        "" => None,
+        suffix => match IntType::from_suffix(suffix) {
+            Some(ty) => Some(ty),
+            None => return error(digits_end, LiteralErrorKind::InvalidSuffix(suffix.to_string())),
+        },
+    };
+    if let Some(ty) = suffix.filter(|ty| negative && !ty.is_signed()) {
+        return error(0, LiteralErrorKind::NegativeUnsigned(ty));
+    }
+
+    // Accumulate the value, stopping at the first digit taking it past what
+    // the type can hold.
+    let max = suffix.map_or(u128::MAX, |ty| ty.max_magnitude(negative));
+    let mut magnitude: u128 = 0;
+    for (offset, &b) in bytes.iter().enumerate().take(digits_end).skip(digits_start) {
+        let Some(digit) = char::from(b).to_digit(radix) else {
+            continue;
+        };
+        magnitude = match magnitude.checked_mul(radix.into()).and_then(|m| m.checked_add(digit.into())) {
+            Some(m) if m <= max => m,
+            _ => {
+                let kind = suffix.map_or(LiteralErrorKind::TooLarge, LiteralErrorKind::OutOfRange);
+                return error(offset, kind);
+            }
+        };
+    }
+
+    Ok(IntLiteral { negative, magnitude, radix, suffix })
+}
+
+impl FromStr for IntLiteral {
+    type Err = LiteralError;
+
+    fn from_str(s: &str) -> Result<IntLiteral, LiteralError> {
+        parse_int_literal(s)
+    }
+}
@@ -0,0 +1,62 @@
+// Every example in the workspace is described by a type implementing `Example`.
+// The launcher never needs to know what an example does: it only asks for its
+// name, a short summary, its source and a way to run it.
+
+use std::io::{self, Write};
+
+use cmdline::Args;
+
+pub trait Example {
+    // The name used on the command line, the same as the crate directory.
+    fn name(&self) -> &'static str;
+
+    // A one-line description shown by `rbe list`.
+    fn summary(&self) -> &'static str;
+
+    // The full source of the example, explanatory comments included.
+    fn source(&self) -> &'static str;
+
+    // The usage message listing the inputs the example accepts.
+    fn usage(&self) -> &'static str;
+
+    // Run the example with its default inputs, writing everything it prints to `out`.
+    fn run(&self, out: &mut dyn Write) -> io::Result<()>;
+
+    // Run the example with inputs taken from the command line.
+    fn run_args(&self, args: Args, out: &mut dyn Write) -> Result<(), cmdline::Error>;
+}
+
+// The registry owns every registered example and keeps them in the order
+// they were registered, which is the order `rbe list` and `rbe run --all` use.
+#[derive(Default)]
+pub struct Registry {
+    examples: Vec<Box<dyn Example>>,
+}
+
+impl Registry {
+    pub fn new() -> Registry {
+        Registry::default()
+    }
+
+    // Add an example. Registering two examples with the same name is a bug
+    // in the launcher, not something a user can trigger, so it panics.
+    pub fn register(&mut self, example: Box<dyn Example>) {
+        assert!(
+            self.get(example.name()).is_none(),
+            "example `{}` registered twice",
+            example.name()
+        );
+        self.examples.push(example);
+    }
+
+    pub fn get(&self, name: &str) -> Option<&dyn Example> {
+        self.examples
+            .iter()
+            .find(|example| example.name() == name)
+            .map(|example| example.as_ref())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &dyn Example> {
+        self.examples.iter().map(|example| example.as_ref())
+    }
+}
@@ -0,0 +1,98 @@
+// Each example crate of the workspace gets a unit struct implementing `Example`.
+// The examples are libraries, so running one is a plain function call.
+
+use std::io::{self, Write};
+
+use cmdline::Args;
+
+use crate::example::{Example, Registry};
+
+// Declare a unit struct for an example crate and implement `Example` for it.
+// The source is embedded at compile time so `rbe show` works anywhere: the
+// `lib.rs` of the crate, then each module listed after the summary, under a
+// line naming its file.
+macro_rules! example {
+    ($ty:ident, $krate:ident, $summary:literal $(, [$($module:ident),* $(,)?])?) => {
+        pub struct $ty;
+
+        impl Example for $ty {
+            fn name(&self) -> &'static str {
+                stringify!($krate)
+            }
+
+            fn summary(&self) -> &'static str {
+                $summary
+            }
+
+            fn source(&self) -> &'static str {
+                concat!(
+                    include_str!(concat!("../../", stringify!($krate), "/src/lib.rs")),
+                    $($(
+                        "\n// src/", stringify!($module), ".rs\n\n",
+                        include_str!(concat!("../../", stringify!($krate), "/src/", stringify!($module), ".rs")),
+                    )*)?
+                )
+            }
+
+            fn usage(&self) -> &'static str {
+                $krate::USAGE
+            }
+
+            fn run(&self, out: &mut dyn Write) -> io::Result<()> {
+                $krate::run(out)
+            }
+
+            fn run_args(&self, args: Args, out: &mut dyn Write) -> Result<(), cmdline::Error> {
+                $krate::run_args(args, out)
+            }
+        }
+    };
+}
+
+example!(FormattedPrint, formattedprint, "Formatted print with `println!` and friends");
+example!(Debug, debug, "Deriving `fmt::Debug` and pretty printing");
+example!(Display, display, "Implementing `fmt::Display` by hand", [complex]);
+example!(TestCaseList, testcaselist, "Chaining `write!` calls with the `?` operator");
+example!(Formatting, formatting, "Format specifiers and the formatting traits");
+example!(Primitives, primitives, "Scalar and compound primitive types");
+example!(LiteralsAndOperators, literalsandoperators, "Literals, arithmetic, boolean and bitwise operators", [checked, literal]);
+example!(Tuples, tuples, "Tuples as values, arguments and return types");
+example!(ArraysAndSlices, arraysandslices, "Stack-allocated arrays and borrowed slices", [arrayvec, grid, report, ringbuffer]);
+example!(Structures, structures, "Tuple structs, C-like structs and unit structs");
+example!(EnumAndTypeAlias, enumandtypealias, "Enums and type aliases");
+example!(EnumUse, enumuse, "Bringing enum variants into scope and C-like enums");
+example!(EnumLinkedList, enumlinkedlist, "A linked list built out of an enum");
+example!(Constants, constants, "`const` and `static` globals", [bands, config]);
+example!(VariableBindings, variablebindings, "Bindings, mutability, scope, shadowing and freezing");
+example!(Types, types, "Casting, literal suffixes, inference and aliasing", [cast, charinfo, rounding, units]);
+example!(Conversion, conversion, "`From`, `Into`, `TryFrom` and conversions to and from strings", [number, validated]);
+example!(Expressions, expressions, "Statements, expressions and blocks");
+example!(FlowOfControl, flowofcontrol, "`if`, loops, `match`, destructuring and `if let`");
+example!(Functions, functions, "Functions, associated functions and methods");
+example!(Fractals, fractals, "The Mandelbrot and Julia sets, rendered with `display::Complex`");
+
+// Register every example, in the order the book introduces them.
+pub fn register_all(registry: &mut Registry) {
+    registry.register(Box::new(FormattedPrint));
+    registry.register(Box::new(Debug));
+    registry.register(Box::new(Display));
+    registry.register(Box::new(TestCaseList));
+    registry.register(Box::new(Formatting));
+    registry.register(Box::new(Primitives));
+    registry.register(Box::new(LiteralsAndOperators));
+    registry.register(Box::new(Tuples));
+    registry.register(Box::new(ArraysAndSlices));
+    registry.register(Box::new(Structures));
+    registry.register(Box::new(EnumAndTypeAlias));
+    registry.register(Box::new(EnumUse));
+    registry.register(Box::new(EnumLinkedList));
+    registry.register(Box::new(Constants));
+    registry.register(Box::new(VariableBindings));
+    registry.register(Box::new(Types));
+    registry.register(Box::new(Conversion));
+    registry.register(Box::new(Expressions));
+    registry.register(Box::new(FlowOfControl));
+    registry.register(Box::new(Functions));
+    registry.register(Box::new(Fractals));
+}
+
@@ -0,0 +1,283 @@
+// How the types of the examples are laid out in memory.
+//
+// `layout_report!(Type)` describes a type: its size and alignment, and
+// whether `Option<Type>` is free because `Type` has a "niche", a bit pattern
+// no valid value uses, in which `None` can be stored. A reference or a `Box`
+// is never null, so `Option<Box<List>>` is still a single pointer.
+//
+// `layout_report!(Type { field, ... })` also gives the offset and size of
+// each field, and from them the padding the compiler inserted to keep every
+// field aligned. Every field must be listed, or it would be counted as
+// padding: leaving one out doesn't compile. Tuple struct fields are listed by
+// position: `layout_report!(Pair { 0, 1 })`.
+//
+// Sizes depend on the target, so the pointer width is reported as well: a
+// slice `&[T]` is two words, 16 bytes on 64 bit targets.
+
+use std::fmt;
+use std::io::{self, Write};
+use std::mem;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Layout {
+    pub name: &'static str,
+    pub size: usize,
+    pub align: usize,
+    // The size of `Option<Type>`.
+    pub option_size: usize,
+    // `None` when the fields were not listed, as for enums.
+    pub fields: Option<Vec<Field>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Field {
+    pub name: &'static str,
+    pub offset: usize,
+    pub size: usize,
+}
+
+#[macro_export]
+macro_rules! layout_report {
+    ($ty:ty { $($field:tt),* $(,)? }) => {{
+        // A struct pattern without `..` must name every field. A type can't
+        // start a pattern, an alias of it can.
+        type Listed = $ty;
+        let _ = |value: &Listed| {
+            let Listed { $($field: _),* } = value;
+        };
+        $crate::layout::Layout {
+            fields: Some(vec![$(
+                $crate::layout::Field {
+                    name: stringify!($field),
+                    offset: ::std::mem::offset_of!($ty, $field),
+                    size: $crate::layout::field_size(|value: &$ty| &value.$field),
+                }
+            ),*]),
+            ..$crate::layout_report!($ty)
+        }
+    }};
+    ($ty:ty) => {
+        $crate::layout::Layout {
+            name: stringify!($ty),
+            size: ::std::mem::size_of::<$ty>(),
+            align: ::std::mem::align_of::<$ty>(),
+            option_size: ::std::mem::size_of::<::std::option::Option<$ty>>(),
+            fields: None,
+        }
+    };
+}
+
+// The size of a field, whose type the macro can't name: the closure
+// `|value: &Type| &value.field` names it for us.
+pub fn field_size<T, F>(_field: impl Fn(&T) -> &F) -> usize {
+    mem::size_of::<F>()
+}
+
+// The pointer width of the target, in bits.
+pub fn pointer_width() -> usize {
+    mem::size_of::<usize>() * 8
+}
+
+impl Layout {
+    // Bytes not used by any field, `None` if the fields are unknown.
+    pub fn padding(&self) -> Option<usize> {
+        let fields = self.fields.as_ref()?;
+        Some(self.size - fields.iter().map(|field| field.size).sum::<usize>())
+    }
+
+    // `Option<Type>` takes no more room than `Type` itself.
+    pub fn has_niche(&self) -> bool {
+        self.option_size == self.size
+    }
+}
+
+// The detailed report of one type: each field with the bytes it occupies,
+// and the padding holes between them.
+impl fmt::Display for Layout {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{}: size {}, align {}", self.name, self.size, self.align)?;
+
+        if let Some(fields) = &self.fields {
+            let mut fields: Vec<&Field> = fields.iter().collect();
+            fields.sort_by_key(|field| field.offset);
+
+            // Everything between the end of one field and the start of the
+            // next one, or the end of the type, is padding.
+            let mut end = 0;
+            for field in fields {
+                if field.offset > end {
+                    writeln!(f, "    {:>3}..{:<3}  padding ({})", end, field.offset, bytes(field.offset - end))?;
+                }
+                let field_end = field.offset + field.size;
+                writeln!(f, "    {:>3}..{:<3}  {} ({})", field.offset, field_end, field.name, bytes(field.size))?;
+                end = end.max(field_end);
+            }
+            if self.size > end {
+                writeln!(f, "    {:>3}..{:<3}  padding ({})", end, self.size, bytes(self.size - end))?;
+            }
+        }
+
+        if self.has_niche() {
+            write!(f, "Option<{}>: size {}, `None` fits in a niche", self.name, self.option_size)
+        } else {
+            write!(f, "Option<{}>: size {}, no niche", self.name, self.option_size)
+        }
+    }
+}
+
+fn bytes(n: usize) -> String {
+    match n {
+        1 => "1 byte".to_string(),
+        n => format!("{} bytes", n),
+    }
+}
+
+// One line per type, with the pointer width of the target first.
+pub fn write_table(out: &mut dyn Write, layouts: &[Layout]) -> io::Result<()> {
+    writeln!(out, "pointer width: {} bits", pointer_width())?;
+    writeln!(out)?;
+
+    let width = layouts.iter().map(|layout| layout.name.len()).max().unwrap_or(0);
+    writeln!(
+        out,
+        "{:width$}  {:>4}  {:>5}  {:>7}  {:>11}  niche",
+        "type",
+        "size",
+        "align",
+        "padding",
+        "Option size",
+        width = width
+    )?;
+
+    for layout in layouts {
+        let padding = match layout.padding() {
+            Some(padding) => padding.to_string(),
+            None => "-".to_string(),
+        };
+        writeln!(
+            out,
+            "{:width$}  {:>4}  {:>5}  {:>7}  {:>11}  {}",
+            layout.name,
+            layout.size,
+            layout.align,
+            padding,
+            layout.option_size,
+            if layout.has_niche() { "yes" } else { "no" },
+            width = width
+        )?;
+    }
+    Ok(())
+}
+
+// The structs and enums of every example and of `codec`, plus a few
+// standard types for comparison. Fields can only be listed when they are
+// public, and a generic type is shown for one instance of it.
+pub fn all() -> Vec<Layout> {
+    vec![
+        layout_report!(debug::UnPrintable { 0 }),
+        layout_report!(debug::DebugPrintable { 0 }),
+        layout_report!(debug::Structure { 0 }),
+        layout_report!(debug::Deep { 0 }),
+        layout_report!(display::Structure { 0 }),
+        layout_report!(display::MinMax { 0, 1 }),
+        layout_report!(display::Point2D { x, y }),
+        layout_report!(display::Complex { real, imag }),
+        layout_report!(display::ParseComplexErrorKind),
+        layout_report!(display::ParseComplexError { offset, kind }),
+        layout_report!(display::ParseError { input, reason }),
+        layout_report!(testcaselist::List { 0 }),
+        layout_report!(formatting::City { name, lat, lon }),
+        layout_report!(formatting::Color { red, green, blue }),
+        layout_report!(formatting::ParseError { input, reason }),
+        layout_report!(literalsandoperators::IntType),
+        layout_report!(literalsandoperators::IntLiteral { negative, magnitude, radix, suffix }),
+        layout_report!(literalsandoperators::LiteralErrorKind),
+        layout_report!(literalsandoperators::LiteralError { offset, kind }),
+        layout_report!(literalsandoperators::checked::Op),
+        layout_report!(literalsandoperators::Overflow<i32>),
+        layout_report!(literalsandoperators::Checked<i32>),
+        layout_report!(tuples::Matrix { 0, 1, 2, 3 }),
+        layout_report!(arraysandslices::SliceReport<'static, i32> { len, first, last, min, max, stats }),
+        layout_report!(arraysandslices::Stats { sum, mean, median, variance }),
+        layout_report!(arraysandslices::ArrayVec<i32, 8>),
+        layout_report!(arraysandslices::CapacityError<i32> { 0 }),
+        layout_report!(arraysandslices::RingBuffer<i32, 8>),
+        layout_report!(arraysandslices::Overflow),
+        layout_report!(arraysandslices::Grid<u8>),
+        layout_report!(arraysandslices::GridView<'static, u8>),
+        layout_report!(arraysandslices::grid::GridViewMut<'static, u8>),
+        layout_report!(arraysandslices::grid::ShapeError { width, height, len }),
+        layout_report!(arraysandslices::Connectivity),
+        layout_report!(structures::Person { name, age }),
+        layout_report!(structures::Unit {}),
+        layout_report!(structures::Pair { 0, 1 }),
+        layout_report!(structures::Point { x, y }),
+        layout_report!(structures::Rectangle { p1, p2 }),
+        layout_report!(enumandtypealias::WebEvent),
+        layout_report!(enumandtypealias::Operations),
+        layout_report!(enumuse::Status),
+        layout_report!(enumuse::Work),
+        layout_report!(enumuse::Number),
+        layout_report!(enumuse::Color),
+        layout_report!(enumlinkedlist::List),
+        layout_report!(Box<enumlinkedlist::List>),
+        layout_report!(Option<Box<enumlinkedlist::List>>),
+        layout_report!(constants::Source),
+        layout_report!(constants::Setting<i32> { value, source }),
+        layout_report!(constants::ConfigError),
+        layout_report!(constants::Config { threshold, language }),
+        layout_report!(constants::Bound<i32>),
+        layout_report!(constants::Band<i32> { name, lower, upper }),
+        layout_report!(constants::BandError),
+        layout_report!(constants::Classifier<i32>),
+        layout_report!(constants::Reading<'static, i32> { value, band, transition }),
+        layout_report!(constants::Transition<'static, i32> { from, to }),
+        layout_report!(types::units::Meter),
+        layout_report!(types::units::Per<types::units::Meter, types::units::Second>),
+        layout_report!(types::units::Quantity<types::units::Meter>),
+        layout_report!(types::cast::Loss),
+        layout_report!(types::cast::CastError { loss, value, from, to }),
+        layout_report!(types::cast::AnyPrimitive),
+        layout_report!(types::cast::ParsePrimitiveError { 0 }),
+        layout_report!(types::rounding::Rounding),
+        layout_report!(types::rounding::FloatToIntError),
+        layout_report!(types::charinfo::AsciiClass),
+        layout_report!(types::charinfo::Class),
+        layout_report!(types::charinfo::CharInfo { ch, utf8, utf16, class }),
+        layout_report!(types::charinfo::CharError),
+        layout_report!(types::charinfo::ByteRole),
+        layout_report!(flowofcontrol::Color),
+        layout_report!(flowofcontrol::Temperature),
+        layout_report!(flowofcontrol::Boh),
+        layout_report!(functions::Point { x, y }),
+        layout_report!(functions::Rectangle { p1, p2 }),
+        layout_report!(functions::Pair { 0, 1 }),
+        layout_report!(fractals::Set),
+        layout_report!(fractals::Fractal),
+        layout_report!(fractals::Orbit { iterations, z }),
+        layout_report!(fractals::Viewport { center, span, columns, rows, aspect }),
+        layout_report!(conversion::Number { value }),
+        layout_report!(conversion::ParseNumberError { input, error }),
+        layout_report!(conversion::EvenNumber),
+        layout_report!(conversion::ValidationError),
+        layout_report!(conversion::Even),
+        layout_report!(conversion::Odd),
+        layout_report!(conversion::Positive),
+        layout_report!(conversion::NonZero),
+        layout_report!(conversion::Prime),
+        layout_report!(conversion::Bounded<1, 12>),
+        layout_report!(conversion::Percentage),
+        layout_report!(conversion::Circle { radius }),
+        layout_report!(conversion::ParseCircleError),
+        layout_report!(codec::Format),
+        layout_report!(codec::DecodeErrorKind),
+        layout_report!(codec::DecodeError { offset, kind }),
+        layout_report!(codec::Encoder),
+        layout_report!(codec::Decoder<'static>),
+        layout_report!(&[i32]),
+        layout_report!(&str),
+        layout_report!(String),
+        layout_report!(char),
+        layout_report!(bool),
+    ]
+}
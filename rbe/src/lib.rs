@@ -0,0 +1,16 @@
+// The pieces of the `rbe` launcher that are useful outside of it:
+// the `Example` trait, the registry, every example registered in it, and
+// the memory layout of the examples' types.
+
+pub mod example;
+pub mod examples;
+pub mod layout;
+
+pub use example::{Example, Registry};
+
+// A registry holding every example of the workspace.
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+    examples::register_all(&mut registry);
+    registry
+}
@@ -0,0 +1,195 @@
+// `rbe` is the single entry point to every example of the workspace.
+//
+//   rbe list           list every example with a short summary
+//   rbe run <name>     run one example, any further argument is handed to it
+//   rbe run --all      run every example with its default inputs, one after the other
+//   rbe show <name>    print the source of an example, comments included
+//   rbe layout [type]  print the memory layout of the examples' types
+//   rbe charinfo ...   print the code points and encodings of some text
+
+use std::env;
+use std::io::{self, Write};
+use std::process::ExitCode;
+
+use cmdline::Args;
+use literalsandoperators::parse_int_literal;
+use rbe::layout::{self, Layout};
+use rbe::{Example, Registry};
+use types::charinfo;
+
+const USAGE: &str = "\
+usage: rbe <command>
+
+commands:
+    list            list every example
+    run <name> ...  run one example, `rbe run <name> --help` lists its inputs
+    run --all       run every example
+    show <name>     print the source of an example
+    layout          print the size, alignment and padding of every type
+    layout <type>   print the fields of the types whose name contains <type>
+    charinfo <text> print the code point, UTF-8 and UTF-16 of every char of <text>
+    charinfo --u8 <n>
+                    print the char `<n> as char` and what byte <n> is in UTF-8
+    charinfo --u32 <n>
+                    print the char with code point <n>, if there is one
+
+<n> is any integer literal: 233, 0xE9, 0b1110_1001...";
+
+fn main() -> ExitCode {
+    let registry = rbe::registry();
+
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+    match args.as_slice() {
+        ["list"] => {
+            list(&registry);
+            ExitCode::SUCCESS
+        }
+        ["run", "--all"] => run_all(&registry),
+        ["run", name, args @ ..] => match registry.get(name) {
+            Some(example) => run_args(example, args),
+            None => unknown(name),
+        },
+        ["show", name] => with_example(&registry, name, show),
+        ["layout"] => layout_table(),
+        ["layout", name] => layout(name),
+        ["charinfo", "--u8", value] => charinfo(value, u8::BITS),
+        ["charinfo", "--u32", value] => charinfo(value, u32::BITS),
+        ["charinfo", text] => {
+            let mut out = io::stdout().lock();
+            report(charinfo::write_text_report(&mut out, text))
+        }
+        ["help" | "--help" | "-h"] => {
+            println!("{}", USAGE);
+            ExitCode::SUCCESS
+        }
+        _ => {
+            eprintln!("{}", USAGE);
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn list(registry: &Registry) {
+    // Align the summaries on the longest name.
+    let width = registry.iter().map(|e| e.name().len()).max().unwrap_or(0);
+
+    for example in registry.iter() {
+        println!("{:width$}  {}", example.name(), example.summary(), width = width);
+    }
+}
+
+// Look up an example by name and hand it to `action`, or explain what went wrong.
+fn with_example(registry: &Registry, name: &str, action: fn(&dyn Example) -> ExitCode) -> ExitCode {
+    match registry.get(name) {
+        Some(example) => action(example),
+        None => unknown(name),
+    }
+}
+
+fn unknown(name: &str) -> ExitCode {
+    eprintln!("rbe: no example named `{}`, try `rbe list`", name);
+    ExitCode::FAILURE
+}
+
+// Run an example with inputs from the command line, exactly as its own binary would.
+fn run_args(example: &dyn Example, args: &[&str]) -> ExitCode {
+    let mut out = io::stdout().lock();
+    let result = Args::parse(args.iter().copied())
+        .and_then(|args| example.run_args(args, &mut out))
+        .and_then(|()| out.flush().map_err(cmdline::Error::from));
+
+    cmdline::report(example.usage(), result)
+}
+
+fn run(example: &dyn Example) -> ExitCode {
+    let mut out = io::stdout().lock();
+
+    match example.run(&mut out).and_then(|()| out.flush()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("rbe: {}: {}", example.name(), err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+// Keep going when an example fails, so one broken example doesn't hide the
+// others, but report the failure in the exit code.
+fn run_all(registry: &Registry) -> ExitCode {
+    let mut code = ExitCode::SUCCESS;
+
+    for example in registry.iter() {
+        println!("==> {}", example.name());
+        if run(example) != ExitCode::SUCCESS {
+            code = ExitCode::FAILURE;
+        }
+        println!();
+    }
+
+    code
+}
+
+fn show(example: &dyn Example) -> ExitCode {
+    println!("// {}: {}", example.name(), example.summary());
+    println!();
+    print!("{}", example.source());
+    ExitCode::SUCCESS
+}
+
+fn report(result: io::Result<()>) -> ExitCode {
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("rbe: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn layout_table() -> ExitCode {
+    let mut out = io::stdout().lock();
+    report(layout::write_table(&mut out, &layout::all()))
+}
+
+fn layout(name: &str) -> ExitCode {
+    let layouts: Vec<Layout> = layout::all()
+        .into_iter()
+        .filter(|layout| layout.name.contains(name))
+        .collect();
+
+    if layouts.is_empty() {
+        eprintln!("rbe: no type matching `{}`, try `rbe layout`", name);
+        return ExitCode::FAILURE;
+    }
+
+    println!("pointer width: {} bits", layout::pointer_width());
+    for layout in layouts {
+        println!();
+        println!("{}", layout);
+    }
+    ExitCode::SUCCESS
+}
+
+// Report on `value`, an integer literal of at most `bits` bits.
+fn charinfo(value: &str, bits: u32) -> ExitCode {
+    let number = match parse_int_literal(value) {
+        Ok(literal) => literal.to_i128().filter(|&n| n >= 0 && n >> bits == 0),
+        Err(err) => {
+            eprintln!("rbe: invalid number `{}`, {}", value, err);
+            return ExitCode::from(2);
+        }
+    };
+    let Some(number) = number else {
+        eprintln!("rbe: `{}` is out of the range of a u{}", value, bits);
+        return ExitCode::from(2);
+    };
+
+    let mut out = io::stdout().lock();
+    if bits == u8::BITS {
+        report(charinfo::write_u8_report(&mut out, number as u8))
+    } else {
+        report(charinfo::write_u32_report(&mut out, number as u32))
+    }
+}
@@ -0,0 +1,123 @@
+// Tests of `arraysandslices::ArrayVec`: pushing, popping, inserting and
+// removing at both ends and past the capacity, and, since the elements live
+// in `MaybeUninit` slots, that every element pushed is dropped exactly once.
+//
+//   cargo test -p rbe --test arrayvec
+
+use std::rc::Rc;
+
+use arraysandslices::{ArrayVec, CapacityError};
+
+#[test]
+fn push_and_pop() {
+    let mut vec: ArrayVec<i32, 3> = ArrayVec::new();
+    assert_eq!(vec.pop(), None);
+    assert_eq!((vec.len(), vec.remaining_capacity()), (0, 3));
+
+    for x in 1..=3 {
+        vec.push(x).unwrap();
+    }
+    assert!(vec.is_full());
+    // The element that didn't fit is handed back, and the vec is unchanged.
+    assert_eq!(vec.push(4), Err(CapacityError(4)));
+    assert_eq!(vec.as_slice(), [1, 2, 3]);
+
+    assert_eq!(vec.pop(), Some(3));
+    vec.push(5).unwrap();
+    assert_eq!(vec.as_slice(), [1, 2, 5]);
+    assert_eq!((vec.pop(), vec.pop(), vec.pop(), vec.pop()), (Some(5), Some(2), Some(1), None));
+
+    // No capacity at all: every push fails.
+    let mut empty: ArrayVec<i32, 0> = ArrayVec::new();
+    assert_eq!(empty.push(1), Err(CapacityError(1)));
+    assert_eq!(empty.pop(), None);
+}
+
+#[test]
+fn insert_and_remove_at_both_ends() {
+    let mut vec: ArrayVec<char, 5> = ArrayVec::new();
+    vec.insert(0, 'c').unwrap();
+    vec.insert(0, 'a').unwrap();
+    vec.insert(2, 'd').unwrap();
+    vec.insert(1, 'b').unwrap();
+    vec.insert(4, 'e').unwrap();
+    assert_eq!(vec.as_slice(), ['a', 'b', 'c', 'd', 'e']);
+    assert_eq!(vec.insert(0, 'z'), Err(CapacityError('z')));
+    assert_eq!(vec.insert(5, 'z'), Err(CapacityError('z')));
+
+    assert_eq!(vec.remove(4), 'e');
+    assert_eq!(vec.remove(0), 'a');
+    assert_eq!(vec.as_slice(), ['b', 'c', 'd']);
+    assert_eq!(vec.remove(1), 'c');
+    vec.push('f').unwrap();
+    vec.insert(1, 'g').unwrap();
+    assert_eq!(vec.as_slice(), ['b', 'g', 'd', 'f']);
+
+    // What's left is still a slice, through `Deref`.
+    vec.sort();
+    assert_eq!(&vec[1..], ['d', 'f', 'g']);
+    assert_eq!(vec.iter().rev().collect::<String>(), "gfdb");
+    assert_eq!(vec.clone().into_iter().collect::<String>(), "bdfg");
+}
+
+#[test]
+#[should_panic(expected = "insertion index 2 is out of bounds (len 1)")]
+fn insert_past_the_end_panics() {
+    let mut vec: ArrayVec<i32, 4> = ArrayVec::new();
+    vec.push(1).unwrap();
+    let _ = vec.insert(2, 2);
+}
+
+#[test]
+#[should_panic(expected = "removal index 1 is out of bounds (len 1)")]
+fn remove_past_the_end_panics() {
+    let mut vec: ArrayVec<i32, 4> = ArrayVec::new();
+    vec.push(1).unwrap();
+    vec.remove(1);
+}
+
+// Every element is a clone of `token`, so the strong count of `token` is one
+// more than the number of elements not dropped yet: an element dropped twice
+// would bring it below that, one never dropped would keep it above.
+#[test]
+fn drops_every_element_once() {
+    let token = Rc::new(());
+    let live = || Rc::strong_count(&token) - 1;
+
+    let mut vec: ArrayVec<Rc<()>, 6> = ArrayVec::new();
+    for _ in 0..6 {
+        vec.push(Rc::clone(&token)).unwrap();
+    }
+    // The one that didn't fit comes back, to be dropped by the caller.
+    drop(vec.push(Rc::clone(&token)).unwrap_err().into_inner());
+    assert_eq!(live(), 6);
+
+    drop(vec.pop());
+    drop(vec.remove(0));
+    vec.insert(1, Rc::clone(&token)).unwrap();
+    assert_eq!((vec.len(), live()), (5, 5));
+
+    vec.truncate(7);
+    assert_eq!(live(), 5);
+    vec.truncate(2);
+    assert_eq!((vec.len(), live()), (2, 2));
+    vec.clear();
+    assert_eq!((vec.len(), live()), (0, 0));
+
+    // A partly filled vec drops what it holds, and nothing else.
+    for _ in 0..3 {
+        vec.push(Rc::clone(&token)).unwrap();
+    }
+    let clone = vec.clone();
+    assert_eq!(live(), 6);
+    drop(vec);
+    assert_eq!(live(), 3);
+
+    // So does a partly consumed `IntoIter`, from either end.
+    let mut iter = clone.into_iter();
+    drop(iter.next());
+    drop(iter.next_back());
+    assert_eq!(live(), 1);
+    drop(iter);
+    assert_eq!(live(), 0);
+}
@@ -0,0 +1,58 @@
+// Tests of the bands of `constants::Classifier`: the overlap check doesn't
+// depend on the order the bands are given in, and hysteresis works up to
+// the extremes of an integer type.
+//
+//   cargo test -p rbe --test bands
+
+use constants::{Band, BandError, Classifier};
+
+// The names of the bands of every reading of `values`.
+fn stream<T>(classifier: &Classifier<T>, values: &[T]) -> Vec<String>
+where
+    T: Copy + PartialOrd + Default + std::ops::Add<Output = T> + std::ops::Sub<Output = T>,
+{
+    let names = classifier.stream(values.iter().copied());
+    names.map(|reading| reading.band.map_or("none".to_string(), |band| band.name.clone())).collect()
+}
+
+#[test]
+fn overlap_ignores_order() {
+    let point = Band::new("point").at_least(10).at_most(10);
+    let above = Band::new("above").above(10).at_most(20);
+    let touching = Band::new("touching").at_least(10).at_most(20);
+
+    for bands in [vec![point.clone(), above.clone()], vec![above.clone(), point.clone()]] {
+        let classifier = Classifier::new(bands).unwrap();
+        let names: Vec<&str> = classifier.bands().iter().map(|band| band.name.as_str()).collect();
+        assert_eq!(names, ["point", "above"]);
+    }
+    for bands in [vec![point.clone(), touching.clone()], vec![touching, point]] {
+        assert!(matches!(Classifier::new(bands), Err(BandError::Overlap { .. })));
+    }
+}
+
+#[test]
+fn hysteresis_at_the_extremes() {
+    let bands = vec![
+        Band::new("bottom").at_most(i32::MIN + 1),
+        Band::new("middle").above(i32::MIN + 1).below(i32::MAX - 1),
+        Band::new("top").at_least(i32::MAX - 1),
+    ];
+    let classifier = Classifier::new(bands).unwrap().with_hysteresis(i32::MAX).unwrap();
+    // A margin of `MAX` is half the range: going from one end to the other
+    // leaves the band, going back to 0 doesn't.
+    assert_eq!(stream(&classifier, &[i32::MIN, i32::MAX, 0]), ["bottom", "top", "top"]);
+    assert_eq!(stream(&classifier, &[i32::MAX, i32::MIN, 0]), ["top", "bottom", "bottom"]);
+    assert_eq!(stream(&classifier, &[0, i32::MIN, i32::MAX]), ["middle", "middle", "middle"]);
+
+    let classifier = Classifier::new(vec![Band::new("low").below(3u8), Band::new("high").at_least(3)]).unwrap();
+    let classifier = classifier.with_hysteresis(u8::MAX).unwrap();
+    assert_eq!(stream(&classifier, &[0, 255, 0]), ["low", "low", "low"]);
+    assert_eq!(stream(&classifier, &[255, 0, 255]), ["high", "high", "high"]);
+
+    // A margin of 2 past `below(10)` is below 12, past `at_least(10)` down
+    // to 8 included.
+    let classifier = Classifier::new(vec![Band::new("low").below(10), Band::new("normal").at_least(10)]).unwrap();
+    let classifier = classifier.with_hysteresis(2).unwrap();
+    assert_eq!(stream(&classifier, &[9, 11, 12, 9, 8, 7]), ["low", "low", "normal", "normal", "normal", "low"]);
+}
@@ -0,0 +1,172 @@
+// Tests of the casts of `types::cast` at the boundaries of the target types:
+// what each of `checked_cast`, `exact_cast`, `saturating_cast`,
+// `wrapping_cast` and `as_cast` gives just inside and just outside them,
+// and which `Loss` the errors report. Then the same for `float_to_int` of
+// `types::rounding`, in every rounding mode.
+//
+//   cargo test -p rbe --test casts
+
+use std::fmt::Debug;
+
+use types::cast::{as_cast, checked_cast, exact_cast, saturating_cast, wrapping_cast, CastError, Integer, Loss, Primitive};
+use types::rounding::{float_to_int, FloatToIntError, Rounding};
+
+// The loss `checked_cast` reports, `None` when it succeeds.
+fn checked_loss<T: Primitive, U: Primitive + Debug>(value: T) -> Option<Loss> {
+    checked_cast::<T, U>(value).err().map(|err| err.loss)
+}
+
+fn exact_loss<T: Primitive, U: Primitive + Debug>(value: T) -> Option<Loss> {
+    exact_cast::<T, U>(value).err().map(|err| err.loss)
+}
+
+// 2^n, exactly.
+fn pow2(n: i32) -> f64 {
+    2f64.powi(n)
+}
+
+#[test]
+fn integer_range() {
+    assert_eq!(checked_cast::<i32, u8>(255), Ok(255));
+    assert_eq!(checked_loss::<i32, u8>(256), Some(Loss::Overflow));
+    assert_eq!(checked_loss::<i32, u8>(-1), Some(Loss::SignChange));
+    assert_eq!(checked_cast::<i16, i8>(-128), Ok(-128));
+    assert_eq!(checked_loss::<i16, i8>(-129), Some(Loss::Underflow));
+    assert_eq!(checked_loss::<i16, i8>(128), Some(Loss::Overflow));
+    assert_eq!(checked_loss::<i128, u128>(i128::MIN), Some(Loss::SignChange));
+    assert_eq!(checked_loss::<u128, i128>(1 << 127), Some(Loss::Overflow));
+    assert_eq!(checked_cast::<i128, i64>(i64::MIN.into()), Ok(i64::MIN));
+    assert_eq!(checked_loss::<i128, i64>(i128::from(i64::MIN) - 1), Some(Loss::Underflow));
+
+    assert_eq!(saturating_cast::<i16, i8>(-129), i8::MIN);
+    assert_eq!(saturating_cast::<i32, u8>(-1), 0);
+    assert_eq!(saturating_cast::<u128, i128>(u128::MAX), i128::MAX);
+    assert_eq!(wrapping_cast::<i16, i8>(-129), 127);
+    assert_eq!(wrapping_cast::<i32, u8>(-1), 255);
+    assert_eq!(wrapping_cast::<u128, i128>(u128::MAX), -1);
+    assert_eq!(as_cast::<i32, u8>(1000), 232);
+
+    assert_eq!(
+        checked_cast::<i32, u8>(1000),
+        Err(CastError { loss: Loss::Overflow, value: "1000".to_string(), from: "i32", to: "u8" })
+    );
+}
+
+#[test]
+fn float_to_integer_range() {
+    // 2^127 is one past `i128::MAX`, -2^127 is `i128::MIN`.
+    assert_eq!(checked_loss::<f64, i128>(pow2(127)), Some(Loss::Overflow));
+    assert_eq!(checked_cast::<f64, i128>(-pow2(127)), Ok(i128::MIN));
+    assert_eq!(checked_loss::<f64, i128>(-pow2(127) - pow2(75)), Some(Loss::Underflow));
+    assert_eq!(checked_cast::<f32, i128>(-pow2(127) as f32), Ok(i128::MIN));
+    assert_eq!(saturating_cast::<f64, i128>(pow2(127)), i128::MAX);
+    assert_eq!(wrapping_cast::<f64, i128>(pow2(127)), i128::MIN);
+
+    // The largest `f64` below 2^128 fits a `u128`, 2^128 doesn't.
+    assert_eq!(checked_cast::<f64, u128>(pow2(128) - pow2(75)), Ok(u128::MAX - (1 << 75) + 1));
+    assert_eq!(checked_loss::<f64, u128>(pow2(128)), Some(Loss::Overflow));
+    assert_eq!(saturating_cast::<f64, u128>(pow2(128)), u128::MAX);
+    assert_eq!(wrapping_cast::<f64, u128>(pow2(128)), 0);
+    assert_eq!(wrapping_cast::<f64, u8>(pow2(128) + pow2(76)), 0);
+    assert_eq!(wrapping_cast::<f64, u8>(-1.0), 255);
+
+    // -0.5 drops to -0, which any unsigned type holds; -1.5 doesn't.
+    assert_eq!(checked_cast::<f64, u8>(-0.5), Ok(0));
+    assert_eq!(exact_loss::<f64, u8>(-0.5), Some(Loss::Fraction));
+    assert_eq!(checked_loss::<f64, u8>(-1.5), Some(Loss::SignChange));
+    assert_eq!(checked_cast::<f32, u8>(255.9), Ok(255));
+    assert_eq!(checked_loss::<f32, u8>(256.0), Some(Loss::Overflow));
+    assert_eq!(checked_loss::<f64, i8>(-129.0), Some(Loss::Underflow));
+    assert_eq!(checked_cast::<f64, i8>(-128.9), Ok(-128));
+
+    for loss in [checked_loss::<f64, i32>(f64::NAN), exact_loss::<f32, u8>(f32::NAN)] {
+        assert_eq!(loss, Some(Loss::NaN));
+    }
+    assert_eq!(saturating_cast::<f64, i32>(f64::NAN), 0);
+    assert_eq!(saturating_cast::<f64, i32>(f64::INFINITY), i32::MAX);
+    assert_eq!(saturating_cast::<f64, u32>(f64::NEG_INFINITY), 0);
+    assert_eq!(checked_loss::<f64, u32>(f64::NEG_INFINITY), Some(Loss::SignChange));
+    assert_eq!(wrapping_cast::<f64, i32>(f64::INFINITY), 0);
+    assert_eq!(as_cast::<f64, u8>(300.0), 255);
+    assert_eq!(as_cast::<f64, i32>(f64::NAN), 0);
+}
+
+#[test]
+fn to_float() {
+    // 2^24 + 1 is the first integer an `f32` can't hold, 2^53 + 1 for `f64`.
+    assert_eq!(exact_cast::<i32, f32>(1 << 24), Ok(16777216.0));
+    assert_eq!(exact_loss::<i32, f32>((1 << 24) + 1), Some(Loss::Precision));
+    assert_eq!(checked_cast::<i32, f32>((1 << 24) + 1), Ok(16777216.0));
+    assert_eq!(exact_loss::<i64, f64>((1 << 53) + 1), Some(Loss::Precision));
+    assert_eq!(exact_cast::<i128, f64>(i128::MIN), Ok(-pow2(127)));
+    assert_eq!(exact_loss::<i128, f64>(i128::MAX), Some(Loss::Precision));
+    assert_eq!(exact_loss::<u128, f64>(u128::MAX), Some(Loss::Precision));
+
+    // `u128::MAX` rounds past `f32::MAX`, to infinity.
+    assert_eq!(checked_loss::<u128, f32>(u128::MAX), Some(Loss::Overflow));
+    assert_eq!(saturating_cast::<u128, f32>(u128::MAX), f32::MAX);
+    assert_eq!(wrapping_cast::<u128, f32>(u128::MAX), f32::INFINITY);
+    assert_eq!(checked_cast::<u128, f32>(1 << 127), Ok(pow2(127) as f32));
+    assert_eq!(checked_loss::<f64, f32>(f64::MAX), Some(Loss::Overflow));
+    assert_eq!(checked_loss::<f64, f32>(-f64::MAX), Some(Loss::Underflow));
+    assert_eq!(saturating_cast::<f64, f32>(-f64::MAX), f32::MIN);
+
+    assert_eq!(exact_loss::<f64, f32>(0.1), Some(Loss::Precision));
+    assert_eq!(checked_cast::<f64, f32>(0.1), Ok(0.1));
+    assert_eq!(exact_cast::<f64, f32>(0.5), Ok(0.5));
+    assert_eq!(exact_cast::<f64, f32>(f64::INFINITY), Ok(f32::INFINITY));
+    assert!(exact_cast::<f64, f32>(f64::NAN).unwrap().is_nan());
+}
+
+// `x` in every mode, in the order of `Rounding::ALL`: truncate, floor, ceil,
+// half away from zero, half to even.
+fn round_all<I: Integer>(x: f64) -> Vec<Result<I, FloatToIntError>> {
+    Rounding::ALL.iter().map(|&mode| float_to_int::<I>(x, mode)).collect()
+}
+
+fn out_of_range(rounded: f64, to: &'static str) -> FloatToIntError {
+    FloatToIntError::OutOfRange { rounded, to }
+}
+
+#[test]
+fn rounding_modes() {
+    assert_eq!(round_all::<i32>(2.5), [Ok(2), Ok(2), Ok(3), Ok(3), Ok(2)]);
+    assert_eq!(round_all::<i32>(3.5), [Ok(3), Ok(3), Ok(4), Ok(4), Ok(4)]);
+    assert_eq!(round_all::<i32>(-2.5), [Ok(-2), Ok(-3), Ok(-2), Ok(-3), Ok(-2)]);
+    assert_eq!(round_all::<i32>(-2.7), [Ok(-2), Ok(-3), Ok(-2), Ok(-3), Ok(-3)]);
+    // The `f64` next to 0.5 rounds down, whatever `x + 0.5` would say.
+    assert_eq!(round_all::<i32>(0.49999999999999994), [Ok(0), Ok(0), Ok(1), Ok(0), Ok(0)]);
+    assert_eq!(float_to_int::<u8>(2.5f32, Rounding::HalfToEven), Ok(2));
+}
+
+#[test]
+fn rounding_at_the_boundaries() {
+    // -0.5 goes to -0, fine for an unsigned type, or to -1, which isn't.
+    let minus_one = out_of_range(-1.0, "u8");
+    assert_eq!(round_all::<u8>(-0.5), [Ok(0), Err(minus_one.clone()), Ok(0), Err(minus_one), Ok(0)]);
+
+    // Whether 255.5 fits a `u8` depends on the way it is rounded.
+    let too_large = out_of_range(256.0, "u8");
+    assert_eq!(round_all::<u8>(255.5), [Ok(255), Ok(255), Err(too_large.clone()), Err(too_large.clone()), Err(too_large)]);
+    let too_small = out_of_range(-129.0, "i8");
+    assert_eq!(round_all::<i8>(-128.5), [Ok(-128), Err(too_small.clone()), Ok(-128), Err(too_small), Ok(-128)]);
+
+    // -2^127 is `i128::MIN`, 2^127 just out of range, in every mode.
+    for result in round_all::<i128>(-pow2(127)) {
+        assert_eq!(result, Ok(i128::MIN));
+    }
+    for result in round_all::<i128>(pow2(127)) {
+        assert_eq!(result, Err(out_of_range(pow2(127), "i128")));
+    }
+    for result in round_all::<u128>(pow2(128)) {
+        assert_eq!(result, Err(out_of_range(pow2(128), "u128")));
+    }
+    assert_eq!(float_to_int::<u128>(pow2(128) - pow2(75), Rounding::Ceil), Ok(u128::MAX - (1 << 75) + 1));
+    assert_eq!(float_to_int::<i128>(-pow2(127) as f32, Rounding::Floor), Ok(i128::MIN));
+
+    for mode in Rounding::ALL {
+        assert_eq!(float_to_int::<i64>(f64::NAN, mode), Err(FloatToIntError::NaN));
+        assert_eq!(float_to_int::<u8>(f32::INFINITY, mode), Err(FloatToIntError::Infinite { negative: false }));
+        assert_eq!(float_to_int::<i8>(f64::NEG_INFINITY, mode), Err(FloatToIntError::Infinite { negative: true }));
+    }
+}
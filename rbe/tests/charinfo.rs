@@ -0,0 +1,111 @@
+// Tests of `types::charinfo`: the graphemes of combining sequences, emoji
+// joined by a zero width joiner and flags, the classes of chars, what each
+// byte of a UTF-8 char is, and which `u32`s are no char.
+//
+//   cargo test -p rbe --test charinfo
+
+use types::charinfo::{char_from_u32, graphemes, AsciiClass, ByteRole, CharError, CharInfo, Class};
+
+#[test]
+fn one_grapheme_each() {
+    for text in [
+        "e",
+        "é",
+        // 'e' and a combining acute accent.
+        "e\u{301}",
+        // With two accents.
+        "a\u{301}\u{328}",
+        // Man, woman, girl, joined by zero width joiners.
+        "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}",
+        // A waving hand with a skin tone, a heart with a variation selector.
+        "\u{1F44B}\u{1F3FD}",
+        "\u{2764}\u{FE0F}",
+        // The regional indicators F and R: the French flag.
+        "\u{1F1EB}\u{1F1F7}",
+        "\r\n",
+    ] {
+        assert_eq!(graphemes(text), 1, "{:?}", text);
+    }
+}
+
+#[test]
+fn several_graphemes() {
+    assert_eq!(graphemes(""), 0);
+    assert_eq!(graphemes("e\u{301}e\u{301}"), 2);
+    assert_eq!(graphemes("caf\u{E9} cafe\u{301}"), 9);
+    // Two flags, then a lone regional indicator.
+    assert_eq!(graphemes("\u{1F1EB}\u{1F1F7}\u{1F1E9}\u{1F1EA}"), 2);
+    assert_eq!(graphemes("\u{1F1EB}\u{1F1F7}\u{1F1E9}"), 2);
+    assert_eq!(graphemes("\u{1F468}\u{200D}\u{1F469}\u{1F467}"), 2);
+    assert_eq!(graphemes("\n\r"), 2);
+
+    // The three lengths of a string differ.
+    let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+    assert_eq!((family.len(), family.chars().count(), graphemes(family)), (18, 5, 1));
+}
+
+#[test]
+fn classes() {
+    for (c, class) in [
+        ('A', Class::Ascii(AsciiClass::Uppercase)),
+        ('z', Class::Ascii(AsciiClass::Lowercase)),
+        ('7', Class::Ascii(AsciiClass::Digit)),
+        ('~', Class::Ascii(AsciiClass::Punctuation)),
+        // Whitespace even though it is a control char too.
+        ('\t', Class::Ascii(AsciiClass::Whitespace)),
+        ('\0', Class::Ascii(AsciiClass::Control)),
+        ('\u{7F}', Class::Ascii(AsciiClass::Control)),
+        ('é', Class::Alphabetic),
+        ('東', Class::Alphabetic),
+        // ARABIC-INDIC DIGIT THREE.
+        ('\u{663}', Class::Numeric),
+        ('\u{A0}', Class::Whitespace),
+        ('\u{85}', Class::Whitespace),
+        ('\u{9F}', Class::Control),
+        ('\u{301}', Class::Extender),
+        ('\u{200D}', Class::Extender),
+        ('\u{1F3FD}', Class::Extender),
+        ('€', Class::Other),
+        ('\u{1F980}', Class::Other),
+    ] {
+        assert_eq!(Class::of(c), class, "{:?}", c);
+    }
+}
+
+#[test]
+fn byte_roles() {
+    // U+1F980, the crab: F0 9F A6 80.
+    let crab = CharInfo::new('\u{1F980}');
+    assert_eq!(crab.utf8, [0xF0, 0x9F, 0xA6, 0x80]);
+    assert_eq!(crab.utf16, [0xD83E, 0xDD80]);
+    let roles: Vec<ByteRole> = crab.utf8.iter().map(|&b| ByteRole::of(b)).collect();
+    assert_eq!(roles, [ByteRole::Lead(4), ByteRole::Continuation, ByteRole::Continuation, ByteRole::Continuation]);
+
+    // The first byte of every char says how long it is.
+    for c in ['a', 'é', '€', '\u{1F980}', '\u{7F}', '\u{80}', '\u{7FF}', '\u{800}', '\u{FFFF}', '\u{10000}', '\u{10FFFF}'] {
+        let utf8 = CharInfo::new(c).utf8;
+        let expected = if utf8.len() == 1 { ByteRole::Ascii } else { ByteRole::Lead(utf8.len()) };
+        assert_eq!(ByteRole::of(utf8[0]), expected, "{:?}", c);
+        assert!(utf8[1..].iter().all(|&b| ByteRole::of(b) == ByteRole::Continuation), "{:?}", c);
+    }
+
+    for byte in [0xC0, 0xC1, 0xF5, 0xFF] {
+        assert_eq!(ByteRole::of(byte), ByteRole::Invalid, "{:#X}", byte);
+    }
+    assert_eq!((ByteRole::of(0xBF), ByteRole::of(0xC2), ByteRole::of(0xF4)), (ByteRole::Continuation, ByteRole::Lead(2), ByteRole::Lead(4)));
+}
+
+#[test]
+fn u32s_that_are_no_char() {
+    assert_eq!(char_from_u32(0xD800), Err(CharError::Surrogate(0xD800)));
+    assert_eq!(char_from_u32(0xDFFF), Err(CharError::Surrogate(0xDFFF)));
+    assert_eq!(char_from_u32(0x110000), Err(CharError::TooLarge(0x110000)));
+    assert_eq!(char_from_u32(u32::MAX), Err(CharError::TooLarge(u32::MAX)));
+    assert_eq!(char_from_u32(0xD800).unwrap_err().to_string(), "0xD800 is a UTF-16 surrogate, not a char");
+
+    // Just around them.
+    assert_eq!(char_from_u32(0xD7FF), Ok('\u{D7FF}'));
+    assert_eq!(char_from_u32(0xE000), Ok('\u{E000}'));
+    assert_eq!(char_from_u32(0x10FFFF), Ok('\u{10FFFF}'));
+    assert_eq!(char_from_u32(0), Ok('\0'));
+}
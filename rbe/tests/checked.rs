@@ -0,0 +1,81 @@
+// Tests of `literalsandoperators::Checked`: an overflow in any operation is
+// reported, it is the first one that is kept through the operations after
+// it, and dividing by zero and `MIN / -1` are overflows too.
+//
+//   cargo test -p rbe --test checked
+
+use literalsandoperators::checked::{Checked, Op, Overflow};
+
+fn binary<T>(op: Op, lhs: T, rhs: T) -> Overflow<T> {
+    Overflow::Binary { op, lhs, rhs }
+}
+
+#[test]
+fn every_operator_overflows() {
+    let max = Checked::new(i32::MAX);
+    let min = Checked::new(i32::MIN);
+    assert_eq!((max + 1).get(), Err(binary(Op::Add, i32::MAX, 1)));
+    assert_eq!((min - 1).get(), Err(binary(Op::Sub, i32::MIN, 1)));
+    assert_eq!((max * 2).get(), Err(binary(Op::Mul, i32::MAX, 2)));
+    assert_eq!((min / -1).get(), Err(binary(Op::Div, i32::MIN, -1)));
+    assert_eq!((min % Checked::new(-1)).get(), Err(binary(Op::Rem, i32::MIN, -1)));
+    assert_eq!((-min).get(), Err(Overflow::Neg(i32::MIN)));
+    assert_eq!((Checked::new(1u8) << 8).get(), Err(Overflow::Shift { op: Op::Shl, lhs: 1, rhs: 8 }));
+
+    // The value is the wrapped one, as in a release build.
+    assert_eq!(((max + 1).wrapped(), (min / -1).wrapped(), (-min).wrapped()), (i32::MIN, i32::MIN, i32::MIN));
+    assert_eq!((Checked::new(0u32) - 1).wrapped(), u32::MAX);
+
+    // Just in range.
+    assert_eq!((max - 1 + 1).get(), Ok(i32::MAX));
+    assert_eq!((min + 1) / -1, Checked::new(i32::MAX));
+    assert_eq!((Checked::new(i64::MIN) / 1).get(), Ok(i64::MIN));
+    assert_eq!((Checked::new(16u8) * 15 + 15).get(), Ok(255));
+    // Bits shifted out don't count, only the shift amount does.
+    assert_eq!((Checked::new(0xFFu8) << 7).get(), Ok(0x80));
+    assert_eq!((Checked::new(1u8) >> 7).get(), Ok(0));
+}
+
+#[test]
+fn division_by_zero() {
+    let quotient = Checked::new(7i32) / 0;
+    assert_eq!(quotient.get(), Err(binary(Op::Div, 7, 0)));
+    assert_eq!(quotient.wrapped(), 0);
+    assert_eq!(quotient.to_string(), "overflow, `7 / 0` divides by zero");
+    assert_eq!((Checked::new(7u8) % 0).get(), Err(binary(Op::Rem, 7, 0)));
+    assert_eq!((Checked::new(0u64) / 0).to_string(), "overflow, `0 / 0` divides by zero");
+
+    // The divisor comes from an expression that is zero after all.
+    let divisor = Checked::new(3i8) - 3;
+    assert_eq!((Checked::new(5i8) / divisor).get(), Err(binary(Op::Div, 5, 0)));
+}
+
+#[test]
+fn the_first_overflow_is_kept() {
+    // `(200 + 100) / 2 + 1` in `u8`: the sum overflows, to 44, and the
+    // operations after it go on with the wrapped value.
+    let total = (Checked::new(200u8) + 100) / 2 + 1;
+    assert_eq!(total.get(), Err(binary(Op::Add, 200, 100)));
+    assert_eq!(total.wrapped(), 23);
+    assert_eq!(total.to_string(), "overflow, `200 + 100` overflows u8");
+
+    // A later overflow doesn't replace it, nor does a division by zero.
+    let mut value = Checked::new(i16::MAX) * 2;
+    value -= i16::MAX;
+    value *= 1000;
+    value /= 0;
+    value += 1;
+    assert_eq!(value.overflow(), Some(binary(Op::Mul, i16::MAX, 2)));
+
+    // The overflows of both operands: the left one is evaluated first.
+    let lhs = Checked::new(i32::MIN) - 1;
+    let rhs = -Checked::new(i32::MIN);
+    assert_eq!((lhs + rhs).overflow(), Some(binary(Op::Sub, i32::MIN, 1)));
+    assert_eq!((rhs + lhs).overflow(), Some(Overflow::Neg(i32::MIN)));
+    assert_eq!((Checked::new(1) + rhs).overflow(), Some(Overflow::Neg(i32::MIN)));
+
+    // Operations that can't overflow keep it too.
+    let value = ((Checked::new(u32::MAX) + 1) * 3) >> 1;
+    assert!(value.overflowed());
+    assert_eq!((value.wrapped(), value.overflow()), (0, Some(binary(Op::Add, u32::MAX, 1))));
+}
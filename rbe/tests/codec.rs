@@ -0,0 +1,172 @@
+// Round-trip tests of the binary codec: in every format, `decode(encode(x))`
+// must give `x` again, and every truncation of `encode(x)` must fail with an
+// "unexpected end" error at the byte where the input stops.
+//
+//   cargo test -p rbe --test codec
+//   ROUNDTRIP_SEED=42 cargo test -p rbe --test codec
+
+use std::fmt::Debug;
+
+use codec::{decode, encode, Decode, DecodeError, DecodeErrorKind, Encode, Format, MAX_EMPTY_ITEMS};
+use enumlinkedlist::List;
+use functions::Point;
+use structures::Person;
+use tuples::Matrix;
+
+mod common;
+
+use common::{seed, Rng};
+
+const CASES: usize = 500;
+
+// The harness: `CASES` values from `generate`, each encoded in every format,
+// decoded back, then decoded from every prefix of its bytes.
+fn check_round_trip<T, F>(generate: F)
+where
+    T: Encode + Decode + PartialEq + Debug,
+    F: Fn(&mut Rng) -> T,
+{
+    let seed = seed();
+    let mut rng = Rng::new(seed);
+    for case in 0..CASES {
+        let value = generate(&mut rng);
+        for format in Format::ALL {
+            let bytes = encode(&value, format);
+            match decode::<T>(&bytes, format) {
+                Ok(decoded) if decoded == value => {}
+                Ok(decoded) => panic!("seed {}, case {}, {}: {:?} encoded as {:02X?} decoded as {:?}", seed, case, format, value, bytes, decoded),
+                Err(err) => panic!("seed {}, case {}, {}: {:?} encoded as {:02X?} failed to decode: {}", seed, case, format, value, bytes, err),
+            }
+            for len in 0..bytes.len() {
+                match decode::<T>(&bytes[..len], format) {
+                    Err(DecodeError { offset, kind: DecodeErrorKind::UnexpectedEnd { .. } }) if offset == len => {}
+                    other => panic!("seed {}, case {}, {}: {:02X?} truncated to {} bytes gave {:?}", seed, case, format, bytes, len, other),
+                }
+            }
+        }
+    }
+}
+
+// The extremes, small values, which have short varints, and any value.
+macro_rules! ints {
+    ($($name:ident: $ty:ident),*) => {$(
+        #[test]
+        fn $name() {
+            check_round_trip(|rng| match rng.range(0, 2) {
+                0 => rng.pick(&[$ty::MIN, $ty::MAX, 0, 1, $ty::MAX / 2]),
+                1 => (rng.next_u64() >> rng.range(0, 63)) as $ty,
+                _ => ((rng.next_u64() as u128) << 64 | rng.next_u64() as u128) as $ty,
+            });
+        }
+    )*};
+}
+
+ints!(
+    u8_: u8, u16_: u16, u32_: u32, u64_: u64, u128_: u128, usize_: usize,
+    i8_: i8, i16_: i16, i32_: i32, i64_: i64, i128_: i128, isize_: isize
+);
+
+#[test]
+fn floats() {
+    check_round_trip(|rng| (rng.f64() as f32, rng.f64()));
+}
+
+#[test]
+fn bool_and_char() {
+    check_round_trip(|rng| (rng.range(0, 1) == 1, rng.pick(&['\0', 'a', 'é', '東', '🦀', char::MAX])));
+}
+
+#[test]
+fn containers() {
+    check_round_trip(|rng| {
+        let len = rng.range(0, 8) as usize;
+        let items: Vec<u16> = (0..len).map(|_| rng.next_u64() as u16).collect();
+        let array = [rng.i32(), rng.i32(), rng.i32()];
+        let maybe = if rng.range(0, 1) == 0 { None } else { Some(rng.string()) };
+        (items, rng.string(), array, maybe, ())
+    });
+}
+
+#[test]
+fn person() {
+    check_round_trip(|rng| Person { name: rng.string(), age: rng.u8() });
+}
+
+#[test]
+fn point() {
+    check_round_trip(|rng| Point::new(rng.f64(), rng.f64()));
+}
+
+#[test]
+fn matrix() {
+    check_round_trip(|rng| Matrix(rng.f64() as f32, rng.f64() as f32, rng.f64() as f32, rng.f64() as f32));
+}
+
+#[test]
+fn list() {
+    check_round_trip(|rng| {
+        let len = rng.range(0, 10);
+        (0..len).fold(List::new(), |list, _| list.prepend(rng.next_u64() as u32))
+    });
+}
+
+// The bytes of the header comment of the codec.
+#[test]
+fn known_encodings() {
+    assert_eq!(encode(&300u32, Format::BigEndian), [0x00, 0x00, 0x01, 0x2C]);
+    assert_eq!(encode(&300u32, Format::LittleEndian), [0x2C, 0x01, 0x00, 0x00]);
+    assert_eq!(encode(&300u32, Format::Varint), [0xAC, 0x02]);
+    assert_eq!(encode(&-1i64, Format::Varint), [0x7F]);
+    assert_eq!(encode(&-300i16, Format::Varint), [0xD4, 0x7D]);
+    assert_eq!(encode(&u128::MAX, Format::Varint).len(), 19);
+    assert_eq!(encode(&i128::MIN, Format::Varint).len(), 19);
+    assert_eq!(encode("hé", Format::Varint), [0x03, b'h', 0xC3, 0xA9]);
+    let list = List::new().prepend(1).prepend(2).prepend(3);
+    assert_eq!(encode(&list, Format::Varint), [0x01, 0x03, 0x01, 0x02, 0x01, 0x01, 0x00]);
+}
+
+fn error<T: Decode + Debug>(bytes: &[u8], format: Format) -> DecodeError {
+    decode::<T>(bytes, format).expect_err("corrupt input decoded")
+}
+
+#[test]
+fn rejects_corrupt_input() {
+    use DecodeErrorKind::*;
+
+    assert_eq!(error::<u8>(&[1, 2], Format::Varint), DecodeError::new(1, TrailingBytes(1)));
+    assert_eq!(error::<bool>(&[2], Format::Varint), DecodeError::new(0, InvalidBool(2)));
+    assert_eq!(error::<char>(&[0x00, 0xD8, 0x00, 0x00], Format::LittleEndian), DecodeError::new(0, InvalidChar(0xD800)));
+    assert_eq!(error::<String>(&[3, b'a', 0xC3, b'b'], Format::Varint), DecodeError::new(2, InvalidUtf8));
+    assert_eq!(error::<Option<u8>>(&[2, 0], Format::Varint), DecodeError::new(0, InvalidTag { ty: "Option", tag: 2 }));
+    assert_eq!(error::<List>(&[1, 5, 7], Format::Varint), DecodeError::new(2, InvalidTag { ty: "List", tag: 7 }));
+    assert_eq!(error::<Matrix>(&[0; 15], Format::BigEndian), DecodeError::new(15, UnexpectedEnd { needed: 1 }));
+    assert_eq!(error::<u64>(&[0; 5], Format::BigEndian), DecodeError::new(5, UnexpectedEnd { needed: 3 }));
+
+    // 2^16 needs 17 bits, a u16 varint takes 3 bytes at most.
+    assert_eq!(error::<u16>(&[0x80, 0x80, 0x04], Format::Varint), DecodeError::new(0, OutOfRange("u16")));
+    assert_eq!(error::<u16>(&[0x80, 0x80, 0x80, 0x00], Format::Varint), DecodeError::new(0, VarintTooLong));
+    assert_eq!(error::<i32>(&[0x80], Format::Varint), DecodeError::new(1, UnexpectedEnd { needed: 1 }));
+    // -49152, below i16::MIN.
+    assert_eq!(error::<i16>(&[0x80, 0x80, 0x7D], Format::Varint), DecodeError::new(0, OutOfRange("i16")));
+
+    // The 19th byte of a 128 bit varint has room for 2 bits only.
+    let mut bytes = vec![0xFF; 18];
+    bytes.push(0x04);
+    assert_eq!(error::<u128>(&bytes, Format::Varint), DecodeError::new(0, OutOfRange("u128")));
+    bytes[18] = 0x3E;
+    assert_eq!(error::<i128>(&bytes, Format::Varint), DecodeError::new(0, OutOfRange("i128")));
+
+    // A length larger than the input fails, it doesn't allocate.
+    let mut bytes = encode(&u64::MAX, Format::Varint);
+    bytes.push(0);
+    assert!(matches!(error::<Vec<u8>>(&bytes, Format::Varint).kind, UnexpectedEnd { .. } | OutOfRange("usize")));
+    // Nor does it spin when the items take no bytes, and the input can't
+    // bound their number: it is capped.
+    for len in [u64::MAX, MAX_EMPTY_ITEMS as u64 + 1] {
+        let bytes = encode(&len, Format::Varint);
+        assert_eq!(error::<Vec<()>>(&bytes, Format::Varint), DecodeError::new(0, OutOfRange("Vec")));
+        assert_eq!(error::<Vec<((), [u8; 0])>>(&bytes, Format::Varint), DecodeError::new(0, OutOfRange("Vec")));
+    }
+    let units = vec![(); MAX_EMPTY_ITEMS];
+    assert_eq!(decode::<Vec<()>>(&encode(&units, Format::Varint), Format::Varint), Ok(units));
+}
@@ -0,0 +1,75 @@
+// The seeded random generator shared by the round-trip tests. Every test
+// starts from `seed()`, so `ROUNDTRIP_SEED` replays a failure.
+
+// Each test file only uses some of the generators.
+#![allow(dead_code)]
+
+use std::env;
+
+// xorshift64*: tiny, fast and good enough to pick test values.
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        // The state must never be zero.
+        Rng(seed ^ 0x9e37_79b9_7f4a_7c15 | 1)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    // Uniform in `low..=high`, near enough for tests.
+    pub fn range(&mut self, low: i64, high: i64) -> i64 {
+        let span = (high - low) as u64 + 1;
+        low + (self.next_u64() % span) as i64
+    }
+
+    pub fn pick<T: Copy>(&mut self, items: &[T]) -> T {
+        items[self.range(0, items.len() as i64 - 1) as usize]
+    }
+
+    // Any `f64` but a NaN, with the awkward ones over-represented: zeros,
+    // infinities, subnormals, and round numbers.
+    pub fn f64(&mut self) -> f64 {
+        match self.range(0, 9) {
+            0 => self.pick(&[0.0, -0.0, f64::INFINITY, f64::NEG_INFINITY, f64::MIN, f64::MAX, f64::MIN_POSITIVE]),
+            1 => f64::from_bits(self.range(1, 0x000f_ffff_ffff_ffff) as u64),
+            2 => self.range(-1000, 1000) as f64 / 10.0,
+            _ => loop {
+                let x = f64::from_bits(self.next_u64());
+                if !x.is_nan() {
+                    break x;
+                }
+            },
+        }
+    }
+
+    pub fn i32(&mut self) -> i32 {
+        match self.range(0, 3) {
+            0 => self.pick(&[0, 1, -1, i32::MIN, i32::MAX]),
+            _ => self.next_u64() as i32,
+        }
+    }
+
+    pub fn u8(&mut self) -> u8 {
+        self.next_u64() as u8
+    }
+
+    // A short string mixing ASCII, the separators of the formats and
+    // multi-byte characters.
+    pub fn string(&mut self) -> String {
+        let len = self.range(0, 12);
+        (0..len).map(|_| self.pick(&['a', 'Z', '0', ' ', ':', ',', '°', 'é', 'ß', '東', '🦀'])).collect()
+    }
+}
+
+pub fn seed() -> u64 {
+    match env::var("ROUNDTRIP_SEED") {
+        Ok(seed) => seed.parse().expect("ROUNDTRIP_SEED must be an integer"),
+        Err(_) => 0x5eed,
+    }
+}
@@ -0,0 +1,128 @@
+// Tests of the arithmetic of `display::Complex`: the operators against values
+// worked out by hand, division by zero, square roots on the principal branch,
+// `exp` undoing `ln`, and the powers of zero. Its parser is tested with the
+// round trips.
+//
+//   cargo test -p rbe --test complex
+
+use std::f64::consts::{FRAC_PI_2, PI};
+
+use display::Complex;
+
+fn c(real: f64, imag: f64) -> Complex {
+    Complex::new(real, imag)
+}
+
+fn assert_close(actual: Complex, expected: Complex) {
+    assert!(actual.approx_eq(expected, 1e-12), "{} is not {}", actual, expected);
+}
+
+fn is_nan(z: Complex) -> bool {
+    z.real.is_nan() && z.imag.is_nan()
+}
+
+#[test]
+fn operators() {
+    let z = c(3.0, 4.0);
+    let w = c(1.0, -2.0);
+    assert_eq!(z + w, c(4.0, 2.0));
+    assert_eq!(z - w, c(2.0, 6.0));
+    // (3 + 4i)(1 - 2i) = 3 - 6i + 4i + 8
+    assert_eq!(z * w, c(11.0, -2.0));
+    // (3 + 4i)/(1 - 2i) = (3 + 4i)(1 + 2i)/5 = (-5 + 10i)/5
+    assert_eq!(z / w, c(-1.0, 2.0));
+    assert_eq!(z * Complex::I, c(-4.0, 3.0));
+    assert_eq!(Complex::I * Complex::I, c(-1.0, 0.0));
+    assert_close(1.0 / z, c(0.12, -0.16));
+    assert_close(z.recip(), c(0.12, -0.16));
+    assert_eq!(-z, c(-3.0, -4.0));
+    assert_eq!(z * z.conjugate(), c(25.0, 0.0));
+    assert_eq!((z.modulus(), z.modulus_squared()), (5.0, 25.0));
+
+    // With a real on either side.
+    assert_eq!((z + 1.0, 1.0 + z, z - 1.0, 1.0 - z), (c(4.0, 4.0), c(4.0, 4.0), c(2.0, 4.0), c(-2.0, -4.0)));
+    assert_eq!((z * 2.0, 2.0 * z, z / 2.0), (c(6.0, 8.0), c(6.0, 8.0), c(1.5, 2.0)));
+    // A real is no complex with a zero imaginary part, whose `0 * inf`
+    // would be a NaN.
+    let infinite = c(f64::INFINITY, 1.0);
+    assert_eq!((2.0 * infinite, infinite * 2.0), (c(f64::INFINITY, 2.0), c(f64::INFINITY, 2.0)));
+    assert!((Complex::from(2.0) * infinite).imag.is_nan());
+
+    let mut acc = z;
+    acc += w;
+    acc *= 2.0;
+    acc -= Complex::ONE;
+    acc /= w;
+    assert_close(acc, c(7.0, 4.0) / w);
+    assert_eq!([z, w, Complex::I].into_iter().sum::<Complex>(), c(4.0, 3.0));
+    assert_eq!([z, w].into_iter().product::<Complex>(), c(11.0, -2.0));
+
+    // Smith's algorithm: neither `c² + d²` overflows nor underflows.
+    assert_close(c(1e300, 1e300) / c(1e300, 1e300), Complex::ONE);
+    assert_close(c(1e-300, 1e-300) / c(0.0, 1e-300), c(1.0, -1.0));
+}
+
+#[test]
+fn division_by_zero() {
+    // As for `f64`, no panic: a zero complex divisor gives NaNs...
+    assert!(is_nan(c(3.0, 4.0) / Complex::ZERO));
+    assert!(is_nan(Complex::ZERO / Complex::ZERO));
+    assert!(is_nan(1.0 / Complex::ZERO));
+    assert!(is_nan(Complex::ZERO.recip()));
+    // ...and a real zero divides each part by zero.
+    assert_eq!(c(3.0, -4.0) / 0.0, c(f64::INFINITY, f64::NEG_INFINITY));
+    let z = c(0.0, 1.0) / 0.0;
+    assert!(z.real.is_nan() && z.imag == f64::INFINITY);
+}
+
+#[test]
+fn square_roots() {
+    assert_eq!(c(-4.0, 0.0).sqrt(), c(0.0, 2.0));
+    assert_eq!(c(-1.0, 0.0).sqrt(), Complex::I);
+    // The sign of a zero imaginary part picks the side of the cut.
+    assert_eq!(c(-4.0, -0.0).sqrt(), c(0.0, -2.0));
+    assert_eq!(c(4.0, 0.0).sqrt(), c(2.0, 0.0));
+    assert_eq!(Complex::ZERO.sqrt(), Complex::ZERO);
+    assert_close(c(3.0, 4.0).sqrt(), c(2.0, 1.0));
+    assert_close(c(-3.0, -4.0).sqrt(), c(1.0, -2.0));
+    assert_close(Complex::I.sqrt(), Complex::from_polar(1.0, PI / 4.0));
+
+    // The real part is never negative, and the root squares back.
+    for real in [-1e6, -2.0, -0.5, 0.0, 0.5, 3.0] {
+        for imag in [-1e6, -1.0, -0.0, 0.0, 1e-9, 7.0] {
+            let z = c(real, imag);
+            let root = z.sqrt();
+            assert!(root.real >= 0.0, "sqrt({}) = {}", z, root);
+            assert_close(root * root, z);
+        }
+    }
+}
+
+#[test]
+fn exp_undoes_ln() {
+    for z in [c(1.0, 0.0), c(3.0, 4.0), c(-2.0, 0.5), c(-1.0, 0.0), c(0.0, -7.0), c(1e-8, 1e-8), c(1e8, -3e7)] {
+        assert_close(z.ln().exp(), z);
+        // The principal logarithm: its imaginary part is the argument.
+        assert!(z.ln().imag.abs() <= PI, "ln({}) = {}", z, z.ln());
+    }
+    assert_close(c(-1.0, 0.0).ln(), c(0.0, PI));
+    assert_close(Complex::I.ln(), c(0.0, FRAC_PI_2));
+    // e^(iπ) + 1 = 0
+    assert_close(c(0.0, PI).exp() + 1.0, Complex::ZERO);
+}
+
+#[test]
+fn powers() {
+    // 0^w: 1 for w = 0, 0 when the real part of w is positive, NaN otherwise.
+    assert_eq!(Complex::ZERO.powc(Complex::ZERO), Complex::ONE);
+    assert_eq!(Complex::ZERO.powc(c(2.0, 1.0)), Complex::ZERO);
+    assert_eq!(Complex::ZERO.powc(c(1e-300, -5.0)), Complex::ZERO);
+    assert!(is_nan(Complex::ZERO.powc(c(-1.0, 0.0))));
+    assert!(is_nan(Complex::ZERO.powc(Complex::I)));
+
+    // i^i = e^(-π/2), a real number.
+    assert_close(Complex::I.powc(Complex::I), c((-FRAC_PI_2).exp(), 0.0));
+    assert_close(c(3.0, 4.0).powc(c(2.0, 0.0)), c(-7.0, 24.0));
+    assert_close(c(3.0, 4.0).powf(2.0), c(-7.0, 24.0));
+    assert_close(c(-8.0, 0.0).powf(1.0 / 3.0), Complex::from_polar(2.0, PI / 3.0));
+}
@@ -0,0 +1,75 @@
+// Tests of the config file of `constants`: what `--print-config` writes
+// reads back as the same configuration, whatever the language holds. The
+// environment is read through a closure standing in for `std::env::var`.
+//
+//   cargo test -p rbe --test config
+
+use std::env::VarError;
+use std::ffi::OsString;
+use std::path::Path;
+
+use constants::config::{LANGUAGE_VAR, THRESHOLD_VAR};
+use constants::{Config, ConfigError, Source};
+
+// `config` written out, then read back over the defaults.
+fn write_then_read(config: &Config) -> Config {
+    let mut out = Vec::new();
+    config.write_to(&mut out).unwrap();
+    let text = String::from_utf8(out).unwrap();
+    let mut read = Config::default();
+    read.merge_str(&text, Path::new("written.toml")).unwrap_or_else(|err| panic!("{}\n{}", err, text));
+    read
+}
+
+#[test]
+fn print_config_reads_back() {
+    let languages = ["Rust", " Rust ", "say \"hi\"", "C:\\path", "tab\there", "two\nlines", "cr\r", "bell\u{7}", "nul\0", "del\u{7F}", "é東🦀", "# not a comment", "\u{85}\u{9F}"];
+    for language in languages {
+        let mut config = Config::default();
+        config.set("language", language, Source::Flag("language".to_string())).unwrap();
+        config.set("threshold", "-2147483648", Source::Default).unwrap();
+        let read = write_then_read(&config);
+        assert_eq!(read.language.value, language);
+        assert_eq!(read.threshold.value, i32::MIN);
+    }
+}
+
+#[test]
+fn rejects_bad_escapes() {
+    for value in ["\"\\x41\"", "\"\\u41\"", "\"\\uD800\"", "\"\\U00110000\"", "\"\\u+041\"", "\"\\", "\"\\u00E"] {
+        let text = format!("language = {}", value);
+        assert!(Config::default().merge_str(&text, Path::new("bad.toml")).is_err(), "{}", text);
+    }
+    let mut config = Config::default();
+    config.merge_str("language = \"\\u00E9\\U0001F980\" # crab", Path::new("good.toml")).unwrap();
+    assert_eq!(config.language.value, "é🦀");
+}
+
+#[test]
+fn reads_the_environment() {
+    let mut config = Config::default();
+    config
+        .merge_env(|name| match name {
+            THRESHOLD_VAR => Ok("7".to_string()),
+            _ => Err(VarError::NotPresent),
+        })
+        .unwrap();
+    assert_eq!(config.threshold.value, 7);
+    assert_eq!(config.threshold.source, Source::Env(THRESHOLD_VAR.to_string()));
+    assert_eq!(config.language, Config::default().language);
+}
+
+#[test]
+fn rejects_variables_that_are_not_unicode() {
+    let mut config = Config::default();
+    let err = config
+        .merge_env(|name| match name {
+            LANGUAGE_VAR => Err(VarError::NotUnicode(OsString::from("caf\u{e9}"))),
+            _ => Err(VarError::NotPresent),
+        })
+        .unwrap_err();
+    assert!(matches!(&err, ConfigError::NotUnicode { var: LANGUAGE_VAR, value } if value == "caf\u{e9}"), "{:?}", err);
+    assert_eq!(err.to_string(), "$CONSTANTS_LANGUAGE is not valid Unicode: \"caf\u{e9}\"");
+    // The language wasn't touched.
+    assert_eq!(config.language, Config::default().language);
+}
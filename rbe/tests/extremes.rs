@@ -0,0 +1,53 @@
+// The examples run with the extreme values of their flags, as `rbe run` would
+// run them: whatever the user passes, an example prints its output or
+// returns a usage error, it never panics.
+//
+//   cargo test -p rbe --test extremes
+
+use cmdline::Args;
+
+// The output of `rbe run <name> <args>`.
+fn run(name: &str, args: &[&str]) -> Result<String, cmdline::Error> {
+    let registry = rbe::registry();
+    let example = registry.get(name).expect("no such example");
+    let mut out = Vec::new();
+    example.run_args(Args::parse(args.iter().copied())?, &mut out)?;
+    Ok(String::from_utf8(out).expect("the output is not UTF-8"))
+}
+
+#[test]
+fn conversion_reports_overflow() {
+    let out = run("conversion", &["--from", "2147483647"]).unwrap();
+    assert!(out.contains("2147483647 + 5 = overflow, 2147483647 * -(5) = overflow"), "{}", out);
+
+    let out = run("conversion", &["--into", "-2147483648"]).unwrap();
+    assert!(out.contains("30 + -2147483648 = -2147483618, 30 * -(-2147483648) = overflow"), "{}", out);
+
+    for value in ["-2147483648", "2147483647"] {
+        run("conversion", &["--from", value, "--into", value, "--radius", value]).unwrap();
+    }
+}
+
+#[test]
+fn fractals_limits_the_view() {
+    for args in [
+        ["--columns", "4294967296", "--rows", "4294967296"],
+        ["--columns", "18446744073709551615", "--rows", "2"],
+        ["--columns", "4097", "--rows", "4096"],
+    ] {
+        assert!(matches!(run("fractals", &args), Err(cmdline::Error::Usage(_))), "{:?}", args);
+    }
+    assert!(matches!(run("fractals", &["--c", "0.3i"]), Err(cmdline::Error::Usage(_))));
+
+    // One thread per row at most, and never more than `MAX_THREADS`, however
+    // many rows there are.
+    let max = fractals::MAX_THREADS.to_string();
+    let out = run("fractals", &["--columns", "1", "--rows", "1", "--iterations", "100000", "--threads", &max, "--bench"]).unwrap();
+    assert!(out.contains("1 points, 1 in the set, 100000 iterations\n"), "{}", out);
+    assert!(out.contains(" on 1 thread, "), "{}", out);
+    let too_many = (fractals::MAX_THREADS + 1).to_string();
+    for threads in [too_many.as_str(), "20000", "18446744073709551615"] {
+        let result = run("fractals", &["--columns", "1", "--rows", "20000", "--threads", threads]);
+        assert!(matches!(result, Err(cmdline::Error::Usage(_))), "--threads {}", threads);
+    }
+}
@@ -0,0 +1,144 @@
+// Tests of `arraysandslices::Grid` and its views: rows, strided columns,
+// views of views, the neighbors of corner, edge and inner cells, what is out
+// of range, and grids a single row or column wide.
+//
+//   cargo test -p rbe --test grid
+
+use arraysandslices::{Connectivity, Grid};
+
+// A `width` x `height` grid whose cells are `10 * row + column`.
+fn numbered(width: usize, height: usize) -> Grid<usize> {
+    Grid::from_fn(width, height, |row, col| 10 * row + col)
+}
+
+fn column<'a, T: Copy + 'a>(cells: impl Iterator<Item = &'a T>) -> Vec<T> {
+    cells.copied().collect()
+}
+
+// The positions of the neighbors of `(row, col)`.
+fn neighbors(grid: &Grid<usize>, row: usize, col: usize, connectivity: Connectivity) -> Vec<(usize, usize)> {
+    grid.neighbors(row, col, connectivity).map(|(position, _)| position).collect()
+}
+
+#[test]
+fn rows_and_columns() {
+    let grid = numbered(4, 3);
+    assert_eq!(grid.row(0), [0, 1, 2, 3]);
+    assert_eq!(grid.row(2), [20, 21, 22, 23]);
+    assert_eq!(grid.rows().next_back(), Some(&[20, 21, 22, 23][..]));
+
+    // A column steps over the buffer `width` cells at a time, and stops at
+    // the last row.
+    assert_eq!(column(grid.column(0)), [0, 10, 20]);
+    assert_eq!(column(grid.column(3)), [3, 13, 23]);
+    assert_eq!(grid[(1, 2)], 12);
+
+    let mut grid = grid;
+    for cell in grid.column_mut(1) {
+        *cell = 0;
+    }
+    assert_eq!(grid.as_slice(), [0, 0, 2, 3, 10, 0, 12, 13, 20, 0, 22, 23]);
+}
+
+#[test]
+fn views_keep_the_stride_of_the_grid() {
+    let grid = numbered(5, 4);
+    let view = grid.view(1..4, 2..4);
+    assert_eq!((view.width(), view.height()), (2, 3));
+    assert_eq!(view.row(0), [12, 13]);
+    // Rows of the view are still 5 cells apart in the buffer.
+    assert_eq!(column(view.column(1)), [13, 23, 33]);
+    assert_eq!(view.get(2, 0), Some(&32));
+    assert_eq!(view.get(3, 0), None);
+    assert_eq!(view.get(0, 2), None);
+
+    let inner = view.view(1..3, 1..2);
+    assert_eq!(column(inner.column(0)), [23, 33]);
+    assert_eq!(inner.to_string(), "23\n33");
+
+    // Empty views, even at the far corner.
+    assert_eq!(grid.view(4..4, 5..5).rows().count(), 0);
+    assert_eq!(grid.view(0..2, 3..3).row(1), [] as [usize; 0]);
+
+    let mut grid = grid;
+    grid.view_mut(0..2, 3..5).fill(0);
+    assert_eq!(grid.row(1), [10, 11, 12, 0, 0]);
+    assert_eq!(grid.row(2), [20, 21, 22, 23, 24]);
+}
+
+#[test]
+fn neighbors_of_corners_edges_and_inner_cells() {
+    let grid = numbered(4, 3);
+    for (row, col, four, eight) in [(0, 0, 2, 3), (2, 3, 2, 3), (0, 2, 3, 5), (1, 0, 3, 5), (1, 1, 4, 8), (1, 2, 4, 8)] {
+        assert_eq!(neighbors(&grid, row, col, Connectivity::Four).len(), four, "({}, {})", row, col);
+        assert_eq!(neighbors(&grid, row, col, Connectivity::Eight).len(), eight, "({}, {})", row, col);
+    }
+    assert_eq!(neighbors(&grid, 0, 0, Connectivity::Eight), [(0, 1), (1, 0), (1, 1)]);
+    assert_eq!(neighbors(&grid, 2, 3, Connectivity::Four), [(1, 3), (2, 2)]);
+    let cells: Vec<usize> = grid.neighbors(1, 1, Connectivity::Four).map(|(_, &cell)| cell).collect();
+    assert_eq!(cells, [1, 10, 12, 21]);
+
+    // A view's neighbors stop at its own edges, not the grid's.
+    let view = grid.view(1..3, 1..3);
+    assert_eq!(view.neighbors(0, 0, Connectivity::Eight).count(), 3);
+
+    // Outside the grid, only the cells that are inside count.
+    assert_eq!(neighbors(&grid, 3, 4, Connectivity::Eight), [(2, 3)]);
+    assert_eq!(neighbors(&grid, 10, 10, Connectivity::Eight), []);
+}
+
+#[test]
+fn out_of_range() {
+    let mut grid = numbered(3, 2);
+    assert_eq!(grid.get(1, 2), Some(&12));
+    assert_eq!((grid.get(2, 0), grid.get(0, 3)), (None, None));
+    assert_eq!(grid.get_mut(2, 0), None);
+    assert_eq!(grid.get(usize::MAX, usize::MAX), None);
+
+    assert!(Grid::from_vec(3, 2, vec![0; 5]).is_err());
+    assert!(Grid::from_vec(usize::MAX, 2, Vec::<u8>::new()).is_err());
+}
+
+#[test]
+#[should_panic(expected = "row 2 is out of bounds (height 2)")]
+fn row_out_of_range_panics() {
+    numbered(3, 2).row(2);
+}
+
+#[test]
+#[should_panic(expected = "column 3 is out of bounds (width 3)")]
+fn column_out_of_range_panics() {
+    let _ = numbered(3, 2).column(3);
+}
+
+#[test]
+#[should_panic(expected = "are not inside a 3x2 grid")]
+fn view_out_of_range_panics() {
+    numbered(3, 2).view(0..2, 1..4);
+}
+
+#[test]
+#[should_panic(expected = "row 2 is out of bounds (height 2)")]
+fn index_out_of_range_panics() {
+    let _ = numbered(3, 2)[(2, 0)];
+}
+
+#[test]
+fn single_row_and_single_column() {
+    // 1 x 5: one column of 5 rows.
+    let tall = numbered(1, 5);
+    assert_eq!(column(tall.column(0)), [0, 10, 20, 30, 40]);
+    assert_eq!(tall.rows().count(), 5);
+    assert_eq!(tall.row(4), [40]);
+    assert_eq!(neighbors(&tall, 0, 0, Connectivity::Eight), [(1, 0)]);
+    assert_eq!(neighbors(&tall, 2, 0, Connectivity::Eight), [(1, 0), (3, 0)]);
+    assert_eq!(tall.to_string(), " 0\n10\n20\n30\n40");
+
+    // 5 x 1: one row of 5 columns, each column a single cell.
+    let wide = numbered(5, 1);
+    assert_eq!(wide.row(0), [0, 1, 2, 3, 4]);
+    assert_eq!(column(wide.column(4)), [4]);
+    assert_eq!(neighbors(&wide, 0, 0, Connectivity::Four), [(0, 1)]);
+    assert_eq!(neighbors(&wide, 0, 2, Connectivity::Eight), [(0, 1), (0, 3)]);
+    assert_eq!(wide.view(0..1, 1..4).to_string(), "1 2 3");
+}
@@ -0,0 +1,113 @@
+// Tests of the layout table: every public struct and enum of the examples
+// and of `codec` has a line in `rbe::layout::all()`, so that a type added to
+// a crate without its `layout_report!` fails here.
+//
+//   cargo test -p rbe --test layout
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// The public types without a line, and why. `*` is any crate.
+const SKIPPED: &[(&str, &str, &str)] = &[
+    ("*", "Params", "the flags of an example"),
+    ("arraysandslices", "IntoIter", "an iterator"),
+    ("constants", "Stream", "an iterator"),
+    ("enumandtypealias", "VeryVerboseEnumOfThingsToDoWithNumbers", "listed as its alias `Operations`"),
+    ("types", "Value", "in the private `mod sealed` of `types::cast`"),
+    ("types", "Outcome", "in the private `mod sealed` of `types::cast`"),
+    ("types", "Length", "a dimension, an empty enum"),
+    ("types", "Time", "a dimension, an empty enum"),
+    ("types", "Mass", "a dimension, an empty enum"),
+    ("types", "Velocity", "a dimension, an empty enum"),
+];
+
+fn skipped(krate: &str, ty: &str) -> bool {
+    SKIPPED.iter().any(|&(k, t, _)| (k == "*" || k == krate) && t == ty)
+}
+
+fn sources(dir: &Path, files: &mut Vec<PathBuf>) {
+    for entry in fs::read_dir(dir).unwrap_or_else(|err| panic!("{}: {}", dir.display(), err)) {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            sources(&path, files);
+        } else if path.extension().is_some_and(|extension| extension == "rs") {
+            files.push(path);
+        }
+    }
+}
+
+// The names of the `pub struct`s and `pub enum`s of a crate. Those a macro
+// defines, `pub struct $name`, can't be found this way.
+fn public_types(krate: &str) -> Vec<String> {
+    let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join(krate).join("src");
+    let mut files = Vec::new();
+    sources(&src, &mut files);
+
+    let mut types = Vec::new();
+    for file in files {
+        let text = fs::read_to_string(&file).unwrap();
+        for line in text.lines() {
+            let line = line.trim_start();
+            let Some(rest) = line.strip_prefix("pub struct ").or_else(|| line.strip_prefix("pub enum ")) else {
+                continue;
+            };
+            let name: String = rest.chars().take_while(|c| c.is_alphanumeric() || *c == '_').collect();
+            if !name.is_empty() {
+                types.push(name);
+            }
+        }
+    }
+    types
+}
+
+// The crate and type of each line of the table, `crate::module::Type<...>`
+// giving `("crate", "Type")`. Standard types have no crate.
+fn listed() -> Vec<(String, String)> {
+    rbe::layout::all()
+        .iter()
+        .filter_map(|layout| {
+            let name: String = layout.name.chars().filter(|c| !c.is_whitespace()).collect();
+            let path = name.split('<').next().unwrap();
+            let (krate, _) = path.split_once("::")?;
+            let ty = path.rsplit("::").next().unwrap();
+            Some((krate.to_string(), ty.to_string()))
+        })
+        .collect()
+}
+
+#[test]
+fn every_public_type_is_listed() {
+    let listed = listed();
+    let mut crates: Vec<&str> = rbe::registry().iter().map(|example| example.name()).collect();
+    crates.push("codec");
+
+    let mut missing = Vec::new();
+    for krate in crates {
+        for ty in public_types(krate) {
+            if !skipped(krate, &ty) && !listed.iter().any(|(k, t)| k == krate && *t == ty) {
+                missing.push(format!("{}::{}", krate, ty));
+            }
+        }
+    }
+    assert!(missing.is_empty(), "not in `rbe::layout::all()`: {}", missing.join(", "));
+}
+
+#[test]
+fn skipped_types_still_exist() {
+    for &(krate, ty, reason) in SKIPPED.iter().filter(|&&(krate, _, _)| krate != "*") {
+        assert!(public_types(krate).iter().any(|name| name == ty), "{}::{} is skipped ({}) but gone", krate, ty, reason);
+    }
+}
+
+#[test]
+fn padding_is_every_byte_outside_the_fields() {
+    for layout in rbe::layout::all() {
+        let Some(fields) = &layout.fields else { continue };
+        let mut used = vec![false; layout.size];
+        for field in fields {
+            used[field.offset..field.offset + field.size].iter_mut().for_each(|byte| *byte = true);
+        }
+        let unused = used.iter().filter(|&&byte| !byte).count();
+        assert_eq!(layout.padding(), Some(unused), "{}", layout.name);
+    }
+}
@@ -0,0 +1,146 @@
+// Tests of `literalsandoperators::parse_int_literal`: every kind of error, at
+// the byte offset of the character it is about, and the literals written with
+// a radix prefix read back as the values they were written from.
+//
+//   cargo test -p rbe --test literals
+
+use literalsandoperators::{parse_int_literal, IntLiteral, IntType, LiteralErrorKind};
+
+mod common;
+
+use common::{seed, Rng};
+
+// The offset and kind of the error `text` gives.
+fn error(text: &str) -> (usize, LiteralErrorKind) {
+    let err = parse_int_literal(text).expect_err(text);
+    (err.offset, err.kind)
+}
+
+fn invalid_digit(digit: char, radix: u32) -> LiteralErrorKind {
+    LiteralErrorKind::InvalidDigit { digit, radix }
+}
+
+fn invalid_suffix(suffix: &str) -> LiteralErrorKind {
+    LiteralErrorKind::InvalidSuffix(suffix.to_string())
+}
+
+#[test]
+fn no_digits() {
+    assert_eq!(error(""), (0, LiteralErrorKind::ExpectedDigit));
+    assert_eq!(error("-"), (1, LiteralErrorKind::ExpectedDigit));
+    assert_eq!(error("u8"), (0, LiteralErrorKind::ExpectedDigit));
+    // A prefix and nothing after it, or only underscores.
+    assert_eq!(error("0x"), (2, LiteralErrorKind::ExpectedDigit));
+    assert_eq!(error("-0o"), (3, LiteralErrorKind::ExpectedDigit));
+    assert_eq!(error("0b__"), (4, LiteralErrorKind::ExpectedDigit));
+    assert_eq!(error("0xu8"), (2, LiteralErrorKind::ExpectedDigit));
+}
+
+#[test]
+fn invalid_digits() {
+    assert_eq!(error("0b1021"), (4, invalid_digit('2', 2)));
+    assert_eq!(error("-0b1_3"), (5, invalid_digit('3', 2)));
+    assert_eq!(error("0o178"), (4, invalid_digit('8', 8)));
+    // The first bad digit is reported, not the last.
+    assert_eq!(error("0o98"), (2, invalid_digit('9', 8)));
+    assert_eq!(error("0o7_9_9"), (4, invalid_digit('9', 8)));
+
+    // Letters are digits only in base 16: elsewhere they start the suffix.
+    assert_eq!(error("12ab"), (2, invalid_suffix("ab")));
+    assert_eq!(error("0xfg"), (3, invalid_suffix("g")));
+    assert_eq!(error("0B11"), (1, invalid_suffix("B11")));
+}
+
+#[test]
+fn misplaced_underscores() {
+    assert_eq!(error("_1"), (0, LiteralErrorKind::LeadingUnderscore));
+    assert_eq!(error("-_1"), (1, LiteralErrorKind::LeadingUnderscore));
+    assert_eq!(error("_0x1"), (0, LiteralErrorKind::LeadingUnderscore));
+    // The prefix can't be split either.
+    assert_eq!(error("0_x1"), (2, invalid_suffix("x1")));
+    assert_eq!(error("1u_8"), (1, invalid_suffix("u_8")));
+
+    // Anywhere after the first digit or the prefix, underscores are fine,
+    // but not after the suffix.
+    for text in ["1_000", "1__0", "0x_ff", "0b_1", "1_u8", "1_000_u32"] {
+        assert!(parse_int_literal(text).is_ok(), "{}", text);
+    }
+    assert_eq!(error("1_000_u32_"), (6, invalid_suffix("u32_")));
+}
+
+#[test]
+fn unknown_suffixes() {
+    assert_eq!(error("1i7"), (1, invalid_suffix("i7")));
+    assert_eq!(error("1U8"), (1, invalid_suffix("U8")));
+    assert_eq!(error("255u8 "), (3, invalid_suffix("u8 ")));
+    assert_eq!(error("1f32"), (1, invalid_suffix("f32")));
+    assert_eq!(error("-0x1_i1280"), (5, invalid_suffix("i1280")));
+}
+
+#[test]
+fn out_of_range() {
+    // The offset is the digit taking the value past the type.
+    assert_eq!(error("256u8"), (2, LiteralErrorKind::OutOfRange(IntType::U8)));
+    assert_eq!(error("128i8"), (2, LiteralErrorKind::OutOfRange(IntType::I8)));
+    assert_eq!(error("-129i8"), (3, LiteralErrorKind::OutOfRange(IntType::I8)));
+    assert_eq!(error("0x100u8"), (4, LiteralErrorKind::OutOfRange(IntType::U8)));
+    assert_eq!(error("0b1_0000_0000u8"), (12, LiteralErrorKind::OutOfRange(IntType::U8)));
+    assert_eq!(error("1000000u8"), (3, LiteralErrorKind::OutOfRange(IntType::U8)));
+    assert_eq!(error("-1u8"), (0, LiteralErrorKind::NegativeUnsigned(IntType::U8)));
+    assert_eq!(error("-0x0u8"), (0, LiteralErrorKind::NegativeUnsigned(IntType::U8)));
+
+    // Just in range.
+    for text in ["255u8", "-128i8", "127i8", "0xffu8", "-0x80i8", "0u8", "-0i8"] {
+        assert!(parse_int_literal(text).is_ok(), "{}", text);
+    }
+    let max = u128::MAX.to_string();
+    assert_eq!(parse_int_literal(&max).unwrap().magnitude, u128::MAX);
+    assert_eq!(parse_int_literal(&format!("-{}i128", 1u128 << 127)).unwrap().to_i128(), Some(i128::MIN));
+    assert_eq!(error(&format!("{}i128", 1u128 << 127)), (38, LiteralErrorKind::OutOfRange(IntType::I128)));
+
+    // Without a suffix, only past `u128::MAX`: 2^128 is 39 digits long.
+    assert_eq!(error("340282366920938463463374607431768211456"), (38, LiteralErrorKind::TooLarge));
+    let two_pow_128 = format!("0x1{}", "0".repeat(32));
+    assert_eq!(error(&two_pow_128), (34, LiteralErrorKind::TooLarge));
+}
+
+// The value written in base 2, 8 and 16 with the prefix of each, and in
+// base 10 by `Display`, reads back as itself.
+fn check_prefixes(negative: bool, magnitude: u128, suffix: Option<IntType>) {
+    let sign = if negative { "-" } else { "" };
+    let name = suffix.map_or("", IntType::name);
+    for (text, radix) in [
+        (format!("{}{:#b}{}", sign, magnitude, name), 2),
+        (format!("{}{:#o}{}", sign, magnitude, name), 8),
+        (format!("{}{:#x}{}", sign, magnitude, name), 16),
+        (format!("{}{}{}", sign, magnitude, name), 10),
+    ] {
+        let literal = parse_int_literal(&text).unwrap_or_else(|err| panic!("{:?}: {}", text, err));
+        assert_eq!(literal, IntLiteral { negative, magnitude, radix, suffix }, "{:?}", text);
+        let back: IntLiteral = literal.to_string().parse().unwrap();
+        assert_eq!((back.negative && back.magnitude != 0, back.magnitude, back.suffix), (negative && magnitude != 0, magnitude, suffix), "{:?}", text);
+    }
+}
+
+#[test]
+fn prefixes_round_trip() {
+    check_prefixes(false, 0, None);
+    check_prefixes(false, u128::MAX, None);
+    check_prefixes(false, u128::MAX, Some(IntType::U128));
+    check_prefixes(true, 1 << 127, Some(IntType::I128));
+    check_prefixes(false, 255, Some(IntType::U8));
+    check_prefixes(true, 128, Some(IntType::I8));
+
+    let mut rng = Rng::new(seed());
+    for _ in 0..500 {
+        let ty = rng.pick(&IntType::ALL);
+        let negative = ty.is_signed() && rng.range(0, 1) == 1;
+        // Any number of bits up to one less than the type has, which fits
+        // it whatever the sign.
+        let bits = rng.range(0, ty.bits() as i64 - 1) as u32;
+        let random = (rng.next_u64() as u128) << 64 | rng.next_u64() as u128;
+        let magnitude = random.checked_shr(128 - bits).unwrap_or(0);
+        let suffix = Some(ty).filter(|_| rng.range(0, 1) == 1);
+        check_prefixes(negative, magnitude, suffix);
+    }
+}
@@ -0,0 +1,146 @@
+// Tests of `arraysandslices::RingBuffer`: both overflow modes when full, the
+// two halves of `as_slices` once the elements wrap around the end of the
+// array, the order they are iterated in, and that every element pushed is
+// dropped exactly once, overwritten ones included.
+//
+//   cargo test -p rbe --test ringbuffer
+
+use std::rc::Rc;
+
+use arraysandslices::{CapacityError, Overflow, RingBuffer};
+
+// A buffer of `N` holding `values`, pushed in order.
+fn filled<const N: usize>(overflow: Overflow, values: impl IntoIterator<Item = i32>) -> RingBuffer<i32, N> {
+    let mut buffer = RingBuffer::new(overflow);
+    for x in values {
+        let _ = buffer.push_back(x);
+    }
+    buffer
+}
+
+#[test]
+fn overwrite_when_full() {
+    let mut buffer: RingBuffer<i32, 3> = filled(Overflow::Overwrite, 1..=3);
+    assert_eq!(buffer.push_back(4), Ok(Some(1)));
+    assert_eq!(buffer.push_back(5), Ok(Some(2)));
+    assert_eq!(buffer.iter().copied().collect::<Vec<_>>(), [3, 4, 5]);
+    assert_eq!((buffer.front(), buffer.back(), buffer.get(3)), (Some(&3), Some(&5), None));
+
+    // Room again after a pop: nothing is overwritten.
+    assert_eq!(buffer.pop_front(), Some(3));
+    assert_eq!(buffer.push_back(6), Ok(None));
+    assert_eq!(buffer.push_back(7), Ok(Some(4)));
+
+    // A buffer with no room drops what comes in.
+    let mut empty: RingBuffer<i32, 0> = RingBuffer::new(Overflow::Overwrite);
+    assert_eq!(empty.push_back(1), Ok(Some(1)));
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn reject_when_full() {
+    let mut buffer: RingBuffer<i32, 3> = filled(Overflow::Reject, 1..=3);
+    assert_eq!(buffer.push_back(4), Err(CapacityError(4)));
+    assert_eq!(buffer.iter().copied().collect::<Vec<_>>(), [1, 2, 3]);
+
+    assert_eq!(buffer.pop_front(), Some(1));
+    assert_eq!(buffer.push_back(4), Ok(None));
+    assert_eq!(buffer.push_back(5), Err(CapacityError(5)));
+    assert_eq!(buffer.iter().copied().collect::<Vec<_>>(), [2, 3, 4]);
+
+    let mut empty: RingBuffer<i32, 0> = RingBuffer::new(Overflow::Reject);
+    assert_eq!(empty.push_back(1), Err(CapacityError(1)));
+}
+
+#[test]
+fn slices_after_wrapping() {
+    // 1 to 7 through a buffer of 5: 3 to 7 are left, and the head is at
+    // slot 2, so 6 and 7 have wrapped around to slots 0 and 1.
+    let mut buffer: RingBuffer<i32, 5> = filled(Overflow::Overwrite, 1..=7);
+    assert_eq!(buffer.as_slices(), (&[3, 4, 5][..], &[6, 7][..]));
+    assert_eq!(buffer.iter().copied().collect::<Vec<_>>(), [3, 4, 5, 6, 7]);
+    assert_eq!(buffer.iter().rev().copied().collect::<Vec<_>>(), [7, 6, 5, 4, 3]);
+    assert_eq!(buffer.get(3), Some(&6));
+
+    for x in buffer.iter_mut() {
+        *x *= 10;
+    }
+    let (first, second) = buffer.as_mut_slices();
+    assert_eq!((&*first, &*second), (&[30, 40, 50][..], &[60, 70][..]));
+
+    // Popping from the back goes through the wrapped part first.
+    assert_eq!(buffer.pop_back(), Some(70));
+    assert_eq!(buffer.as_slices(), (&[30, 40, 50][..], &[60][..]));
+    assert_eq!(buffer.pop_back(), Some(60));
+    assert_eq!(buffer.as_slices(), (&[30, 40, 50][..], &[][..]));
+
+    // Equal buffers, wherever their elements sit in the array.
+    let buffer: RingBuffer<i32, 5> = filled(Overflow::Overwrite, 1..=7);
+    let mut contiguous = buffer.clone();
+    assert_eq!(contiguous.make_contiguous(), [3, 4, 5, 6, 7]);
+    assert_eq!(contiguous.as_slices(), (&[3, 4, 5, 6, 7][..], &[][..]));
+    assert_eq!(contiguous, buffer);
+    assert_eq!(buffer.into_iter().rev().collect::<Vec<_>>(), [7, 6, 5, 4, 3]);
+}
+
+#[test]
+fn iterates_oldest_first_across_many_wraps() {
+    let mut buffer: RingBuffer<i32, 4> = RingBuffer::new(Overflow::Overwrite);
+    for x in 0..100 {
+        let _ = buffer.push_back(x);
+        let start = (x - 3).max(0);
+        assert_eq!(buffer.iter().copied().collect::<Vec<_>>(), (start..=x).collect::<Vec<_>>());
+    }
+    let mut iter = buffer.into_iter();
+    assert_eq!((iter.next(), iter.next_back(), iter.len()), (Some(96), Some(99), 2));
+    assert_eq!(iter.collect::<Vec<_>>(), [97, 98]);
+}
+
+// As in the `ArrayVec` tests, every element is a clone of `token`, so its
+// strong count is one more than the number of elements not dropped yet.
+#[test]
+fn drops_every_element_once() {
+    let token = Rc::new(());
+    let live = || Rc::strong_count(&token) - 1;
+
+    let mut buffer: RingBuffer<Rc<()>, 4> = RingBuffer::new(Overflow::Overwrite);
+    for _ in 0..4 {
+        buffer.push_back(Rc::clone(&token)).unwrap();
+    }
+    // The overwritten element is handed back, to be dropped by the caller.
+    for _ in 0..3 {
+        let oldest = buffer.push_back(Rc::clone(&token)).unwrap();
+        assert!(oldest.is_some());
+    }
+    assert_eq!(live(), 4);
+
+    drop(buffer.pop_front());
+    drop(buffer.pop_back());
+    assert_eq!(live(), 2);
+    buffer.clear();
+    assert_eq!((buffer.len(), live()), (0, 0));
+
+    // A wrapped buffer drops both of its halves.
+    for _ in 0..6 {
+        let _ = buffer.push_back(Rc::clone(&token));
+    }
+    assert!(!buffer.as_slices().1.is_empty());
+    let clone = buffer.clone();
+    assert_eq!(live(), 8);
+    drop(buffer);
+    assert_eq!(live(), 4);
+
+    let mut iter = clone.into_iter();
+    drop(iter.next());
+    assert_eq!(live(), 3);
+    drop(iter);
+    assert_eq!(live(), 0);
+
+    let mut rejecting: RingBuffer<Rc<()>, 2> = RingBuffer::new(Overflow::Reject);
+    for _ in 0..3 {
+        let _ = rejecting.push_back(Rc::clone(&token));
+    }
+    assert_eq!(live(), 2);
+    drop(rejecting);
+    assert_eq!(live(), 0);
+}
@@ -0,0 +1,115 @@
+// Round-trip tests: for every type whose `FromStr` reads back its `Display`
+// output, `x.to_string().parse()` must give `x` again. The values come from
+// a small seeded generator, so a failure can be replayed: the message gives
+// the seed and the case that failed.
+//
+//   cargo test -p rbe --test roundtrip
+//   ROUNDTRIP_SEED=42 cargo test -p rbe --test roundtrip
+
+use std::fmt::{Debug, Display};
+use std::str::FromStr;
+
+use conversion::Circle;
+use display::{Complex, Point2D};
+use formatting::{City, Color};
+
+mod common;
+
+use common::{seed, Rng};
+
+const CASES: usize = 2000;
+
+// The harness: `CASES` values from `generate`, each printed and parsed back.
+fn check_round_trip<T, F>(generate: F)
+where
+    T: Display + FromStr + PartialEq + Debug,
+    T::Err: Display,
+    F: Fn(&mut Rng) -> T,
+{
+    let seed = seed();
+    let mut rng = Rng::new(seed);
+    for case in 0..CASES {
+        let value = generate(&mut rng);
+        let text = value.to_string();
+        match text.parse::<T>() {
+            Ok(parsed) if parsed == value => {}
+            Ok(parsed) => panic!("seed {}, case {}: {:?} printed as {:?} parsed as {:?}", seed, case, value, text, parsed),
+            Err(err) => panic!("seed {}, case {}: {:?} printed as {:?} failed to parse: {}", seed, case, value, text, err),
+        }
+    }
+}
+
+#[test]
+fn circle() {
+    check_round_trip(|rng| Circle { radius: rng.i32() });
+}
+
+#[test]
+fn point() {
+    check_round_trip(|rng| Point2D { x: rng.f64(), y: rng.f64() });
+}
+
+#[test]
+fn complex() {
+    check_round_trip(|rng| Complex { real: rng.f64(), imag: rng.f64() });
+}
+
+// `City` prints three decimals: only thousandths survive the trip.
+#[test]
+fn city() {
+    check_round_trip(|rng| City {
+        name: rng.string(),
+        lat: rng.range(-90_000, 90_000) as f32 / 1000.0,
+        lon: rng.range(-180_000, 180_000) as f32 / 1000.0,
+    });
+}
+
+#[test]
+fn color() {
+    check_round_trip(|rng| Color { red: rng.u8(), green: rng.u8(), blue: rng.u8() });
+}
+
+// `FromStr` is no more lenient than `Display`.
+#[test]
+fn rejects_what_display_never_writes() {
+    for text in ["Circle of radius", "circle of radius 6", "Circle of radius +6", "Circle of radius 6 ", "Circle of radius 007", "Circle of radius -0"] {
+        assert!(text.parse::<Circle>().is_err(), "{:?}", text);
+    }
+    for text in ["x: 1, y: 2", "x: 1, y; ", "1, 2", " x: 1, y; 2"] {
+        assert!(text.parse::<Point2D>().is_err(), "{:?}", text);
+    }
+    for text in ["1 + 2", "1 + -2i", "1 - -2i", "2i + 1", "1 + 2j", "1 + 2 - 3i", "-2∠45°"] {
+        assert!(text.parse::<Complex>().is_err(), "{:?}", text);
+    }
+    for text in ["Oslo 59.950°N 10.750°E", "Oslo: -59.950°N 10.750°E", "Oslo: 59.950°E 10.750°N", "Oslo: 59.950°N"] {
+        assert!(text.parse::<City>().is_err(), "{:?}", text);
+    }
+    // Numbers for the right coordinates, written otherwise than with three
+    // decimals, and a zero with the negative direction.
+    for text in ["Oslo: 059.950°N 10.750°E", "Oslo: 59.95°N 10.750°E", "Oslo: 59.9500°N 10.750°E", "Oslo: 5.995e1°N 10.750°E", "Null: 0.000°S 0.000°E", "Null: 0.000°N 0.000°W"] {
+        assert!(text.parse::<City>().is_err(), "{:?}", text);
+    }
+    for text in ["RGB (1,2,3) 0x010203 ", "RGB (1,2) 0x0102", "RGB (1,2,3,4) 0x01020304", "RGB (1,2,3) 0x010204", "RGB (256,0,0) 0x1000000", "RGB (007,0,0) 0x070000", "RGB (1,02,3) 0x010203"] {
+        assert!(text.parse::<Color>().is_err(), "{:?}", text);
+    }
+}
+
+// `Complex` also reads the shorter forms a user would type.
+#[test]
+fn complex_reads_user_input() {
+    for (text, real, imag) in [
+        ("3", 3.0, 0.0),
+        ("-2i", 0.0, -2.0),
+        ("i", 0.0, 1.0),
+        ("3.3+7.2i", 3.3, 7.2),
+        ("1e-3-2.5e2i", 1e-3, -2.5e2),
+        (" 2∠90° ", 0.0, 2.0),
+    ] {
+        let parsed: Complex = text.parse().unwrap_or_else(|err| panic!("{:?}: {}", text, err));
+        assert!(parsed.approx_eq(Complex::new(real, imag), 1e-12), "{:?} parsed as {}", text, parsed);
+    }
+    for (text, offset) in [("", 0), ("3 + 4", 5), ("1 - -2i", 4), ("2i + 3", 3), ("1e", 2), ("2∠45°x", 8)] {
+        let err = text.parse::<Complex>().expect_err(text);
+        assert_eq!(err.offset, offset, "{:?}: {}", text, err);
+    }
+}
@@ -0,0 +1,26 @@
+// Tests of what `rbe show` prints: the source of an example is every file of
+// its crate, not only `lib.rs`, so that a module added to a crate without
+// being listed in its `example!` fails here.
+//
+//   cargo test -p rbe --test show
+
+use std::fs;
+use std::path::Path;
+
+#[test]
+fn every_module_is_shown() {
+    for example in rbe::registry().iter() {
+        let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join(example.name()).join("src");
+        let source = example.source();
+        for entry in fs::read_dir(&src).unwrap_or_else(|err| panic!("{}: {}", src.display(), err)) {
+            let path = entry.unwrap().path();
+            // The thin binary running the example is not part of it.
+            let file = path.file_name().unwrap().to_str().unwrap();
+            if file == "main.rs" {
+                continue;
+            }
+            let text = fs::read_to_string(&path).unwrap();
+            assert!(source.contains(&text), "`rbe show {}` leaves out {}", example.name(), path.display());
+        }
+    }
+}
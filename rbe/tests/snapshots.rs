@@ -0,0 +1,122 @@
+// Golden-output tests: every registered example is run into a buffer and its
+// output is compared with `tests/snapshots/<name>.expected`. What `rbe show`
+// prints of the examples in `SHOWN` is compared with `show-<name>.expected`,
+// so that a module missing from their source is noticed.
+//
+//   cargo test -p rbe --test snapshots                 check every example
+//   cargo test -p rbe --test snapshots -- tuples       check only `tuples`
+//   cargo test -p rbe --test snapshots -- --update     regenerate the files
+
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use rbe::Example;
+
+// The examples split into modules, whose source is snapshotted too.
+const SHOWN: &[&str] = &["arraysandslices"];
+
+fn main() -> ExitCode {
+    let mut update = false;
+    let mut filters = Vec::new();
+
+    // cargo may forward libtest flags such as `--nocapture`: ignore them.
+    for arg in env::args().skip(1) {
+        if arg == "--update" {
+            update = true;
+        } else if !arg.starts_with('-') {
+            filters.push(arg);
+        }
+    }
+
+    let registry = rbe::registry();
+    let mut failures = 0;
+    let mut checked = 0;
+
+    let outputs = registry.iter().map(|example| (example.name().to_string(), capture(example)));
+    let sources = SHOWN.iter().map(|name| {
+        let example = registry.get(name).unwrap_or_else(|| panic!("no example `{}`", name));
+        (format!("show-{}", name), Ok(source(example)))
+    });
+
+    for (name, actual) in outputs.chain(sources) {
+        if !filters.is_empty() && !filters.iter().any(|f| name.contains(f.as_str())) {
+            continue;
+        }
+        checked += 1;
+
+        let actual = actual.map_err(|err| format!("  running failed: {}", err));
+        let result = actual.and_then(|actual| if update { update_snapshot(&name, &actual) } else { check_snapshot(&name, &actual) });
+        match result {
+            Ok(()) => println!("snapshot {} ... ok", name),
+            Err(message) => {
+                println!("snapshot {} ... FAILED\n{}", name, message);
+                failures += 1;
+            }
+        }
+    }
+
+    println!();
+    println!("{} snapshots checked, {} failed", checked, failures);
+
+    if failures == 0 {
+        ExitCode::SUCCESS
+    } else {
+        println!("run `cargo test -p rbe --test snapshots -- --update` if the new output is expected");
+        ExitCode::FAILURE
+    }
+}
+
+fn snapshot_path(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("snapshots")
+        .join(format!("{}.expected", name))
+}
+
+fn capture(example: &dyn Example) -> io::Result<String> {
+    let mut out = Vec::new();
+    example.run(&mut out)?;
+    String::from_utf8(out).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+}
+
+// What `rbe show` prints.
+fn source(example: &dyn Example) -> String {
+    format!("// {}: {}\n\n{}", example.name(), example.summary(), example.source())
+}
+
+fn update_snapshot(name: &str, actual: &str) -> Result<(), String> {
+    let path = snapshot_path(name);
+    fs::write(&path, actual).map_err(|err| format!("  cannot write {}: {}", path.display(), err))
+}
+
+fn check_snapshot(name: &str, actual: &str) -> Result<(), String> {
+    let path = snapshot_path(name);
+    let expected = fs::read_to_string(&path)
+        .map_err(|err| format!("  cannot read {}: {}", path.display(), err))?;
+
+    if actual == expected {
+        return Ok(());
+    }
+
+    // Point at the first line that differs, that's usually all it takes.
+    let mut expected_lines = expected.lines();
+    let mut actual_lines = actual.lines();
+    let mut line = 1;
+    loop {
+        match (expected_lines.next(), actual_lines.next()) {
+            (Some(e), Some(a)) if e == a => line += 1,
+            (None, None) => return Err("  outputs only differ in their line endings".to_string()),
+            (e, a) => {
+                return Err(format!(
+                    "  first difference at line {}:\n  expected: {}\n  actual:   {}",
+                    line,
+                    e.unwrap_or("<end of output>"),
+                    a.unwrap_or("<end of output>"),
+                ));
+            }
+        }
+    }
+}
@@ -0,0 +1,52 @@
+// Tests of the arithmetic of `types::units` across dimensions: a length
+// divided by a time is a velocity, in the units of both, and a velocity times
+// a time is a length again. What must not compile is checked by the
+// compile-fail tests of the `types` crate.
+//
+//   cargo test -p rbe --test units
+
+use types::units::{Foot, Hour, Kilometer, KilometersPerHour, Length, Meter, MetersPerSecond, Mile, MilesPerHour, Minute, Per, Quantity, Second, Unit, Velocity};
+
+// Compiles only for a velocity, so every call checks the dimension too.
+fn meters_per_second<U: Unit<Dimension = Velocity>>(velocity: Quantity<U>) -> f64 {
+    velocity.to::<MetersPerSecond>().value()
+}
+
+fn meters<U: Unit<Dimension = Length>>(length: Quantity<U>) -> f64 {
+    length.to::<Meter>().value()
+}
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() <= 1e-9 * a.abs().max(b.abs())
+}
+
+#[test]
+fn length_per_time_is_a_velocity() {
+    let velocity: Quantity<Per<Meter, Second>> = Quantity::<Meter>::new(100.0) / Quantity::<Second>::new(8.0);
+    assert_eq!(velocity, Quantity::<MetersPerSecond>::new(12.5));
+    assert_eq!(meters_per_second(velocity), 12.5);
+    assert_eq!(velocity.to_string(), "12.5 m/s");
+
+    let velocity: Quantity<KilometersPerHour> = Quantity::<Kilometer>::new(42.195) / Quantity::<Hour>::new(2.0);
+    assert_eq!(format!("{:.4}", velocity), "21.0975 km/h");
+    assert!(close(meters_per_second(velocity), 42195.0 / 7200.0));
+
+    // The same velocity in any pair of units.
+    let mph: Quantity<MilesPerHour> = velocity.to();
+    assert!(close(meters_per_second(mph), meters_per_second(velocity)));
+    let feet_per_minute = Quantity::<Foot>::new(5280.0) / Quantity::<Minute>::new(60.0);
+    assert!(close(feet_per_minute.to::<MilesPerHour>().value(), 1.0));
+    assert_eq!(feet_per_minute.to_string(), "88 ft/min");
+}
+
+#[test]
+fn velocity_times_time_is_a_length() {
+    let velocity = Quantity::<Mile>::new(60.0) / Quantity::<Hour>::new(1.0);
+    let length: Quantity<Mile> = velocity * Quantity::<Hour>::new(2.5);
+    assert_eq!(length, Quantity::<Mile>::new(150.0));
+    assert!(close(meters(length), 150.0 * 1609.344));
+
+    // Only in the time unit of the velocity: another one is converted first.
+    let length = velocity * Quantity::<Minute>::new(90.0).to::<Hour>();
+    assert_eq!(length.to_string(), "90 mi");
+}
@@ -0,0 +1,178 @@
+// There are three types of structures `struct` that can be created
+// using the `struct` keyword:
+// 1) Tuple structs, which are baseically named tuples
+// 2) The classic C structs
+// 3) Unit structs, which are field-less and are useful for generics.
+
+// An attribute to hide warnings for unused code.
+#![allow(dead_code)]
+
+use std::io::{self, Write};
+
+use cmdline::Args;
+use codec::{Decode, DecodeError, Decoder, Encode, Encoder};
+
+#[derive(Debug, PartialEq)]
+pub struct Person {
+    pub name: String,
+    pub age: u8,
+}
+
+// In binary, a person is their name then their age.
+impl Encode for Person {
+    fn encode(&self, encoder: &mut Encoder) {
+        self.name.encode(encoder);
+        self.age.encode(encoder);
+    }
+}
+
+impl Decode for Person {
+    fn decode(decoder: &mut Decoder) -> Result<Person, DecodeError> {
+        Ok(Person { name: String::decode(decoder)?, age: u8::decode(decoder)? })
+    }
+}
+
+// A unit struct
+pub struct Unit;
+
+// A tuple struct
+pub struct Pair(pub i32, pub f32);
+
+#[derive(Debug)]
+// A tuple struct with two fields
+pub struct Point {
+    pub x: f32,
+    pub y: f32,
+}
+
+#[derive(Debug)]
+// Structs can be reused as fields of another struct
+pub struct Rectangle {
+    // A rectangle can be specified by where the top left and bottom right
+    // corners are in space.
+    pub p1: Point,
+    pub p2: Point,
+}
+
+// A function which calculates the area of a `Rectangle`
+pub fn rect_area(out: &mut dyn Write, point_one: Point, point_two: Point) -> io::Result<f32> {
+    let base = (point_one.x - point_two.x).abs();
+    writeln!(out, "base is: {}", base)?;
+    let height = (point_one.y - point_two.y).abs();
+    writeln!(out, "height is: {}", height)?;
+    let area = base * height;
+    writeln!(out, "area is: {}", area)?;
+    Ok(area)
+}
+
+// A better function to calculate the area of a rectangle
+pub fn rectangle_area(rect: Rectangle) -> f32 {
+    let Rectangle {
+        p1: Point {x: x1, y: y1 },
+        p2: Point {x: x2, y: y2},
+    } = rect;
+    ((x1 - x2) * (y1 - y2)).abs()
+}
+
+pub fn square(point: Point, f: f32) -> Rectangle {
+    Rectangle {
+        p1: point,
+        p2: Point {x: f, y:f},
+    }
+}
+
+pub const USAGE: &str = "\
+usage: structures [--name <string>] [--age <u8>]
+
+options:
+    --name <string>    name of the person printed with `{:?}` (default: Peter)
+    --age <u8>         age of the same person (default: 27)
+    -h, --help         print this message";
+
+// Who gets printed.
+pub struct Params {
+    pub name: String,
+    pub age: u8,
+}
+
+impl Default for Params {
+    fn default() -> Params {
+        Params { name: String::from("Peter"), age: 27 }
+    }
+}
+
+impl Params {
+    pub fn from_args(mut args: Args) -> Result<Params, cmdline::Error> {
+        let default = Params::default();
+        let params = Params {
+            name: args.get("name", default.name)?,
+            age: args.get("age", default.age)?,
+        };
+        args.finish()?;
+        Ok(params)
+    }
+}
+
+pub fn run(out: &mut dyn Write) -> io::Result<()> {
+    run_with(out, &Params::default())
+}
+
+pub fn run_args(args: Args, out: &mut dyn Write) -> Result<(), cmdline::Error> {
+    let params = Params::from_args(args)?;
+    Ok(run_with(out, &params)?)
+}
+
+pub fn run_with(out: &mut dyn Write, params: &Params) -> io::Result<()> {
+    // Create struct with field init shorthand
+    let name = params.name.clone();
+    let age = params.age;
+    let peter = Person { name, age };
+
+    // Print debug struct
+    writeln!(out, "{:?}", peter)?;
+
+    // Instantiate a `Point`
+    let point = Point { x: 10.3, y: 0.4 };
+
+    // Access the fields of the point
+    writeln!(out, "point coordinates: {} {}", point.x, point.y)?;
+
+    // Make a new point by using struct update syntax to use the fields of our other one
+    let new_point = Point { x: 5.2, ..point};
+
+    // `new_point.y` will be the same as `point.y` because we used that field from `point`
+    writeln!(out, "second point: ({}, {})", new_point.x, new_point.y)?;
+
+    // Destructure the point using a `let` binding
+    let Point { x: my_x, y: my_y } = point;
+
+    let _rectangle = Rectangle {
+        // struct instantiation is an expression too
+        p1: Point { x: my_x, y: my_y },
+        p2: point,
+    };
+
+    // calculate the rectangle area
+    let area = rectangle_area(_rectangle);
+    writeln!(out, "rectangle area: {}", area)?;
+
+    // execute the square function
+    let _square = square(Point { x: 0.6, y: 0.6 }, 1.2);
+    writeln!(out, "square is: {:?}", _square)?;
+
+    // Instantiate a unit struct
+    let _unit = Unit;
+
+    // Instantiate a tuple struct
+    let pair = Pair(1, 0.1);
+
+    // Access the fields of a tuple struct
+    writeln!(out, "pair contains {:?} and {:?}", pair.0, pair.1)?;
+
+    // Destructure a tuple struct
+    let Pair(integer, decimal) = pair;
+
+    writeln!(out, "pair contains {:?} and {:?}", integer, decimal)?;
+
+    Ok(())
+}
@@ -0,0 +1,83 @@
+// Impelmenting `fmt::Display` for a Structure where the elements
+// must each be handled sequentially is tricky.
+// The problem is that each `write!` generates a `fmt::Result`.
+// Proper handling of this requires dealing with all the results.
+// Rust provides the `?` operator for exactly this purpose.
+
+// Try `write!` to see if it errors. If it errors, return
+// the error. Otherwise continue.
+// write!(f, "{}", value)?;
+
+// With `?` available, implementing `fmt::Display` for a `Vec` is straightforward.
+use std::fmt;
+use std::io::{self, Write};
+
+use cmdline::Args;
+
+// Define a structure named `List` containing a `Vec`.
+pub struct List(pub Vec<i32>);
+
+impl fmt::Display for List {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // extract the value using tuple indexing,
+        // and create a reference to `vec`.
+        let vec = &self.0;
+
+        write!(f, "[")?;
+
+        // Iterate over `v` in `vec` while enumerating the iteration
+        // count in `count`.
+        for (count, v) in vec.iter().enumerate() {
+            // for every element except the first, add a comma.
+            // Use the ? operator to return on errors.
+            if count != 0 { write!(f, ", ")?; }
+            write!(f, "{}: {}", count, v)?;
+        }
+
+        // Close the opened bracket and return a `fmt::Result` value.
+        write!(f, "]")
+    }
+}
+
+pub const USAGE: &str = "\
+usage: testcaselist [--list <i32,...>]
+
+options:
+    --list <i32,...>    the numbers in the list (default: 1,2,3)
+    -h, --help          print this message";
+
+// The numbers wrapped in a `List` and printed.
+pub struct Params {
+    pub list: Vec<i32>,
+}
+
+impl Default for Params {
+    fn default() -> Params {
+        Params { list: vec![1, 2, 3] }
+    }
+}
+
+impl Params {
+    pub fn from_args(mut args: Args) -> Result<Params, cmdline::Error> {
+        let default = Params::default();
+        let params = Params { list: args.get_list("list", default.list)? };
+        args.finish()?;
+        Ok(params)
+    }
+}
+
+pub fn run(out: &mut dyn Write) -> io::Result<()> {
+    run_with(out, &Params::default())
+}
+
+pub fn run_args(args: Args, out: &mut dyn Write) -> Result<(), cmdline::Error> {
+    let params = Params::from_args(args)?;
+    Ok(run_with(out, &params)?)
+}
+
+pub fn run_with(out: &mut dyn Write, params: &Params) -> io::Result<()> {
+    let v = List(params.list.clone());
+    writeln!(out, "{}", v)?;
+
+    Ok(())
+}
@@ -0,0 +1,157 @@
+
+use std::fmt;
+use std::io::{self, Write};
+
+use cmdline::Args;
+use codec::{Decode, DecodeError, Decoder, Encode, Encoder};
+// A tuple is a collection of values of different types.
+// Tuples are constructed using parentheses `()` and each tuple
+// itself is a value with type signature (T1, T2, ...),
+// where T1, T2 are the types of its members.
+// Functions can use tuples to return multiple values, as tuples
+//  can hold any number of values.
+
+// Tuples can be used as function arguments and as return values.
+pub fn reverse(pair: (i32, bool)) -> (bool, i32) {
+    // `let` can be used to bind the members of a tuple to variables
+    let (int_param, bool_param) = pair;
+
+    (bool_param, int_param)
+}
+
+// The following struct is for the activity.
+#[derive(Debug, PartialEq)]
+pub struct Matrix(pub f32, pub f32, pub f32, pub f32);
+
+impl fmt::Display for Matrix {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})\n ({}, {})", self.0, self.1, self.2, self.3)
+    }
+}
+
+// In binary, a matrix is its elements row by row, as a tuple would be.
+impl Encode for Matrix {
+    fn encode(&self, encoder: &mut Encoder) {
+        (self.0, self.1, self.2, self.3).encode(encoder);
+    }
+}
+
+impl Decode for Matrix {
+    fn decode(decoder: &mut Decoder) -> Result<Matrix, DecodeError> {
+        let (a, b, c, d) = Decode::decode(decoder)?;
+        Ok(Matrix(a, b, c, d))
+    }
+}
+
+// This function accepts a matrix as an argument and returns a matrix
+// in which two elements have been swapped.
+pub fn transpose(matrix: Matrix) -> Matrix {
+    // `let` can be used to bind the members of a tuple to variables
+    Matrix(matrix.0, matrix.2, matrix.1, matrix.3)
+}
+
+
+pub const USAGE: &str = "\
+usage: tuples [options]
+
+options:
+    --int <i32>          first member of the pair to reverse (default: 1)
+    --bool <bool>        second member of the pair to reverse (default: true)
+    --matrix <f32,...>   the four elements of the matrix to transpose (default: 1.1,1.2,2.1,2.2)
+    -h, --help           print this message";
+
+// The inputs of the example: the pair handed to `reverse` and the matrix
+// handed to `transpose`.
+pub struct Params {
+    pub pair: (i32, bool),
+    pub matrix: (f32, f32, f32, f32),
+}
+
+impl Default for Params {
+    fn default() -> Params {
+        Params {
+            pair: (1, true),
+            matrix: (1.1, 1.2, 2.1, 2.2),
+        }
+    }
+}
+
+impl Params {
+    pub fn from_args(mut args: Args) -> Result<Params, cmdline::Error> {
+        let default = Params::default();
+        let (a, b, c, d) = default.matrix;
+
+        let pair = (args.get("int", default.pair.0)?, args.get("bool", default.pair.1)?);
+        let matrix = match args.get_list("matrix", vec![a, b, c, d])?.as_slice() {
+            &[a, b, c, d] => (a, b, c, d),
+            other => {
+                return Err(cmdline::Error::Usage(format!(
+                    "`--matrix` takes 4 elements, got {}",
+                    other.len()
+                )))
+            }
+        };
+        args.finish()?;
+
+        Ok(Params { pair, matrix })
+    }
+}
+
+pub fn run(out: &mut dyn Write) -> io::Result<()> {
+    run_with(out, &Params::default())
+}
+
+pub fn run_args(args: Args, out: &mut dyn Write) -> Result<(), cmdline::Error> {
+    let params = Params::from_args(args)?;
+    Ok(run_with(out, &params)?)
+}
+
+pub fn run_with(out: &mut dyn Write, params: &Params) -> io::Result<()> {
+    // A tuple with a bunch of different types
+    let long_tuple = (1u8, 2u16, 3u32, 4u64,
+                     -1i8, -2i16, -3i32, -4i64,
+                    0.1f32, 0.2f64,
+                    'a', true);
+
+    // Values can be extracted from the tuples using tuple indexing
+    writeln!(out, "long tuple first value: {}", long_tuple.0)?;
+    writeln!(out, "long tuple second value: {}", long_tuple.1)?;
+
+    // Tuples can be tuple members
+    let tuple_of_tuples = ((1u8, 2u16, 2u32), (4u64, -1i8), -2i16);
+
+    // Tuples are printable
+    writeln!(out, "tuple of tuples: {:?}", tuple_of_tuples)?;
+
+    // But long tuples (more than 12 elements) cannot be printed
+    // let too_long_tuple = (1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13);
+    // println!("too long tuple: {:?}", too_long_tuple);
+    // TODO ^ uncomment the above 2 lines to see the compiler error
+    // the tuple does not implement std::fmt::Debug is the error message
+
+    let pair = params.pair;
+    writeln!(out, "pair is {:?}", pair)?;
+
+    writeln!(out, "the reversed pair is {:?}", reverse(pair))?;
+
+    // To create one element tuples, the comma is required to tell them apart
+    // from the literal surrounded by parentheses
+    writeln!(out, "one element tuple: {:?}", (5u32,))?;
+    writeln!(out, "just an integer: {:?}", (5u32))?;
+
+    // Tuples can be destructured to create bindings
+    let tuple = (1, "hello", 4.5, true);
+
+    let (a, b, c, d) = tuple;
+    writeln!(out, "{:?}, {:?}, {:?}, {:?}", a, b, c, d)?;
+
+    let (a, b, c, d) = params.matrix;
+    let matrix = Matrix(a, b, c, d);
+    writeln!(out, "Prinring matrix using debug format:\n {:?}", matrix)?; //using debug format `{:?}`
+    writeln!(out, "Printing matrix using display format:\n {}", matrix)?; //using display format `{}`
+
+    writeln!(out, "Matrix:\n{}", matrix)?;
+    writeln!(out, "Matrix transposed:\n{}", transpose(matrix))?; //using transpose
+
+    Ok(())
+}
@@ -0,0 +1,498 @@
+// Casting without surprises. `as` never fails: `1000 as u8` keeps the low
+// bits, 232, `-1i8 as u8` is 255 and `300.0_f32 as u8` is 255. The functions
+// here make the choice explicit, between every pair of numeric primitives:
+//
+//   checked_cast      an error if the value is out of the range of the
+//                     target type, or a NaN going to an integer; fractions
+//                     are dropped and floats rounded, as `as` does
+//   exact_cast        an error for any loss at all, including a dropped
+//                     fraction or a rounded float
+//   saturating_cast   the nearest bound when out of range, 0 for a NaN
+//   wrapping_cast     modulo 2^bits for integer targets, like `as` between
+//                     integers, even from a float (NaN and infinities give 0);
+//                     a float target rounds, overflowing to infinity
+//
+//   let x: u8 = checked_cast(1000i32)?;   // Err: 1000 is too large for u8
+//   let x: u8 = saturating_cast(1000);    // 255
+//   let x: u8 = wrapping_cast(1000);      // 232
+//
+// The error says what would have been lost, with a `Loss`.
+
+use std::fmt;
+use std::io::{self, Write};
+use std::str::FromStr;
+
+use self::sealed::{Outcome, Sealed, Value};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Loss {
+    // Above the largest value of the target type.
+    Overflow,
+    // Below the smallest value of the (signed or float) target type.
+    Underflow,
+    // A negative value for an unsigned type.
+    SignChange,
+    // A float with a fractional part, for an integer type.
+    Fraction,
+    // A value falling between two values of the (float) target type.
+    Precision,
+    // A NaN, for an integer type.
+    NaN,
+}
+
+impl fmt::Display for Loss {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Loss::Overflow => "overflow",
+            Loss::Underflow => "underflow",
+            Loss::SignChange => "sign change",
+            Loss::Fraction => "fraction",
+            Loss::Precision => "precision",
+            Loss::NaN => "NaN",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CastError {
+    pub loss: Loss,
+    // The value, as `Display` prints it, and the names of both types.
+    pub value: String,
+    pub from: &'static str,
+    pub to: &'static str,
+}
+
+impl fmt::Display for CastError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let CastError { value, from, to, .. } = self;
+        match self.loss {
+            Loss::Overflow => write!(f, "{} {} is too large for {}", from, value, to),
+            Loss::Underflow => write!(f, "{} {} is too small for {}", from, value, to),
+            Loss::SignChange => write!(f, "{} {} is negative, and {} is unsigned", from, value, to),
+            Loss::Fraction => write!(f, "{} {} has a fractional part, and {} has none", from, value, to),
+            Loss::Precision => write!(f, "{} {} has no exact {} representation", from, value, to),
+            Loss::NaN => write!(f, "{} NaN has no {} value", from, to),
+        }
+    }
+}
+
+impl std::error::Error for CastError {}
+
+// The numeric primitives: every integer type, `f32` and `f64`.
+pub trait Primitive: Copy + fmt::Display + Sealed {
+    const NAME: &'static str;
+    const IS_FLOAT: bool;
+}
+
+// The integer types.
+pub trait Integer: Primitive {}
+
+// The float types, with a lossless way to `f64`.
+pub trait Float: Primitive {
+    fn to_f64(self) -> f64;
+}
+
+// What converting takes is hidden here: outside this module, `Primitive` can
+// be named, not implemented.
+mod sealed {
+    use super::Loss;
+
+    // Any primitive value, without loss: an integer as a sign and a
+    // magnitude, a float widened to `f64`.
+    #[derive(Clone, Copy)]
+    pub enum Value {
+        Int { negative: bool, magnitude: u128 },
+        Float(f64),
+    }
+
+    // Everything the four casts need to know about a value in a given type.
+    // The saturated and the wrapped results only differ when `range` is set.
+    pub struct Outcome<T> {
+        pub saturated: T,
+        pub wrapped: T,
+        // Why the value doesn't fit the range of the type.
+        pub range: Option<Loss>,
+        // Why the value in range still isn't exact.
+        pub rounding: Option<Loss>,
+    }
+
+    impl<T> Outcome<T> {
+        pub fn map<U>(self, f: impl Fn(T) -> U) -> Outcome<U> {
+            Outcome { saturated: f(self.saturated), wrapped: f(self.wrapped), range: self.range, rounding: self.rounding }
+        }
+    }
+
+    pub trait Sealed: Sized {
+        fn to_value(self) -> Value;
+        fn outcome(value: Value) -> Outcome<Self>;
+    }
+}
+
+fn cast_error<T: Primitive, U: Primitive>(value: T, loss: Loss) -> CastError {
+    CastError { loss, value: value.to_string(), from: T::NAME, to: U::NAME }
+}
+
+pub fn checked_cast<T: Primitive, U: Primitive>(value: T) -> Result<U, CastError> {
+    let outcome = U::outcome(value.to_value());
+    match outcome.range {
+        Some(loss) => Err(cast_error::<T, U>(value, loss)),
+        None => Ok(outcome.saturated),
+    }
+}
+
+pub fn exact_cast<T: Primitive, U: Primitive>(value: T) -> Result<U, CastError> {
+    let outcome = U::outcome(value.to_value());
+    match outcome.range.or(outcome.rounding) {
+        Some(loss) => Err(cast_error::<T, U>(value, loss)),
+        None => Ok(outcome.saturated),
+    }
+}
+
+pub fn saturating_cast<T: Primitive, U: Primitive>(value: T) -> U {
+    U::outcome(value.to_value()).saturated
+}
+
+pub fn wrapping_cast<T: Primitive, U: Primitive>(value: T) -> U {
+    U::outcome(value.to_value()).wrapped
+}
+
+// What `as` gives: it saturates from a float to an integer, and wraps
+// everywhere else.
+pub fn as_cast<T: Primitive, U: Primitive>(value: T) -> U {
+    let outcome = U::outcome(value.to_value());
+    match value.to_value() {
+        Value::Float(_) if !U::IS_FLOAT => outcome.saturated,
+        _ => outcome.wrapped,
+    }
+}
+
+// 2^n, exactly.
+fn pow2(n: u32) -> f64 {
+    2f64.powi(n as i32)
+}
+
+// The two's complement of `-magnitude` if `negative`, over 128 bits: `as`
+// keeps the low bits, so the narrower integer types keep their own.
+fn twos_complement(negative: bool, magnitude: u128) -> u128 {
+    if negative {
+        magnitude.wrapping_neg()
+    } else {
+        magnitude
+    }
+}
+
+// The outcome for an integer type of `bits` bits, as 128 bit patterns.
+fn int_outcome(value: Value, bits: u32, signed: bool) -> Outcome<u128> {
+    let value_bits = if signed { bits - 1 } else { bits };
+    let max = u128::MAX >> (128 - value_bits);
+    // The magnitude of the smallest value.
+    let min_magnitude = if signed { 1u128 << (bits - 1) } else { 0 };
+    let min = twos_complement(true, min_magnitude);
+
+    let (negative, magnitude, wrapped, rounding) = match value {
+        Value::Int { negative, magnitude } => {
+            (negative && magnitude > 0, Some(magnitude), twos_complement(negative, magnitude), None)
+        }
+        Value::Float(x) if x.is_nan() => {
+            return Outcome { saturated: 0, wrapped: 0, range: Some(Loss::NaN), rounding: None };
+        }
+        Value::Float(x) => {
+            let whole = x.trunc();
+            let negative = whole < 0.0;
+            let rounding = (whole != x).then_some(Loss::Fraction);
+
+            // Out of range is decided on the float itself: `magnitude` is
+            // only needed in range, where it is exact. `None` stands for too
+            // large, as no `u128` would do for `u128` itself.
+            let too_large = if negative { -whole > pow2(bits - 1) || !signed } else { whole >= pow2(value_bits) };
+            let magnitude = (!too_large).then(|| whole.abs() as u128);
+
+            // Reducing modulo 2^128 first keeps every bit that matters to
+            // the narrower types, and makes the magnitude fit a `u128`.
+            let reduced = whole % pow2(128);
+            let wrapped = if reduced.is_finite() { twos_complement(negative, reduced.abs() as u128) } else { 0 };
+            (negative, magnitude, wrapped, rounding)
+        }
+    };
+
+    let range = if negative && !signed {
+        Some(Loss::SignChange)
+    } else if negative && magnitude.is_none_or(|magnitude| magnitude > min_magnitude) {
+        Some(Loss::Underflow)
+    } else if !negative && magnitude.is_none_or(|magnitude| magnitude > max) {
+        Some(Loss::Overflow)
+    } else {
+        None
+    };
+    let saturated = match range {
+        Some(Loss::Overflow) => max,
+        Some(_) => min,
+        // In range, so the magnitude is known.
+        None => twos_complement(negative, magnitude.unwrap_or_default()),
+    };
+    Outcome { saturated, wrapped, range, rounding }
+}
+
+macro_rules! signed {
+    ($($ty:ident)*) => {$(
+        impl Sealed for $ty {
+            fn to_value(self) -> Value {
+                Value::Int { negative: self < 0, magnitude: self.unsigned_abs() as u128 }
+            }
+
+            fn outcome(value: Value) -> Outcome<$ty> {
+                int_outcome(value, $ty::BITS, true).map(|bits| bits as $ty)
+            }
+        }
+    )*};
+}
+
+macro_rules! unsigned {
+    ($($ty:ident)*) => {$(
+        impl Sealed for $ty {
+            fn to_value(self) -> Value {
+                Value::Int { negative: false, magnitude: self as u128 }
+            }
+
+            fn outcome(value: Value) -> Outcome<$ty> {
+                int_outcome(value, $ty::BITS, false).map(|bits| bits as $ty)
+            }
+        }
+    )*};
+}
+
+macro_rules! float {
+    ($($ty:ident)*) => {$(
+        impl Sealed for $ty {
+            fn to_value(self) -> Value {
+                Value::Float(self as f64)
+            }
+
+            fn outcome(value: Value) -> Outcome<$ty> {
+                let (rounded, exact) = match value {
+                    Value::Int { negative, magnitude } => {
+                        let rounded = if negative { -(magnitude as $ty) } else { magnitude as $ty };
+                        // Back to an integer: `as` saturates at 2^128.
+                        let back = (rounded as f64).abs();
+                        (rounded, back < pow2(128) && back as u128 == magnitude)
+                    }
+                    Value::Float(x) => {
+                        let rounded = x as $ty;
+                        (rounded, x.is_nan() || rounded as f64 == x)
+                    }
+                };
+
+                // Only a finite value can overflow: an infinity is one already.
+                let overflow = rounded.is_infinite() && !matches!(value, Value::Float(x) if x.is_infinite());
+                let (range, saturated) = if !overflow {
+                    (None, rounded)
+                } else if rounded > 0.0 {
+                    (Some(Loss::Overflow), $ty::MAX)
+                } else {
+                    (Some(Loss::Underflow), $ty::MIN)
+                };
+                let rounding = (!exact).then_some(Loss::Precision);
+                Outcome { saturated, wrapped: rounded, range, rounding }
+            }
+        }
+    )*};
+}
+
+macro_rules! primitive {
+    ($($ty:ident: $float:expr),*) => {$(
+        impl Primitive for $ty {
+            const NAME: &'static str = stringify!($ty);
+            const IS_FLOAT: bool = $float;
+        }
+    )*};
+}
+
+signed!(i8 i16 i32 i64 i128 isize);
+unsigned!(u8 u16 u32 u64 u128 usize);
+float!(f32 f64);
+
+impl Integer for i8 {}
+impl Integer for i16 {}
+impl Integer for i32 {}
+impl Integer for i64 {}
+impl Integer for i128 {}
+impl Integer for isize {}
+impl Integer for u8 {}
+impl Integer for u16 {}
+impl Integer for u32 {}
+impl Integer for u64 {}
+impl Integer for u128 {}
+impl Integer for usize {}
+
+impl Float for f32 {
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+}
+
+impl Float for f64 {
+    fn to_f64(self) -> f64 {
+        self
+    }
+}
+primitive!(
+    i8: false, i16: false, i32: false, i64: false, i128: false, isize: false,
+    u8: false, u16: false, u32: false, u64: false, u128: false, usize: false,
+    f32: true, f64: true
+);
+
+// A value of any primitive type, known at run time: `300.5f32`, `-1i8`.
+// Without a suffix, a number is an `i32`, or an `f64` if it has a `.`, an
+// exponent, or is `inf` or `NaN`, as for the literals of the source code.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AnyPrimitive {
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
+    I128(i128),
+    Isize(isize),
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    U128(u128),
+    Usize(usize),
+    F32(f32),
+    F64(f64),
+}
+
+// Evaluates `$body` with `$value` bound to the value inside `$any`,
+// whatever its type.
+macro_rules! with_any {
+    ($any:expr, $value:ident => $body:expr) => {
+        match $any {
+            AnyPrimitive::I8($value) => $body,
+            AnyPrimitive::I16($value) => $body,
+            AnyPrimitive::I32($value) => $body,
+            AnyPrimitive::I64($value) => $body,
+            AnyPrimitive::I128($value) => $body,
+            AnyPrimitive::Isize($value) => $body,
+            AnyPrimitive::U8($value) => $body,
+            AnyPrimitive::U16($value) => $body,
+            AnyPrimitive::U32($value) => $body,
+            AnyPrimitive::U64($value) => $body,
+            AnyPrimitive::U128($value) => $body,
+            AnyPrimitive::Usize($value) => $body,
+            AnyPrimitive::F32($value) => $body,
+            AnyPrimitive::F64($value) => $body,
+        }
+    };
+}
+
+impl fmt::Display for AnyPrimitive {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        with_any!(*self, value => write!(f, "{}{}", value, type_name(value)))
+    }
+}
+
+fn type_name<T: Primitive>(_: T) -> &'static str {
+    T::NAME
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsePrimitiveError(pub String);
+
+impl fmt::Display for ParsePrimitiveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "`{}` is not a number with an optional type suffix, such as `300.5f32`", self.0)
+    }
+}
+
+impl std::error::Error for ParsePrimitiveError {}
+
+impl FromStr for AnyPrimitive {
+    type Err = ParsePrimitiveError;
+
+    fn from_str(s: &str) -> Result<AnyPrimitive, ParsePrimitiveError> {
+        let error = || ParsePrimitiveError(s.to_string());
+        let float_like = s.contains(['.', 'e', 'E']) || s.ends_with("inf") || s.ends_with("NaN");
+
+        macro_rules! suffixes {
+            ($($suffix:ident => $variant:ident),*) => {
+                $(if let Some(number) = s.strip_suffix(stringify!($suffix)) {
+                    return number.parse().map(AnyPrimitive::$variant).map_err(|_| error());
+                })*
+            };
+        }
+        // The longest first: `5i128` must not be read as `5i1` and `28`.
+        suffixes!(
+            i128 => I128, u128 => U128, isize => Isize, usize => Usize,
+            i16 => I16, i32 => I32, i64 => I64, u16 => U16, u32 => U32, u64 => U64,
+            f32 => F32, f64 => F64, i8 => I8, u8 => U8
+        );
+
+        if float_like {
+            s.parse().map(AnyPrimitive::F64).map_err(|_| error())
+        } else {
+            s.parse().map(AnyPrimitive::I32).map_err(|_| error())
+        }
+    }
+}
+
+// One line of the table: the target type and what each cast gives.
+fn row<T: Primitive, U: Primitive>(value: T) -> [String; 6] {
+    let result = |result: Result<U, CastError>| match result {
+        Ok(value) => value.to_string(),
+        Err(err) => err.loss.to_string(),
+    };
+    [
+        U::NAME.to_string(),
+        as_cast::<T, U>(value).to_string(),
+        result(checked_cast(value)),
+        saturating_cast::<T, U>(value).to_string(),
+        wrapping_cast::<T, U>(value).to_string(),
+        result(exact_cast(value)),
+    ]
+}
+
+fn rows<T: Primitive>(value: T) -> Vec<[String; 6]> {
+    vec![
+        row::<T, i8>(value),
+        row::<T, i16>(value),
+        row::<T, i32>(value),
+        row::<T, i64>(value),
+        row::<T, i128>(value),
+        row::<T, isize>(value),
+        row::<T, u8>(value),
+        row::<T, u16>(value),
+        row::<T, u32>(value),
+        row::<T, u64>(value),
+        row::<T, u128>(value),
+        row::<T, usize>(value),
+        row::<T, f32>(value),
+        row::<T, f64>(value),
+    ]
+}
+
+// Every cast of `value` to every primitive type, a failed one showing the
+// loss that made it fail.
+pub fn write_table(out: &mut dyn Write, value: AnyPrimitive) -> io::Result<()> {
+    let header = ["to", "as", "checked", "saturating", "wrapping", "exact"].map(String::from);
+    let rows = with_any!(value, value => rows(value));
+
+    let mut widths = [0; 6];
+    for row in std::iter::once(&header).chain(&rows) {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+
+    writeln!(out, "casting {}:", value)?;
+    for row in std::iter::once(&header).chain(&rows) {
+        // Type names to the left, values to the right.
+        let cells: Vec<String> = row
+            .iter()
+            .zip(widths)
+            .enumerate()
+            .map(|(i, (cell, width))| if i == 0 { format!("{:<width$}", cell) } else { format!("{:>width$}", cell) })
+            .collect();
+        writeln!(out, "{}", cells.join("  ").trim_end())?;
+    }
+    Ok(())
+}
@@ -0,0 +1,294 @@
+// What a `char` is made of. `65 as char` is 'A' because a `char` is a
+// Unicode code point, a number from 0 to 0x10FFFF, and the first 256 of them
+// are the bytes of Latin-1. Text is stored as UTF-8 though, where only ASCII
+// takes a single byte, so a string's length in bytes, in chars and in what a
+// reader sees as characters (graphemes) often differ:
+//
+//   "é"                    2 bytes, 1 char, 1 grapheme
+//   "e\u{301}"             3 bytes, 2 chars ('e' and a combining accent), 1 grapheme
+//   "\u{1F1EB}\u{1F1F7}"    8 bytes, 2 chars (regional indicators F and R), 1 grapheme, a flag
+//
+// Counting graphemes exactly takes the Unicode tables; the count here only
+// knows the usual suspects: combining marks, variation selectors, skin
+// tones, zero width joiners, flags and "\r\n". It is "grapheme-ish".
+//
+// Not every `u32` is a `char`: the surrogates, 0xD800 to 0xDFFF, only exist
+// inside UTF-16, and nothing goes beyond 0x10FFFF. Every `u8` is a `char`,
+// read as Latin-1, but a byte above 0x7F is no character on its own in UTF-8.
+
+use std::fmt;
+use std::io::{self, Write};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AsciiClass {
+    Control,
+    Whitespace,
+    Digit,
+    Uppercase,
+    Lowercase,
+    Punctuation,
+}
+
+// The class of a char: the ASCII ones are known precisely, the others only
+// through what `char` can tell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Class {
+    Ascii(AsciiClass),
+    Alphabetic,
+    Numeric,
+    Whitespace,
+    Control,
+    // Joins the char before it into a grapheme, see `is_extender`.
+    Extender,
+    Other,
+}
+
+impl Class {
+    pub fn of(c: char) -> Class {
+        if c.is_ascii() {
+            // `is_ascii_whitespace` first: '\t' and '\n' are control chars too.
+            let class = if c.is_ascii_whitespace() {
+                AsciiClass::Whitespace
+            } else if c.is_ascii_control() {
+                AsciiClass::Control
+            } else if c.is_ascii_digit() {
+                AsciiClass::Digit
+            } else if c.is_ascii_uppercase() {
+                AsciiClass::Uppercase
+            } else if c.is_ascii_lowercase() {
+                AsciiClass::Lowercase
+            } else {
+                AsciiClass::Punctuation
+            };
+            return Class::Ascii(class);
+        }
+
+        if is_extender(c) {
+            Class::Extender
+        } else if c.is_alphabetic() {
+            Class::Alphabetic
+        } else if c.is_numeric() {
+            Class::Numeric
+        } else if c.is_whitespace() {
+            Class::Whitespace
+        } else if c.is_control() {
+            Class::Control
+        } else {
+            Class::Other
+        }
+    }
+}
+
+impl fmt::Display for Class {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Class::Ascii(AsciiClass::Control) => "ASCII control",
+            Class::Ascii(AsciiClass::Whitespace) => "ASCII whitespace",
+            Class::Ascii(AsciiClass::Digit) => "ASCII digit",
+            Class::Ascii(AsciiClass::Uppercase) => "ASCII uppercase letter",
+            Class::Ascii(AsciiClass::Lowercase) => "ASCII lowercase letter",
+            Class::Ascii(AsciiClass::Punctuation) => "ASCII punctuation",
+            Class::Alphabetic => "alphabetic",
+            Class::Numeric => "numeric",
+            Class::Whitespace => "whitespace",
+            Class::Control => "control",
+            Class::Extender => "extends the previous char",
+            Class::Other => "other",
+        })
+    }
+}
+
+const ZERO_WIDTH_JOINER: char = '\u{200D}';
+
+// A char that never starts a grapheme: combining marks, variation
+// selectors, emoji skin tones, tag characters and the zero width joiner.
+pub fn is_extender(c: char) -> bool {
+    matches!(
+        c,
+        '\u{0300}'..='\u{036F}'
+            | '\u{1AB0}'..='\u{1AFF}'
+            | '\u{1DC0}'..='\u{1DFF}'
+            | '\u{20D0}'..='\u{20FF}'
+            | '\u{FE20}'..='\u{FE2F}'
+            | '\u{FE00}'..='\u{FE0F}'
+            | '\u{E0100}'..='\u{E01EF}'
+            | '\u{1F3FB}'..='\u{1F3FF}'
+            | '\u{E0020}'..='\u{E007F}'
+            | ZERO_WIDTH_JOINER
+    )
+}
+
+// Two in a row make a flag.
+fn is_regional_indicator(c: char) -> bool {
+    ('\u{1F1E6}'..='\u{1F1FF}').contains(&c)
+}
+
+// The number of graphemes, as far as `is_extender` knows.
+pub fn graphemes(text: &str) -> usize {
+    let mut count = 0;
+    let mut previous = None;
+    // A regional indicator waiting for the second one of its flag.
+    let mut open_flag = false;
+
+    for c in text.chars() {
+        let joins = match previous {
+            None => false,
+            Some('\r') => c == '\n',
+            Some(ZERO_WIDTH_JOINER) => true,
+            Some(_) => is_extender(c) || (open_flag && is_regional_indicator(c)),
+        };
+        open_flag = is_regional_indicator(c) && !(joins && open_flag);
+        if !joins {
+            count += 1;
+        }
+        previous = Some(c);
+    }
+    count
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CharInfo {
+    pub ch: char,
+    pub utf8: Vec<u8>,
+    pub utf16: Vec<u16>,
+    pub class: Class,
+}
+
+impl CharInfo {
+    pub fn new(ch: char) -> CharInfo {
+        let mut buffer = [0u8; 4];
+        let utf8 = ch.encode_utf8(&mut buffer).as_bytes().to_vec();
+        let mut buffer = [0u16; 2];
+        let utf16 = ch.encode_utf16(&mut buffer).to_vec();
+        CharInfo { ch, utf8, utf16, class: Class::of(ch) }
+    }
+
+    pub fn code_point(&self) -> u32 {
+        u32::from(self.ch)
+    }
+
+    // The char as it can be shown: escaped if invisible, and on a dotted
+    // circle if it combines with the char before it.
+    pub fn printable(&self) -> String {
+        match self.class {
+            Class::Extender => format!("\u{25CC}{}", self.ch),
+            Class::Ascii(AsciiClass::Control | AsciiClass::Whitespace) | Class::Whitespace | Class::Control => {
+                self.ch.escape_debug().to_string()
+            }
+            _ => self.ch.to_string(),
+        }
+    }
+}
+
+fn hex_bytes(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02X}", b)).collect::<Vec<_>>().join(" ")
+}
+
+fn hex_units(units: &[u16]) -> String {
+    units.iter().map(|u| format!("{:04X}", u)).collect::<Vec<_>>().join(" ")
+}
+
+// `U+00E9 'é', UTF-8 C3 A9, UTF-16 00E9, alphabetic`
+impl fmt::Display for CharInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "U+{:04X} '{}', UTF-8 {}, UTF-16 {}, {}",
+            self.code_point(),
+            self.printable(),
+            hex_bytes(&self.utf8),
+            hex_units(&self.utf16),
+            self.class
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CharError {
+    // 0xD800 to 0xDFFF, reserved for UTF-16 surrogate pairs.
+    Surrogate(u32),
+    // Above 0x10FFFF, the last code point.
+    TooLarge(u32),
+}
+
+impl fmt::Display for CharError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CharError::Surrogate(value) => write!(f, "0x{:X} is a UTF-16 surrogate, not a char", value),
+            CharError::TooLarge(value) => write!(f, "0x{:X} is above 0x10FFFF, the last code point", value),
+        }
+    }
+}
+
+impl std::error::Error for CharError {}
+
+// `char::from_u32`, saying why a value is no char.
+pub fn char_from_u32(value: u32) -> Result<char, CharError> {
+    char::from_u32(value).ok_or(if value > 0x10FFFF { CharError::TooLarge(value) } else { CharError::Surrogate(value) })
+}
+
+// What a byte can be in UTF-8.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ByteRole {
+    Ascii,
+    // 0x80 to 0xBF: the second, third or fourth byte of a char.
+    Continuation,
+    // The first byte of a char of that many bytes.
+    Lead(usize),
+    // 0xC0, 0xC1 and 0xF5 to 0xFF appear in no valid UTF-8.
+    Invalid,
+}
+
+impl ByteRole {
+    pub fn of(byte: u8) -> ByteRole {
+        match byte {
+            0x00..=0x7F => ByteRole::Ascii,
+            0x80..=0xBF => ByteRole::Continuation,
+            0xC2..=0xDF => ByteRole::Lead(2),
+            0xE0..=0xEF => ByteRole::Lead(3),
+            0xF0..=0xF4 => ByteRole::Lead(4),
+            _ => ByteRole::Invalid,
+        }
+    }
+}
+
+impl fmt::Display for ByteRole {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ByteRole::Ascii => write!(f, "an ASCII char on its own"),
+            ByteRole::Continuation => write!(f, "a continuation byte, never first"),
+            ByteRole::Lead(len) => write!(f, "the first byte of a {} byte char", len),
+            ByteRole::Invalid => write!(f, "never valid"),
+        }
+    }
+}
+
+// The lengths of a string, counted in four ways.
+pub fn write_text_report(out: &mut dyn Write, text: &str) -> io::Result<()> {
+    writeln!(out, "text: {:?}", text)?;
+    writeln!(
+        out,
+        "{} bytes, {} chars, {} UTF-16 units, {} graphemes (approximately)",
+        text.len(),
+        text.chars().count(),
+        text.encode_utf16().count(),
+        graphemes(text)
+    )?;
+    for (offset, ch) in text.char_indices() {
+        writeln!(out, "  byte {:>3}: {}", offset, CharInfo::new(ch))?;
+    }
+    Ok(())
+}
+
+// A byte, both as the `char` of `as char` and as a byte of UTF-8.
+pub fn write_u8_report(out: &mut dyn Write, byte: u8) -> io::Result<()> {
+    writeln!(out, "{} as char: {}", byte, CharInfo::new(byte as char))?;
+    writeln!(out, "0x{:02X} in UTF-8: {}", byte, ByteRole::of(byte))
+}
+
+pub fn write_u32_report(out: &mut dyn Write, value: u32) -> io::Result<()> {
+    match char_from_u32(value) {
+        Ok(ch) => writeln!(out, "{}: {}", value, CharInfo::new(ch)),
+        Err(err) => writeln!(out, "{}: {}", value, err),
+    }
+}
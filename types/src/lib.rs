@@ -0,0 +1,270 @@
+// Rust provides several mechanisms to change or define the type of primitive and user defined types:
+// 1) Casting between prmitive types
+// 2) Specifying the desired type of literals
+// 3) Using type inference
+// 4) Aliasing types
+
+// Suppress all warnings from casts which overflow.
+#![allow(overflowing_literals)]
+// The casts below are the point of the example, even the unnecessary ones.
+#![allow(clippy::unnecessary_cast, clippy::cast_nan_to_int, clippy::vec_init_then_push)]
+
+use std::io::{self, Write};
+
+use cmdline::Args;
+use codec::{Encode, Format};
+
+pub mod cast;
+pub mod charinfo;
+pub mod rounding;
+pub mod units;
+
+use cast::{checked_cast, exact_cast, saturating_cast, wrapping_cast, AnyPrimitive};
+use rounding::{float_to_int, FloatToIntError, Rounding};
+use units::{Hour, Inch, Kilogram, Kilometer, Millimeter, MetersPerSecond, Nanosecond, Pound, Quantity, Second};
+
+pub const USAGE: &str = "\
+usage: types [options]
+
+options:
+    --decimal <f32>     the float cast to `u8` and then to `char` (default: 65.4321)
+    --cast <number>     the number cast to every numeric type, with an optional
+                        type suffix such as `-1i8` (default: 300.5f32)
+    -h, --help          print this message";
+
+// The inputs of the example, try different values for `decimal` with `--decimal`.
+pub struct Params {
+    pub decimal: f32,
+    pub cast: AnyPrimitive,
+}
+
+impl Default for Params {
+    fn default() -> Params {
+        Params { decimal: 65.4321, cast: AnyPrimitive::F32(300.5) }
+    }
+}
+
+impl Params {
+    pub fn from_args(mut args: Args) -> Result<Params, cmdline::Error> {
+        let default = Params::default();
+        let params = Params {
+            decimal: args.get("decimal", default.decimal)?,
+            cast: args.get("cast", default.cast)?,
+        };
+        args.finish()?;
+        Ok(params)
+    }
+}
+
+pub fn run(out: &mut dyn Write) -> io::Result<()> {
+    run_with(out, &Params::default())
+}
+
+pub fn run_args(args: Args, out: &mut dyn Write) -> Result<(), cmdline::Error> {
+    let params = Params::from_args(args)?;
+    Ok(run_with(out, &params)?)
+}
+
+pub fn run_with(out: &mut dyn Write, params: &Params) -> io::Result<()> {
+    // 1) Casting
+    // Rust provides no implicit type conversion (coercion) between primitive types.
+    // But explicit type conversion (casting) can be performed using the `as` keyword.
+    // Rules for converting between integral types follow C conventions generally,
+    // except in cases wher eC has undefined behaviour. The behaviour of all casts between
+    // integral types is well defined in Rust.
+    let decimal = params.decimal;
+
+    // Error! No implicit conversion
+    // let integer: u8 = decimal;
+    // Comment out the previous line to fix the error.
+
+    // Explicit conversion
+    let integer = decimal as u8;
+    let character = integer as char;
+
+    // Error! There are limitations in convesion rules.
+    // A float cannot be directly converted to a char.
+    // let character = decimal as char;
+    // Comment out the previous line to fix the error.
+
+    writeln!(out, "Casting: {} -> {} -> {}", decimal, integer, character)?;
+    // A `char` is a Unicode code point, and `rbe charinfo` tells more about it
+    writeln!(out, "{}", charinfo::CharInfo::new(character))?;
+
+    // When casting any value to an unsigned type, `T`,
+    //  T::MAX + 1 is added or subtracted until the value
+    // fits into the new type.
+
+    // 1000 already fits in a `u16`
+    writeln!(out, "1000 as u16 is: {}", 1000 as u16)?;
+
+    // 1000 - 256 - 256 - 256 = 232
+    // Under the hood the first 8 least significant bits (LSB) are kept,
+    // while the rest towards the most significant bit (MSB) get truncated.
+    writeln!(out, "1000 as u8 is: {}", 1000 as u8)?;
+    // -1 + 256 = 255
+    writeln!(out, "-1 as a u8 is: {}", (-1i8) as u8)?;
+
+    // For positive numbers this is the same as the modulo operator
+    writeln!(out, "1000 mod 256 is: {}", 1000 % 256)?;
+
+    // When casting to a signed type the bitwise result is the same as
+    //  first casting to the corresponding unsigned tpye. If the most
+    // significant bit of that value is 1, then the value is negative.
+
+    // Unless it already fits, of course.
+    writeln!(out, "128 as i16 is: {}", 128 as i16)?;
+
+    // 128 as i8 -> -128, whose two's complement in eight bits is:
+    writeln!(out, "128 as i8 is: {}", 128 as i8)?;
+
+    // Repeating the example above
+    // 1000 as u8 -> 232
+    writeln!(out, "1000 as u8 is: {}", 1000 as u8)?;
+    // and the two's complement of 232 is -24
+    writeln!(out, "232 as i8 is: {}", 232 as i8)?;
+
+    // Since Rust 1.45, the `as` keyword perform a `saturating cast`
+    // when casting from float to int. If the floating point value
+    // exceeds the upper bound or is less than the lower bound, the returned value
+    // will be equal to the bound crossed.
+
+    // 300.0 is 255
+    writeln!(out, "300.0 is {}", 300.0_f32 as u8)?;
+    // -100.0 as u8 is 0
+    writeln!(out, "-100.0 as u8 is: {}", -100.0_f32 as u8)?;
+    // nan as u8 is 0
+    writeln!(out, "nan as u8 is {}", f32::NAN as u8)?;
+
+    // This behaviour incurs a small runtime cost, which the unsafe
+    // `to_int_unchecked` avoids by not checking at all: on 300.0, -100.0 or
+    // NaN, none of which fits a `u8`, its result is undefined behavior.
+    // `float_to_int` checks instead, rounds the way it's told, and says why
+    // a value has no `u8`:
+    let modes = Rounding::ALL.map(|mode| mode.to_string());
+    writeln!(out, "{:>6}  {:>12}  {:>12}  {:>12}  {:>12}  {:>12}", "u8", modes[0], modes[1], modes[2], modes[3], modes[4])?;
+    for value in [300.0_f32, -100.0, f32::NAN, f32::INFINITY, 2.5, 3.5, -0.5] {
+        let cells = Rounding::ALL.map(|mode| match float_to_int::<u8>(value, mode) {
+            Ok(int) => int.to_string(),
+            Err(FloatToIntError::OutOfRange { .. }) => "out of range".to_string(),
+            Err(FloatToIntError::NaN) => "NaN".to_string(),
+            Err(FloatToIntError::Infinite { .. }) => "infinite".to_string(),
+        });
+        writeln!(out, "{:>6}  {:>12}  {:>12}  {:>12}  {:>12}  {:>12}", value, cells[0], cells[1], cells[2], cells[3], cells[4])?;
+    }
+    if let Err(err) = float_to_int::<u8>(300.0_f32, Rounding::Truncate) {
+        writeln!(out, "300.0 to u8: {}", err)?;
+    }
+
+    // The `cast` module says what a cast would lose, and lets us choose
+    // what to do about it
+    match checked_cast::<i32, u8>(1000) {
+        Ok(value) => writeln!(out, "checked_cast(1000) to u8 is {}", value)?,
+        Err(err) => writeln!(out, "checked_cast(1000) to u8 fails: {}", err)?,
+    }
+    writeln!(out, "saturating_cast(1000) to u8 is {}", saturating_cast::<i32, u8>(1000))?;
+    writeln!(out, "wrapping_cast(1000) to u8 is {}", wrapping_cast::<i32, u8>(1000))?;
+    match exact_cast::<f32, u8>(decimal) {
+        Ok(value) => writeln!(out, "exact_cast({}) to u8 is {}", decimal, value)?,
+        Err(err) => writeln!(out, "exact_cast({}) to u8 fails: {}", decimal, err)?,
+    }
+    cast::write_table(out, params.cast)?;
+
+    // 2) Literals
+    // Numeric literals can be type annotated by adding the type as a suffix.
+    // As an example, to specify that the literal `42` should have the type `i32`
+    // write `42i32`.
+
+    // The type of unsuffixed numeric literals will depend on how they are used.
+    // If no constraint exists, the compiler will use `i32` for integers and `f64`
+    // for floating -point numbers.
+
+    // Suffixed literals, their type are known at initialization
+    let x = 1u8;
+    let y = 2u32;
+    let z = 3f32;
+
+    // Unsuffixed literals, their tpyes depend on how they are used
+    let i = 1;
+    let f = 1.0;
+    let c = 'c';
+
+    // `size_of_val` returns the size of a variable in bytes
+    writeln!(out, "size of `x` in bytes: {}", std::mem::size_of_val(&x))?;
+    writeln!(out, "size of `y` in bytes: {}", std::mem::size_of_val(&y))?;
+    writeln!(out, "size of `z` in bytes: {}", std::mem::size_of_val(&z))?;
+    writeln!(out, "size of `i` in bytes: {}", std::mem::size_of_val(&i))?;
+    writeln!(out, "size of `f` in bytes: {}", std::mem::size_of_val(&f))?;
+    writeln!(out, "size of `c` in bytes: {}", std::mem::size_of_val(&c))?;
+
+    // Which bytes they are depends on the byte order: big endian puts the
+    // most significant byte first, little endian, as x86 and ARM, last. A
+    // varint (LEB128) only takes the bytes the value needs.
+    write_bytes(out, "y", y)?;
+    write_bytes(out, "z", z)?;
+    write_bytes(out, "300i32", 300i32)?;
+    write_bytes(out, "-300i16", -300i16)?;
+
+    // 3) Inference
+    // The type inference engine is pretty smart. It does more than looking at the type of the value
+    // xpression during an initialization. It also looks at how the variable is used afterwards to infer its type. Here's an advanced example of type inference:
+
+    // Because of the annotation, the compiler knows that `elem` has type `u8`.
+    let elem = 5u8;
+
+    // Create an empty vector (a growable array)
+    let mut vec = Vec::new();
+    // At this point the compiler doesn-t know the exact type of `vec`
+    // it just knows that it's a vector of something (`Vec<_>`).
+
+    // Insert `elem` in the vector
+    vec.push(elem);
+    // Now the compiler knows that `vec` is a vector of `u8` (`Vec<u8>`)
+
+    // TODO: try commenting out the `vec.push` instruction
+    writeln!(out, "{:?}", vec)?;
+
+    // 4) Aliasing
+    // The `type` statement can be used to give a new name to an existing type. Types must have
+    // `UpperCamelCase` names, or the compiler will raise a warning. The exception to this rule are the primitive types: `usize`, `f32`, etc.
+
+    // `NanoSecond`, `Inch` and `U64` would be NEW NAMES for `u64`:
+    //   type NanoSecond = u64;
+    //   type Inch = u64;
+    // and aliases don't provide any extra type safety, because aliases are
+    // not new types: adding nanoseconds to inches would compile.
+
+    // The `units` module gives every unit its own type instead. An alias is
+    // still handy to shorten a long type name.
+    type Duration = Quantity<Nanosecond>;
+    let nanoseconds = Duration::new(5.0);
+    let inches = Quantity::<Inch>::new(2.0);
+
+    // Error! Nanoseconds and inches don't add up
+    // let unit = nanoseconds + inches;
+    // Comment out the previous line to fix the error.
+
+    // Converting to another unit of the same dimension is explicit
+    let millimeters = Quantity::<Millimeter>::new(25.4);
+    writeln!(out, "{} + {} = {}", inches, millimeters, inches + millimeters.to::<Inch>())?;
+    writeln!(out, "{} is {}", nanoseconds, nanoseconds.to::<Second>())?;
+    // Error! A time can't be converted to a length
+    // let length = nanoseconds.to::<Inch>();
+
+    // A length divided by a time is a velocity
+    let marathon = Quantity::<Kilometer>::new(42.195);
+    let speed = marathon / Quantity::<Hour>::new(2.0);
+    writeln!(out, "{} in 2 h is {:.2}, or {:.2}", marathon, speed, speed.to::<MetersPerSecond>())?;
+    writeln!(out, "{} is {:.1}", Quantity::<Pound>::new(150.0), Quantity::<Pound>::new(150.0).to::<Kilogram>())?;
+
+    Ok(())
+}
+
+fn write_bytes(out: &mut dyn Write, name: &str, value: impl Encode) -> io::Result<()> {
+    for format in Format::ALL {
+        let bytes: Vec<String> = codec::encode(&value, format).iter().map(|b| format!("{:02X}", b)).collect();
+        let label = format!("`{}` in {}:", name, format);
+        writeln!(out, "{:<28} {}", label, bytes.join(" "))?;
+    }
+    Ok(())
+}
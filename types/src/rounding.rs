@@ -0,0 +1,90 @@
+// From a float to an integer, safely. `as` saturates and maps NaN to 0,
+// silently, and `to_int_unchecked` is undefined behavior as soon as the
+// value doesn't fit. `float_to_int` rounds the way it is told, then checks
+// the result fits, and otherwise says why not:
+//
+//   float_to_int::<u8>(2.5_f32, Rounding::HalfToEven)    // Ok(2)
+//   float_to_int::<u8>(300.0_f32, Rounding::Truncate)    // Err(OutOfRange)
+//   float_to_int::<u8>(f32::NAN, Rounding::Truncate)     // Err(NaN)
+
+use std::fmt;
+
+use crate::cast::{checked_cast, Float, Integer};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rounding {
+    // Toward zero, as `as` does: 2.7 -> 2, -2.7 -> -2.
+    Truncate,
+    // Toward negative infinity: -2.5 -> -3.
+    Floor,
+    // Toward positive infinity: 2.1 -> 3.
+    Ceil,
+    // To the nearest, ties away from zero, as `f64::round`: 2.5 -> 3, -2.5 -> -3.
+    HalfAwayFromZero,
+    // To the nearest, ties to the even neighbor: 2.5 -> 2, 3.5 -> 4.
+    HalfToEven,
+}
+
+impl Rounding {
+    pub const ALL: [Rounding; 5] =
+        [Rounding::Truncate, Rounding::Floor, Rounding::Ceil, Rounding::HalfAwayFromZero, Rounding::HalfToEven];
+
+    pub fn round(self, x: f64) -> f64 {
+        match self {
+            Rounding::Truncate => x.trunc(),
+            Rounding::Floor => x.floor(),
+            Rounding::Ceil => x.ceil(),
+            Rounding::HalfAwayFromZero => x.round(),
+            Rounding::HalfToEven => x.round_ties_even(),
+        }
+    }
+}
+
+impl fmt::Display for Rounding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Rounding::Truncate => "truncate",
+            Rounding::Floor => "floor",
+            Rounding::Ceil => "ceil",
+            Rounding::HalfAwayFromZero => "half away",
+            Rounding::HalfToEven => "half even",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FloatToIntError {
+    // The rounded value is outside the range of the integer type.
+    OutOfRange { rounded: f64, to: &'static str },
+    NaN,
+    Infinite { negative: bool },
+}
+
+impl fmt::Display for FloatToIntError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FloatToIntError::OutOfRange { rounded, to } => write!(f, "{} is out of the range of {}", rounded, to),
+            FloatToIntError::NaN => write!(f, "NaN has no integer value"),
+            FloatToIntError::Infinite { negative: false } => write!(f, "infinity has no integer value"),
+            FloatToIntError::Infinite { negative: true } => write!(f, "negative infinity has no integer value"),
+        }
+    }
+}
+
+impl std::error::Error for FloatToIntError {}
+
+pub fn float_to_int<I: Integer>(value: impl Float, mode: Rounding) -> Result<I, FloatToIntError> {
+    // Widening an `f32` is exact, and so is rounding in `f64` what was an `f32`.
+    let x = value.to_f64();
+    if x.is_nan() {
+        return Err(FloatToIntError::NaN);
+    }
+    if x.is_infinite() {
+        return Err(FloatToIntError::Infinite { negative: x < 0.0 });
+    }
+
+    // The rounded value has no fraction left, so the only possible loss is
+    // the range.
+    let rounded = mode.round(x);
+    checked_cast(rounded).map_err(|_| FloatToIntError::OutOfRange { rounded, to: I::NAME })
+}
@@ -0,0 +1,219 @@
+// Quantities that know their unit. `type Inch = u64` is only another name
+// for `u64`, so nothing stops adding inches to nanoseconds. Here every unit
+// is a type, and a `Quantity<Inch>` is a number of inches:
+//
+//   Quantity::<Inch>::new(2.0) + Quantity::<Nanosecond>::new(5.0)  // Error!
+//
+// Quantities add up only within the same unit. Going from one unit to
+// another of the same dimension is explicit, with `to`, and going across
+// dimensions doesn't compile at all:
+//
+//   Quantity::<Inch>::new(2.0).to::<Millimeter>()    // 50.8 mm
+//   Quantity::<Inch>::new(2.0).to::<Second>()        // Error!
+//
+// A length divided by a time is a velocity, whose unit `Per<Kilometer, Hour>`
+// is written km/h, and a velocity times a time is a length again.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+// The dimensions: what a unit measures.
+pub enum Length {}
+pub enum Time {}
+pub enum Mass {}
+pub enum Velocity {}
+
+pub trait Unit {
+    type Dimension;
+    // How many of the dimension's base unit (meter, second, kilogram, meter
+    // per second) one of this unit makes.
+    const FACTOR: f64;
+
+    fn write_symbol(f: &mut fmt::Formatter) -> fmt::Result;
+}
+
+// `unit!(Inch, Length, 0.0254, "in")` declares the unit `Inch`, a `Length`
+// worth 0.0254 meters, written `in`.
+macro_rules! unit {
+    ($name:ident, $dimension:ident, $factor:expr, $symbol:expr) => {
+        pub enum $name {}
+
+        impl Unit for $name {
+            type Dimension = $dimension;
+            const FACTOR: f64 = $factor;
+
+            fn write_symbol(f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str($symbol)
+            }
+        }
+    };
+}
+
+unit!(Meter, Length, 1.0, "m");
+unit!(Millimeter, Length, 0.001, "mm");
+unit!(Kilometer, Length, 1000.0, "km");
+unit!(Inch, Length, 0.0254, "in");
+unit!(Foot, Length, 0.3048, "ft");
+unit!(Mile, Length, 1609.344, "mi");
+
+unit!(Second, Time, 1.0, "s");
+unit!(Millisecond, Time, 1e-3, "ms");
+unit!(Nanosecond, Time, 1e-9, "ns");
+unit!(Minute, Time, 60.0, "min");
+unit!(Hour, Time, 3600.0, "h");
+
+unit!(Kilogram, Mass, 1.0, "kg");
+unit!(Gram, Mass, 0.001, "g");
+unit!(Pound, Mass, 0.453_592_37, "lb");
+
+// The unit of a length `L` per time `T`, a velocity.
+pub struct Per<L, T>(PhantomData<(L, T)>);
+
+impl<L, T> Unit for Per<L, T>
+where
+    L: Unit<Dimension = Length>,
+    T: Unit<Dimension = Time>,
+{
+    type Dimension = Velocity;
+    const FACTOR: f64 = L::FACTOR / T::FACTOR;
+
+    fn write_symbol(f: &mut fmt::Formatter) -> fmt::Result {
+        L::write_symbol(f)?;
+        f.write_str("/")?;
+        T::write_symbol(f)
+    }
+}
+
+pub type MetersPerSecond = Per<Meter, Second>;
+pub type KilometersPerHour = Per<Kilometer, Hour>;
+pub type MilesPerHour = Per<Mile, Hour>;
+
+pub struct Quantity<U> {
+    value: f64,
+    unit: PhantomData<U>,
+}
+
+impl<U: Unit> Quantity<U> {
+    pub fn new(value: f64) -> Quantity<U> {
+        Quantity { value, unit: PhantomData }
+    }
+
+    // The number of `U`s.
+    pub fn value(self) -> f64 {
+        self.value
+    }
+
+    // The same quantity in another unit of the same dimension.
+    pub fn to<V>(self) -> Quantity<V>
+    where
+        V: Unit<Dimension = U::Dimension>,
+    {
+        Quantity::new(self.value * U::FACTOR / V::FACTOR)
+    }
+}
+
+// Implemented by hand: deriving would require `U` itself to be `Clone`,
+// `Copy` and so on, and units are never values.
+impl<U> Clone for Quantity<U> {
+    fn clone(&self) -> Quantity<U> {
+        *self
+    }
+}
+
+impl<U> Copy for Quantity<U> {}
+
+impl<U> PartialEq for Quantity<U> {
+    fn eq(&self, other: &Quantity<U>) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<U> PartialOrd for Quantity<U> {
+    fn partial_cmp(&self, other: &Quantity<U>) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<U: Unit> fmt::Debug for Quantity<U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Quantity({})", self)
+    }
+}
+
+// The value, honoring the precision if any, then the symbol: `50.80 mm`.
+impl<U: Unit> fmt::Display for Quantity<U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)?;
+        f.write_str(" ")?;
+        U::write_symbol(f)
+    }
+}
+
+impl<U: Unit> Add for Quantity<U> {
+    type Output = Quantity<U>;
+
+    fn add(self, rhs: Quantity<U>) -> Quantity<U> {
+        Quantity::new(self.value + rhs.value)
+    }
+}
+
+impl<U: Unit> Sub for Quantity<U> {
+    type Output = Quantity<U>;
+
+    fn sub(self, rhs: Quantity<U>) -> Quantity<U> {
+        Quantity::new(self.value - rhs.value)
+    }
+}
+
+impl<U: Unit> Neg for Quantity<U> {
+    type Output = Quantity<U>;
+
+    fn neg(self) -> Quantity<U> {
+        Quantity::new(-self.value)
+    }
+}
+
+// Scaling by a plain number keeps the unit.
+impl<U: Unit> Mul<f64> for Quantity<U> {
+    type Output = Quantity<U>;
+
+    fn mul(self, rhs: f64) -> Quantity<U> {
+        Quantity::new(self.value * rhs)
+    }
+}
+
+impl<U: Unit> Div<f64> for Quantity<U> {
+    type Output = Quantity<U>;
+
+    fn div(self, rhs: f64) -> Quantity<U> {
+        Quantity::new(self.value / rhs)
+    }
+}
+
+// Length / time = velocity, in the units of both.
+impl<L, T> Div<Quantity<T>> for Quantity<L>
+where
+    L: Unit<Dimension = Length>,
+    T: Unit<Dimension = Time>,
+{
+    type Output = Quantity<Per<L, T>>;
+
+    fn div(self, rhs: Quantity<T>) -> Quantity<Per<L, T>> {
+        Quantity::new(self.value / rhs.value)
+    }
+}
+
+// Velocity * time = length, when the time is in the unit of the velocity.
+impl<L, T> Mul<Quantity<T>> for Quantity<Per<L, T>>
+where
+    L: Unit<Dimension = Length>,
+    T: Unit<Dimension = Time>,
+{
+    type Output = Quantity<L>;
+
+    fn mul(self, rhs: Quantity<T>) -> Quantity<L> {
+        Quantity::new(self.value * rhs.value)
+    }
+}
@@ -0,0 +1,9 @@
+// Mixing the dimensions of `types::units` must not compile. The expected
+// errors are in `tests/ui/*.stderr`; `TRYBUILD=overwrite cargo test -p types`
+// regenerates them after a deliberate change.
+
+#[test]
+fn ui() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/fail_*.rs");
+}
@@ -0,0 +1,5 @@
+use types::units::{Meter, Quantity, Second};
+
+fn main() {
+    let _ = Quantity::<Meter>::new(2.0) + Quantity::<Second>::new(5.0);
+}
@@ -0,0 +1,5 @@
+use types::units::{Meter, Quantity, Second};
+
+fn main() {
+    let _length: Quantity<Meter> = Quantity::<Second>::new(5.0);
+}
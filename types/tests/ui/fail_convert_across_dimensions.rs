@@ -0,0 +1,5 @@
+use types::units::{Inch, Quantity, Second};
+
+fn main() {
+    let _ = Quantity::<Inch>::new(2.0).to::<Second>();
+}
@@ -0,0 +1,195 @@
+// `#[derive(Validated)]` writes the conversions of a validated newtype, a
+// struct wrapping a single value that only holds the values passing a check,
+// the way `conversion::EvenNumber` does by hand:
+//
+//   fn is_even(value: &i32) -> bool {
+//       value % 2 == 0
+//   }
+//
+//   #[derive(Debug, Validated)]
+//   #[validate(fn = "is_even", error = "NotEven")]
+//   pub struct EvenNumber(i32);
+//
+// `fn` names the check, any function taking a reference to the wrapped value
+// and returning `bool`. `error` names the error type the derive declares next
+// to the struct, with the same visibility:
+//
+//   pub enum NotEven {
+//       Invalid(i32),                  // the value failed `is_even`
+//       Parse(<i32 as FromStr>::Err),  // `FromStr` could not parse a value
+//   }
+//
+// The struct gets `TryFrom<i32>` and `FromStr`, both going through the check,
+// `Deref<Target = i32>` to read the value, `Display` showing the value alone,
+// and an `into_inner` method. The wrapped type must implement `Debug`,
+// `Clone`, `PartialEq`, `Display` and `FromStr`, like every integer does.
+//
+// The struct can be a tuple struct or have a single named field. Any misuse
+// of the attribute is a compile error pointing at the faulty part.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::spanned::Spanned;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, Ident, LitStr, Member, Path, Result};
+
+#[proc_macro_derive(Validated, attributes(validate))]
+pub fn derive_validated(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input).unwrap_or_else(Error::into_compile_error).into()
+}
+
+// What `#[validate(...)]` says.
+struct Options {
+    check: Path,
+    error: Ident,
+}
+
+fn expand(input: &DeriveInput) -> Result<proc_macro2::TokenStream> {
+    let name = &input.ident;
+    let vis = &input.vis;
+
+    if !input.generics.params.is_empty() || input.generics.where_clause.is_some() {
+        return Err(Error::new(input.generics.span(), "`Validated` does not support generic structs"));
+    }
+    let (member, inner) = single_field(input)?;
+    let Options { check, error } = options(input)?;
+
+    let check_name = quote!(#check).to_string().replace(' ', "");
+    let inner_name = quote!(#inner).to_string().replace(' ', "");
+    let invalid_message = format!("{{}} does not satisfy `{}`", check_name);
+    let parse_message = format!("not a valid {}: {{}}", inner_name);
+
+    Ok(quote! {
+        #[derive(Debug, Clone, PartialEq)]
+        #vis enum #error {
+            Invalid(#inner),
+            Parse(<#inner as ::std::str::FromStr>::Err),
+        }
+
+        impl ::std::fmt::Display for #error {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                match self {
+                    #error::Invalid(value) => write!(f, #invalid_message, value),
+                    #error::Parse(err) => write!(f, #parse_message, err),
+                }
+            }
+        }
+
+        impl ::std::error::Error for #error {}
+
+        impl #name {
+            pub fn into_inner(self) -> #inner {
+                self.#member
+            }
+        }
+
+        impl ::std::convert::TryFrom<#inner> for #name {
+            type Error = #error;
+
+            fn try_from(value: #inner) -> ::std::result::Result<#name, #error> {
+                if #check(&value) {
+                    ::std::result::Result::Ok(#name { #member: value })
+                } else {
+                    ::std::result::Result::Err(#error::Invalid(value))
+                }
+            }
+        }
+
+        impl ::std::str::FromStr for #name {
+            type Err = #error;
+
+            fn from_str(s: &str) -> ::std::result::Result<#name, #error> {
+                let value = s.parse::<#inner>().map_err(#error::Parse)?;
+                <#name as ::std::convert::TryFrom<#inner>>::try_from(value)
+            }
+        }
+
+        impl ::std::ops::Deref for #name {
+            type Target = #inner;
+
+            fn deref(&self) -> &#inner {
+                &self.#member
+            }
+        }
+
+        impl ::std::fmt::Display for #name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                ::std::fmt::Display::fmt(&self.#member, f)
+            }
+        }
+    })
+}
+
+// The only field of the struct: how to reach it and its type.
+fn single_field(input: &DeriveInput) -> Result<(Member, &syn::Type)> {
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        Data::Enum(data) => return Err(Error::new(data.enum_token.span, "`Validated` can only be derived for structs")),
+        Data::Union(data) => return Err(Error::new(data.union_token.span, "`Validated` can only be derived for structs")),
+    };
+    let mut iter = fields.iter();
+    match (iter.next(), iter.next()) {
+        (Some(field), None) => {
+            let member = match &field.ident {
+                Some(ident) => Member::Named(ident.clone()),
+                None => Member::Unnamed(0.into()),
+            };
+            Ok((member, &field.ty))
+        }
+        _ => {
+            let span = match fields {
+                Fields::Unit => input.ident.span(),
+                _ => fields.span(),
+            };
+            Err(Error::new(span, "`Validated` needs a struct with exactly one field"))
+        }
+    }
+}
+
+// Read the `#[validate(fn = "...", error = "...")]` attribute.
+fn options(input: &DeriveInput) -> Result<Options> {
+    let mut attrs = input.attrs.iter().filter(|attr| attr.path().is_ident("validate"));
+    let Some(attr) = attrs.next() else {
+        return Err(Error::new(
+            input.ident.span(),
+            "`#[derive(Validated)]` needs a `#[validate(fn = \"...\", error = \"...\")]` attribute",
+        ));
+    };
+    if let Some(duplicate) = attrs.next() {
+        return Err(Error::new_spanned(duplicate, "duplicate `#[validate]` attribute"));
+    }
+
+    let mut check: Option<Path> = None;
+    let mut error: Option<Ident> = None;
+    attr.parse_nested_meta(|meta| {
+        if meta.path.is_ident("fn") {
+            if check.is_some() {
+                return Err(meta.error("duplicate `fn` key"));
+            }
+            let lit: LitStr = meta.value()?.parse()?;
+            let path = lit
+                .parse::<Path>()
+                .map_err(|_| Error::new(lit.span(), "`fn` must name a function, such as \"is_even\""))?;
+            check = Some(path);
+        } else if meta.path.is_ident("error") {
+            if error.is_some() {
+                return Err(meta.error("duplicate `error` key"));
+            }
+            let lit: LitStr = meta.value()?.parse()?;
+            let ident = lit
+                .parse::<Ident>()
+                .map_err(|_| Error::new(lit.span(), "`error` must be a type name, such as \"NotEven\""))?;
+            error = Some(ident);
+        } else {
+            return Err(meta.error("unknown key, expected `fn` or `error`"));
+        }
+        Ok(())
+    })?;
+
+    let missing = |key: &str| {
+        let span = attr.meta.span();
+        Error::new(span, format!("missing `{} = \"...\"` in `#[validate]`", key))
+    };
+    Ok(Options { check: check.ok_or_else(|| missing("fn"))?, error: error.ok_or_else(|| missing("error"))? })
+}
+
@@ -0,0 +1,10 @@
+// Every misuse of `#[validate]` must be a compile error saying what is wrong.
+// The expected messages are in `tests/ui/*.stderr`; `TRYBUILD=overwrite cargo
+// test -p validate` regenerates them after a deliberate change.
+
+#[test]
+fn ui() {
+    let cases = trybuild::TestCases::new();
+    cases.pass("tests/ui/pass.rs");
+    cases.compile_fail("tests/ui/fail_*.rs");
+}
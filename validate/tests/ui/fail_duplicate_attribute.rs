@@ -0,0 +1,12 @@
+use validate::Validated;
+
+fn is_even(value: &i32) -> bool {
+    value % 2 == 0
+}
+
+#[derive(Validated)]
+#[validate(fn = "is_even", error = "NotEven")]
+#[validate(fn = "is_even", error = "NotEven")]
+pub struct EvenNumber(i32);
+
+fn main() {}
@@ -0,0 +1,13 @@
+use validate::Validated;
+
+fn is_even(value: &i32) -> bool {
+    value % 2 == 0
+}
+
+#[derive(Validated)]
+#[validate(fn = "is_even", error = "NotEven")]
+pub enum EvenNumber {
+    Even(i32),
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use validate::Validated;
+
+fn is_even(value: &i32) -> bool {
+    value % 2 == 0
+}
+
+#[derive(Validated)]
+pub struct EvenNumber(i32);
+
+fn main() {}
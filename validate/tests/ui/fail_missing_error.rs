@@ -0,0 +1,11 @@
+use validate::Validated;
+
+fn is_even(value: &i32) -> bool {
+    value % 2 == 0
+}
+
+#[derive(Validated)]
+#[validate(fn = "is_even")]
+pub struct EvenNumber(i32);
+
+fn main() {}
@@ -0,0 +1,36 @@
+use std::convert::TryFrom;
+
+use validate::Validated;
+
+fn is_even(value: &i32) -> bool {
+    value % 2 == 0
+}
+
+fn is_short(name: &String) -> bool {
+    name.len() <= 8
+}
+
+#[derive(Debug, Validated)]
+#[validate(fn = "is_even", error = "NotEven")]
+pub struct EvenNumber(i32);
+
+#[derive(Debug, Validated)]
+#[validate(fn = "is_short", error = "TooLong")]
+struct Username {
+    name: String,
+}
+
+fn main() {
+    let even = EvenNumber::try_from(8).unwrap();
+    assert_eq!(*even, 8);
+    assert_eq!(even.to_string(), "8");
+    assert_eq!(EvenNumber::try_from(5).unwrap_err(), NotEven::Invalid(5));
+    assert_eq!("-4".parse::<EvenNumber>().unwrap().into_inner(), -4);
+    assert!(matches!("four".parse::<EvenNumber>(), Err(NotEven::Parse(_))));
+    assert_eq!(NotEven::Invalid(5).to_string(), "5 does not satisfy `is_even`");
+
+    let name: Username = "ferris".parse().unwrap();
+    assert_eq!(name.len(), 6);
+    assert_eq!(Username::try_from("a bit too long".to_string()).unwrap_err().to_string(),
+               "a bit too long does not satisfy `is_short`");
+}